@@ -1,7 +1,7 @@
 use std::error::Error;
 
 use primitives::{AssetBalance, ChainAddress};
-use settings_chain::ChainProviders;
+use settings_chain::{ChainHealth, ChainProviders};
 
 pub struct ChainClient {
     providers: ChainProviders,
@@ -38,4 +38,8 @@ impl ChainClient {
             .get_balance_assets(request.chain, request.address)
             .await
     }
+
+    pub async fn health_status(&self) -> Vec<ChainHealth> {
+        self.providers.health_status().await
+    }
 }
@@ -1,6 +1,4 @@
-use auto_allocator;
-
-use core_tracing::CoreTracing;
+use core_tracing::{CoreTracing, ReloadHandle};
 use rocket::tokio::sync::Mutex;
 use rocket::{Build, Rocket, routes};
 use settings::Settings;
@@ -17,7 +15,7 @@ use crate::chain::client::ChainClient;
 use model::APIService;
 use settings_chain::ChainProviders;
 
-async fn rocket_api(settings: Settings) -> Rocket<Build> {
+async fn rocket_api(settings: Settings, reload_handle: ReloadHandle) -> Rocket<Build> {
     let providers = ChainProviders::from_settings(&settings, "api");
     let chain_client = ChainClient::new(providers);
 
@@ -28,9 +26,15 @@ async fn rocket_api(settings: Settings) -> Rocket<Build> {
 
     rocket::custom(figment)
         .manage(Mutex::new(chain_client))
+        .manage(reload_handle)
         .mount(
             "/",
-            routes![system::status::get_status, system::status::get_health],
+            routes![
+                system::status::get_status,
+                system::status::get_health,
+                system::status::get_health_chains,
+                system::log_level::put_log_level,
+            ],
         )
         .mount(
             "/v1",
@@ -47,7 +51,7 @@ async fn main() {
     let info = auto_allocator::get_allocator_info();
     let settings = Settings::new().unwrap();
 
-    let _tracing = CoreTracing::init(&settings, "api");
+    let tracer = CoreTracing::init(&settings, "api");
 
     warn!("currently used allocator type {:?}", info.allocator_type);
     warn!("detailed reason for allocator selection {:?}", info.reason);
@@ -65,7 +69,7 @@ async fn main() {
 
     match service {
         APIService::Api => {
-            let rocket_api = rocket_api(settings.clone()).await;
+            let rocket_api = rocket_api(settings.clone(), tracer.reload_handle()).await;
             rocket_api.launch().await.expect("Failed to launch Rocket");
         }
         APIService::WebsocketPrices => todo!(),
@@ -4,6 +4,7 @@ use rocket::{Request, http::Status};
 use serde::Serialize;
 
 #[derive(Debug)]
+#[allow(dead_code)]
 pub enum ApiError {
     BadRequest(String),
     NotFound(String),
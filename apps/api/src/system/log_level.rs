@@ -0,0 +1,28 @@
+use core_tracing::ReloadHandle;
+use rocket::serde::json::Json;
+use rocket::{State, put};
+use serde::Deserialize;
+use std::str::FromStr;
+use tracing::Level;
+
+use crate::responders::{ApiError, ApiResponse};
+
+#[derive(Deserialize)]
+pub struct LogLevelRequest {
+    level: String,
+}
+
+#[put("/system/log-level", data = "<request>")]
+pub fn put_log_level(
+    request: Json<LogLevelRequest>,
+    reload_handle: &State<ReloadHandle>,
+) -> Result<ApiResponse<String>, ApiError> {
+    let level = Level::from_str(&request.level)
+        .map_err(|_| ApiError::BadRequest(format!("Invalid log level: '{}'", request.level)))?;
+    reload_handle
+        .set_level(level)
+        .map_err(|err| ApiError::InternalServerError(format!("Failed to reload log level: {}", err)))?;
+
+    tracing::warn!("log level changed to {}", level);
+    Ok(level.to_string().into())
+}
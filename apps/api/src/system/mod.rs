@@ -1 +1,2 @@
-pub mod status;
\ No newline at end of file
+pub mod log_level;
+pub mod status;
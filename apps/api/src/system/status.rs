@@ -1,11 +1,17 @@
-use rocket::get;
+use primitives::Chain;
+use rocket::http::Status;
 use rocket::serde::json::Json;
+use rocket::tokio::sync::Mutex;
+use rocket::{State, get};
 use serde::Serialize;
+use std::collections::BTreeMap;
 use std::time::{Instant, SystemTime};
 use sysinfo::System;
 
+use crate::chain::ChainClient;
+
 #[derive(Serialize)]
-struct ServerStatus {
+pub struct ServerStatus {
     timestamp: u64,
     uptime_seconds: u64,
     os: OsInfo,
@@ -13,6 +19,13 @@ struct ServerStatus {
     memory: MemoryInfo,
 }
 
+#[derive(Serialize)]
+pub struct ChainHealthStatus {
+    reachable: bool,
+    latest_block: Option<u64>,
+    latency_ms: Option<u64>,
+}
+
 #[derive(Serialize, Clone)]
 struct OsInfo {
     name: String,
@@ -95,3 +108,83 @@ pub async fn get_status() -> Json<ServerStatus> {
 pub fn get_health() -> &'static str {
     "OK"
 }
+
+/// 任一链不可用时返回 503，供负载均衡做 readiness 检查
+#[get("/health/chains")]
+pub async fn get_health_chains(
+    client: &State<Mutex<ChainClient>>,
+) -> (Status, Json<BTreeMap<Chain, ChainHealthStatus>>) {
+    let health = client.lock().await.health_status().await;
+    let status = if health.iter().all(|chain| chain.reachable) {
+        Status::Ok
+    } else {
+        Status::ServiceUnavailable
+    };
+
+    let chains = health
+        .into_iter()
+        .map(|chain| {
+            (
+                chain.chain,
+                ChainHealthStatus {
+                    reachable: chain.reachable,
+                    latest_block: chain.latest_block,
+                    latency_ms: chain.latency_ms,
+                },
+            )
+        })
+        .collect();
+
+    (status, Json(chains))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rocket::local::asynchronous::Client;
+    use rocket::routes;
+    use serde_json::Value;
+    use settings_chain::ChainProviders;
+    use settings_chain::testkit::MockProvider;
+
+    async fn health_chains(providers: ChainProviders) -> (Status, Value) {
+        let rocket = rocket::build()
+            .manage(Mutex::new(ChainClient::new(providers)))
+            .mount("/", routes![get_health_chains]);
+        let client = Client::tracked(rocket).await.unwrap();
+
+        let response = client.get("/health/chains").dispatch().await;
+        let status = response.status();
+        (status, response.into_json().await.unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_get_health_chains() {
+        let (status, body) = health_chains(ChainProviders::new(vec![
+            Box::new(MockProvider::new(Chain::Ethereum).with_latest_block(100)),
+            Box::new(MockProvider::new(Chain::Solana).with_latest_block(200)),
+        ]))
+        .await;
+
+        assert_eq!(status, Status::Ok);
+        assert_eq!(body["ethereum"]["reachable"], true);
+        assert_eq!(body["ethereum"]["latest_block"], 100);
+        assert_eq!(body["solana"]["latest_block"], 200);
+        assert!(body["solana"]["latency_ms"].is_u64());
+    }
+
+    #[tokio::test]
+    async fn test_get_health_chains_unreachable() {
+        let (status, body) = health_chains(ChainProviders::new(vec![
+            Box::new(MockProvider::new(Chain::Ethereum).with_latest_block(100)),
+            Box::new(MockProvider::new(Chain::Solana).with_error("connection refused")),
+        ]))
+        .await;
+
+        assert_eq!(status, Status::ServiceUnavailable);
+        assert_eq!(body["ethereum"]["reachable"], true);
+        assert_eq!(body["solana"]["reachable"], false);
+        assert!(body["solana"]["latest_block"].is_null());
+        assert!(body["solana"]["latency_ms"].is_null());
+    }
+}
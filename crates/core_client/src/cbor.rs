@@ -0,0 +1,79 @@
+//! CBOR 请求体/响应体编解码
+//!
+//! 未开启 `cbor` feature 时所有函数都返回 `ClientError::Serialization`。
+
+use crate::ClientError;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// 将值编码为 CBOR 字节
+///
+/// # 参数
+/// - `value` - 需要编码的值
+///
+/// # 返回值
+/// - `Ok(Vec<u8>)` - CBOR 编码后的字节
+/// - `Err(ClientError)` - 编码失败或未开启 `cbor` feature
+#[cfg(feature = "cbor")]
+pub fn to_vec<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, ClientError> {
+    let mut data = Vec::new();
+    ciborium::into_writer(value, &mut data).map_err(|e| ClientError::Serialization(format!("Failed to encode CBOR: {e}")))?;
+    Ok(data)
+}
+
+/// 将 CBOR 字节解码为指定类型
+///
+/// # 参数
+/// - `data` - CBOR 编码的字节
+///
+/// # 返回值
+/// - `Ok(R)` - 解码后的值
+/// - `Err(ClientError)` - 解码失败或未开启 `cbor` feature
+#[cfg(feature = "cbor")]
+pub fn from_slice<R: DeserializeOwned>(data: &[u8]) -> Result<R, ClientError> {
+    ciborium::from_reader(data).map_err(|e| ClientError::Serialization(format!("Failed to decode CBOR: {e}")))
+}
+
+#[cfg(not(feature = "cbor"))]
+pub fn to_vec<T: Serialize + ?Sized>(_value: &T) -> Result<Vec<u8>, ClientError> {
+    Err(disabled())
+}
+
+#[cfg(not(feature = "cbor"))]
+pub fn from_slice<R: DeserializeOwned>(_data: &[u8]) -> Result<R, ClientError> {
+    Err(disabled())
+}
+
+#[cfg(not(feature = "cbor"))]
+fn disabled() -> ClientError {
+    ClientError::Serialization("CBOR support requires the `cbor` feature".to_string())
+}
+
+#[cfg(all(test, feature = "cbor"))]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_cbor_roundtrip() {
+        let value = json!({ "method": "getSlot", "params": [1, "finalized"] });
+
+        let data = to_vec(&value).unwrap();
+        assert_ne!(data, serde_json::to_vec(&value).unwrap());
+        assert_eq!(from_slice::<serde_json::Value>(&data).unwrap(), value);
+    }
+
+    #[test]
+    fn test_cbor_decode_invalid() {
+        assert!(matches!(from_slice::<serde_json::Value>(&[0xff]), Err(ClientError::Serialization(_))));
+    }
+}
+
+#[cfg(all(test, not(feature = "cbor")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cbor_disabled() {
+        assert!(matches!(to_vec(&1), Err(ClientError::Serialization(_))));
+    }
+}
@@ -0,0 +1,266 @@
+//! 熔断器
+//!
+//! 连续失败达到阈值后熔断，冷却期内直接拒绝请求，避免持续压向已经不可用的节点；
+//! 冷却结束后放行一个探测请求，成功则恢复，失败则重新熔断。
+
+use crate::{Client, ClientError};
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// 默认连续失败阈值
+pub const DEFAULT_FAILURE_THRESHOLD: u32 = 5;
+/// 默认熔断冷却时间
+pub const DEFAULT_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// 熔断器对外可见的状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// 正常放行请求
+    Closed,
+    /// 熔断中，直接拒绝请求
+    Open,
+    /// 冷却结束，正在放行探测请求
+    HalfOpen,
+}
+
+#[derive(Debug)]
+enum State {
+    Closed { failures: u32 },
+    Open { until: Instant },
+    // 探测请求被取消时不会回报结果，超过 `until` 后允许发起新的探测
+    HalfOpen { until: Instant },
+}
+
+/// 带熔断保护的客户端包装器
+///
+/// 克隆出的实例共享同一个熔断状态。只有网络错误、超时以及 429/5xx 响应计为失败，
+/// 其他错误说明节点仍在正常响应，会重置失败计数。
+#[derive(Debug, Clone)]
+pub struct CircuitBreaker<C: Client> {
+    client: C,
+    failure_threshold: u32,
+    cooldown: Duration,
+    state: Arc<Mutex<State>>,
+}
+
+impl<C: Client> CircuitBreaker<C> {
+    /// 使用默认阈值和冷却时间包装客户端
+    pub fn new(client: C) -> Self {
+        Self {
+            client,
+            failure_threshold: DEFAULT_FAILURE_THRESHOLD,
+            cooldown: DEFAULT_COOLDOWN,
+            state: Arc::new(Mutex::new(State::Closed { failures: 0 })),
+        }
+    }
+
+    /// 设置触发熔断的连续失败次数，最小为 1
+    pub fn with_failure_threshold(mut self, failure_threshold: u32) -> Self {
+        self.failure_threshold = failure_threshold.max(1);
+        self
+    }
+
+    /// 设置熔断后的冷却时间
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// 当前熔断状态
+    pub fn state(&self) -> CircuitState {
+        match *self.state.lock().unwrap() {
+            State::Closed { .. } => CircuitState::Closed,
+            State::Open { until } if Instant::now() >= until => CircuitState::HalfOpen,
+            State::Open { .. } => CircuitState::Open,
+            State::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+
+    fn acquire(&self) -> Result<(), ClientError> {
+        let mut state = self.state.lock().unwrap();
+        match *state {
+            State::Closed { .. } => Ok(()),
+            State::Open { until } | State::HalfOpen { until } if Instant::now() >= until => {
+                *state = State::HalfOpen {
+                    until: Instant::now() + self.cooldown,
+                };
+                Ok(())
+            }
+            State::Open { .. } | State::HalfOpen { .. } => Err(ClientError::Network("circuit open".to_string())),
+        }
+    }
+
+    fn record<R>(&self, result: &Result<R, ClientError>) {
+        let mut state = self.state.lock().unwrap();
+        let failed = matches!(result, Err(err) if is_failure(err));
+        *state = match (&*state, failed) {
+            (_, false) => State::Closed { failures: 0 },
+            (State::Closed { failures }, true) if failures + 1 < self.failure_threshold => State::Closed { failures: failures + 1 },
+            (_, true) => State::Open {
+                until: Instant::now() + self.cooldown,
+            },
+        };
+    }
+
+    async fn call<R, F>(&self, request: F) -> Result<R, ClientError>
+    where
+        F: Future<Output = Result<R, ClientError>>,
+    {
+        self.acquire()?;
+        let result = request.await;
+        self.record(&result);
+        result
+    }
+}
+
+fn is_failure(error: &ClientError) -> bool {
+    match error {
+        ClientError::Network(_) | ClientError::Timeout => true,
+        ClientError::Http { status, .. } => *status == 429 || *status >= 500,
+        ClientError::Serialization(_) | ClientError::ResponseTooLarge { .. } => false,
+    }
+}
+
+#[async_trait]
+impl<C: Client> Client for CircuitBreaker<C> {
+    async fn get<R>(&self, path: &str) -> Result<R, ClientError>
+    where
+        R: DeserializeOwned,
+    {
+        self.call(self.client.get(path)).await
+    }
+
+    async fn get_with_headers<R>(&self, path: &str, headers: Option<HashMap<String, String>>) -> Result<R, ClientError>
+    where
+        R: DeserializeOwned,
+    {
+        self.call(self.client.get_with_headers(path, headers)).await
+    }
+
+    async fn get_raw(&self, path: &str) -> Result<Vec<u8>, ClientError> {
+        self.call(self.client.get_raw(path)).await
+    }
+
+    async fn post<T, R>(&self, path: &str, body: &T, headers: Option<HashMap<String, String>>) -> Result<R, ClientError>
+    where
+        T: Serialize + Send + Sync,
+        R: DeserializeOwned,
+    {
+        self.call(self.client.post(path, body, headers)).await
+    }
+
+    async fn post_raw<T>(&self, path: &str, body: &T) -> Result<Vec<u8>, ClientError>
+    where
+        T: Serialize + Send + Sync,
+    {
+        self.call(self.client.post_raw(path, body)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testkit::MockClient;
+    use serde_json::{json, Value};
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    fn switchable_client(healthy: Arc<AtomicBool>) -> MockClient {
+        MockClient::new(move |_| {
+            if healthy.load(Ordering::SeqCst) {
+                Ok(json!("ok"))
+            } else {
+                Err(ClientError::Http { status: 503, len: 0 })
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_opens_after_consecutive_failures() {
+        let healthy = Arc::new(AtomicBool::new(false));
+        let mock = switchable_client(healthy.clone());
+        let breaker = CircuitBreaker::new(mock.clone()).with_failure_threshold(3).with_cooldown(Duration::from_secs(60));
+
+        for _ in 0..3 {
+            assert!(matches!(breaker.get::<Value>("/").await, Err(ClientError::Http { status: 503, .. })));
+        }
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        healthy.store(true, Ordering::SeqCst);
+        match breaker.get::<Value>("/").await {
+            Err(ClientError::Network(msg)) => assert_eq!(msg, "circuit open"),
+            other => panic!("expected circuit open, got {:?}", other),
+        }
+        assert_eq!(mock.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_success_resets_failure_count() {
+        let healthy = Arc::new(AtomicBool::new(false));
+        let breaker = CircuitBreaker::new(switchable_client(healthy.clone())).with_failure_threshold(2);
+
+        breaker.get::<Value>("/").await.unwrap_err();
+        healthy.store(true, Ordering::SeqCst);
+        breaker.get::<Value>("/").await.unwrap();
+        healthy.store(false, Ordering::SeqCst);
+        breaker.get::<Value>("/").await.unwrap_err();
+
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_non_transport_errors_do_not_open() {
+        let breaker = CircuitBreaker::new(MockClient::new(|_| Err(ClientError::Http { status: 404, len: 0 }))).with_failure_threshold(1);
+
+        breaker.get::<Value>("/").await.unwrap_err();
+        breaker.get::<Value>("/").await.unwrap_err();
+
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_half_open_probe_closes_on_success() {
+        let healthy = Arc::new(AtomicBool::new(false));
+        let breaker = CircuitBreaker::new(switchable_client(healthy.clone()))
+            .with_failure_threshold(1)
+            .with_cooldown(Duration::from_millis(20));
+
+        breaker.get::<Value>("/").await.unwrap_err();
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+
+        healthy.store(true, Ordering::SeqCst);
+        assert_eq!(breaker.get::<Value>("/").await.unwrap(), json!("ok"));
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_half_open_probe_reopens_on_failure() {
+        let breaker = CircuitBreaker::new(switchable_client(Arc::new(AtomicBool::new(false))))
+            .with_failure_threshold(1)
+            .with_cooldown(Duration::from_millis(20));
+
+        breaker.get::<Value>("/").await.unwrap_err();
+        tokio::time::sleep(Duration::from_millis(30)).await;
+
+        assert!(matches!(breaker.get::<Value>("/").await, Err(ClientError::Http { status: 503, .. })));
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(matches!(breaker.get::<Value>("/").await, Err(ClientError::Network(_))));
+    }
+
+    #[tokio::test]
+    async fn test_clones_share_state() {
+        let breaker = CircuitBreaker::new(switchable_client(Arc::new(AtomicBool::new(false)))).with_failure_threshold(1);
+        let clone = breaker.clone();
+
+        breaker.get::<Value>("/").await.unwrap_err();
+
+        assert_eq!(clone.state(), CircuitState::Open);
+    }
+}
@@ -4,6 +4,9 @@
 
 use std::time::Duration;
 
+/// 默认请求超时
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
 /// 创建一个预配置的 reqwest 客户端构建器
 ///
 /// 返回一个配置好默认参数的 `ClientBuilder`，可以直接使用或进一步自定义。
@@ -33,7 +36,7 @@ use std::time::Duration;
 /// ```
 pub fn builder() -> reqwest::ClientBuilder {
     reqwest::Client::builder()
-        .timeout(Duration::from_secs(30))
+        .timeout(DEFAULT_TIMEOUT)
         .connect_timeout(Duration::from_secs(15))
         .pool_idle_timeout(Duration::from_secs(90))
         .pool_max_idle_per_host(20)
@@ -19,6 +19,9 @@ const APPLICATION_FORM_URL_ENCODED: &str = "application/x-www-form-urlencoded";
 /// 二进制内容类型
 const APPLICATION_X_BINARY: &str = "application/x-binary";
 
+/// CBOR 内容类型
+const APPLICATION_CBOR: &str = "application/cbor";
+
 /// HTTP Content-Type 枚举
 ///
 /// 支持常见的 HTTP 内容类型，用于在请求和响应中指定数据格式。
@@ -28,6 +31,7 @@ const APPLICATION_X_BINARY: &str = "application/x-binary";
 /// - `TextPlain` - 纯文本格式 (text/plain)
 /// - `ApplicationFormUrlEncoded` - 表单 URL 编码 (application/x-www-form-urlencoded)
 /// - `ApplicationXBinary` - 二进制格式 (application/x-binary)
+/// - `ApplicationCbor` - CBOR 格式 (application/cbor)，请求体编解码需要开启 `cbor` feature
 #[derive(Debug, Clone, PartialEq)]
 pub enum ContentType {
     /// JSON 格式 (application/json)
@@ -38,6 +42,8 @@ pub enum ContentType {
     ApplicationFormUrlEncoded,
     /// 二进制格式 (application/x-binary)
     ApplicationXBinary,
+    /// CBOR 格式 (application/cbor)
+    ApplicationCbor,
 }
 
 impl ContentType {
@@ -59,6 +65,7 @@ impl ContentType {
             ContentType::TextPlain => TEXT_PLAIN,
             ContentType::ApplicationFormUrlEncoded => APPLICATION_FORM_URL_ENCODED,
             ContentType::ApplicationXBinary => APPLICATION_X_BINARY,
+            ContentType::ApplicationCbor => APPLICATION_CBOR,
         }
     }
 }
@@ -91,6 +98,7 @@ impl FromStr for ContentType {
             TEXT_PLAIN => Ok(ContentType::TextPlain),
             APPLICATION_FORM_URL_ENCODED => Ok(ContentType::ApplicationFormUrlEncoded),
             APPLICATION_X_BINARY => Ok(ContentType::ApplicationXBinary),
+            APPLICATION_CBOR => Ok(ContentType::ApplicationCbor),
             _ => Err("Unknown content type"),
         }
     }
@@ -109,6 +117,7 @@ mod tests {
             "application/x-www-form-urlencoded"
         );
         assert_eq!(ContentType::ApplicationXBinary.as_str(), "application/x-binary");
+        assert_eq!(ContentType::ApplicationCbor.as_str(), "application/cbor");
     }
 
     #[test]
@@ -126,6 +135,10 @@ mod tests {
             ContentType::from_str("application/x-binary").unwrap(),
             ContentType::ApplicationXBinary
         );
+        assert_eq!(
+            ContentType::from_str("application/cbor").unwrap(),
+            ContentType::ApplicationCbor
+        );
     }
 
     #[test]
@@ -142,6 +155,7 @@ mod tests {
             ContentType::TextPlain,
             ContentType::ApplicationFormUrlEncoded,
             ContentType::ApplicationXBinary,
+            ContentType::ApplicationCbor,
         ];
 
         for content_type in types {
@@ -0,0 +1,36 @@
+//! 幂等请求键
+//!
+//! 同一个逻辑请求的所有重试都应携带相同的 `Idempotency-Key`，服务端据此识别重复请求
+//! （例如超时后重发的交易广播）。
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 幂等键请求头名称
+pub const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
+
+static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// 生成一个新的幂等键
+///
+/// 由当前时间、进程 ID 和进程内递增计数组成，在同一进程内保证唯一。
+///
+/// # 返回值
+/// 十六进制格式的幂等键
+pub fn new_idempotency_key() -> String {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_nanos()).unwrap_or_default();
+    let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+    format!("{:x}-{:x}-{:x}", nanos, std::process::id(), count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_new_idempotency_key_is_unique() {
+        let keys: HashSet<String> = (0..1000).map(|_| new_idempotency_key()).collect();
+        assert_eq!(keys.len(), 1000);
+    }
+}
@@ -12,15 +12,26 @@ pub mod client_config;
 
 pub mod query;
 
+pub mod idempotency;
+
+pub mod cbor;
+
+pub mod circuit_breaker;
+
+#[cfg(any(test, feature = "testkit"))]
+pub mod testkit;
+
+pub use circuit_breaker::{CircuitBreaker, CircuitState};
 pub use content_type::{ContentType, CONTENT_TYPE};
-pub use query::build_path_with_query;
+pub use idempotency::{new_idempotency_key, IDEMPOTENCY_KEY};
+pub use query::{build_path_with_query, build_path_with_repeated_query};
 pub use types::ClientError;
 
 #[cfg(feature = "reqwest")]
 pub use reqwest_client::ReqwestClient;
 
 #[cfg(feature = "reqwest")]
-pub use retry::{default_should_retry, retry, retry_policy};
+pub use retry::{default_should_retry, retry, retry_policy, retry_with_delay, DEFAULT_RETRY_DELAY};
 
 #[cfg(feature = "reqwest")]
 pub use client_config::builder;
@@ -44,8 +55,21 @@ pub trait Client: Send + Sync + Debug {
         let _ = headers;
         self.get(path).await
     }
+    /// 返回原始响应体，默认实现将 JSON 响应重新序列化为字节
+    async fn get_raw(&self, path: &str) -> Result<Vec<u8>, ClientError> {
+        let value: serde_json::Value = self.get(path).await?;
+        Ok(serde_json::to_vec(&value)?)
+    }
     async fn post<T, R>(&self, path: &str, body: &T, headers: Option<HashMap<String, String>>) -> Result<R, ClientError>
     where
         T: Serialize + Send + Sync,
         R: DeserializeOwned;
+    /// 返回原始响应体，默认实现将 JSON 响应重新序列化为字节
+    async fn post_raw<T>(&self, path: &str, body: &T) -> Result<Vec<u8>, ClientError>
+    where
+        T: Serialize + Send + Sync,
+    {
+        let value: serde_json::Value = self.post(path, body, None).await?;
+        Ok(serde_json::to_vec(&value)?)
+    }
 }
@@ -3,6 +3,8 @@
 //! 本模块提供了将结构体序列化为 URL 查询参数字符串的功能。
 
 use serde::Serialize;
+use serde_json::Value;
+use serde_urlencoded::ser::Error;
 
 /// 从可序列化的结构体构建带查询参数的路径
 ///
@@ -44,6 +46,68 @@ pub fn build_path_with_query<T: Serialize>(path: &str, query: &T) -> Result<Stri
     Ok(format!("{}?{}", path, query_string))
 }
 
+/// 与 `build_path_with_query` 相同，但序列（如 `Vec<String>`）字段展开为重复的键
+///
+/// 例如 `ids: vec!["a", "b"]` 序列化为 `ids=a&ids=b`，`None` 字段会被跳过。
+///
+/// # 参数
+/// - `path` - 基础 URL 路径（不含查询参数）
+/// - `query` - 要序列化为查询参数的结构体引用
+///
+/// # 返回值
+/// - `Ok(String)` - 包含查询参数的完整路径
+/// - `Err(serde_urlencoded::ser::Error)` - 不是结构体/映射，或字段为嵌套结构时的错误
+///
+/// # 示例
+/// ```
+/// use serde::Serialize;
+/// use core_client::build_path_with_repeated_query;
+///
+/// #[derive(Serialize)]
+/// struct PricesQuery {
+///     ids: Vec<String>,
+///     currency: String,
+/// }
+///
+/// let query = PricesQuery {
+///     ids: vec!["bitcoin".to_string(), "ethereum".to_string()],
+///     currency: "usd".to_string(),
+/// };
+///
+/// let path = build_path_with_repeated_query("/prices", &query).unwrap();
+/// assert_eq!(path, "/prices?ids=bitcoin&ids=ethereum&currency=usd");
+/// ```
+pub fn build_path_with_repeated_query<T: Serialize>(path: &str, query: &T) -> Result<String, Error> {
+    let Value::Object(fields) = serde_json::to_value(query).map_err(|e| Error::Custom(e.to_string().into()))? else {
+        return Err(Error::Custom("top-level serializer supports only maps and structs".into()));
+    };
+
+    let mut pairs = Vec::new();
+    for (key, value) in fields {
+        match value {
+            Value::Array(values) => {
+                for value in values {
+                    pairs.push((key.clone(), query_value(value)?));
+                }
+            }
+            Value::Null => {}
+            value => pairs.push((key, query_value(value)?)),
+        }
+    }
+
+    let query_string = serde_urlencoded::to_string(pairs)?;
+    Ok(format!("{}?{}", path, query_string))
+}
+
+fn query_value(value: Value) -> Result<String, Error> {
+    match value {
+        Value::String(value) => Ok(value),
+        Value::Number(value) => Ok(value.to_string()),
+        Value::Bool(value) => Ok(value.to_string()),
+        _ => Err(Error::Custom("unsupported nested value in query".into())),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -152,9 +216,46 @@ mod tests {
         let query = NumericQuery {
             int: -42,
             uint: 123456,
-            float: 3.14,
+            float: 2.5,
         };
         let result = build_path_with_query("/data", &query).unwrap();
-        assert_eq!(result, "/data?int=-42&uint=123456&float=3.14");
+        assert_eq!(result, "/data?int=-42&uint=123456&float=2.5");
+    }
+
+    #[derive(Serialize)]
+    struct PricesQuery {
+        ids: Vec<String>,
+        currency: Option<String>,
+        limit: u32,
+    }
+
+    #[test]
+    fn test_build_path_with_repeated_query() {
+        let query = PricesQuery {
+            ids: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            currency: None,
+            limit: 3,
+        };
+
+        let result = build_path_with_repeated_query("/prices", &query).unwrap();
+        assert_eq!(result, "/prices?ids=a&ids=b&ids=c&limit=3");
+    }
+
+    #[test]
+    fn test_build_path_with_repeated_query_escapes_values() {
+        let query = PricesQuery {
+            ids: vec!["solana_USDC::a b".to_string()],
+            currency: Some("usd&eur".to_string()),
+            limit: 1,
+        };
+
+        let result = build_path_with_repeated_query("/prices", &query).unwrap();
+        assert_eq!(result, "/prices?ids=solana_USDC%3A%3Aa+b&currency=usd%26eur&limit=1");
+    }
+
+    #[test]
+    fn test_build_path_with_repeated_query_rejects_nested() {
+        assert!(build_path_with_repeated_query("/prices", &vec!["a"]).is_err());
+        assert!(build_path_with_repeated_query("/prices", &serde_json::json!({ "ids": [["a"]] })).is_err());
     }
 }
@@ -2,12 +2,26 @@
 //!
 //! 本模块提供了基于 reqwest 库的 HTTP 客户端实现，支持自动重试、自定义请求头等功能。
 
-use crate::{retry_policy, Client, ClientError, ContentType, CONTENT_TYPE};
+use crate::{cbor, new_idempotency_key, retry_policy, Client, ClientError, ContentType, CONTENT_TYPE, IDEMPOTENCY_KEY};
 use async_trait::async_trait;
-use reqwest::header::USER_AGENT;
+use flate2::{write::GzEncoder, Compression};
+use reqwest::header::{CONTENT_ENCODING, USER_AGENT};
 use reqwest::RequestBuilder;
 use serde::{de::DeserializeOwned, Serialize};
-use std::{collections::HashMap, str::FromStr, time::Duration};
+use std::{
+    collections::HashMap,
+    io::Write,
+    str::FromStr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// 默认的响应体大小上限（32MB）
+pub const DEFAULT_MAX_RESPONSE_BYTES: usize = 32 * 1024 * 1024;
+
+/// 开启请求体压缩时，超过该字节数的 POST 请求体才会被 gzip 压缩
+pub const REQUEST_COMPRESSION_THRESHOLD: usize = 1024;
 
 /// 基于 reqwest 的 HTTP 客户端
 ///
@@ -18,6 +32,10 @@ use std::{collections::HashMap, str::FromStr, time::Duration};
 /// - `base_url` - API 基础 URL，所有请求路径都会附加到此 URL 后面
 /// - `client` - 底层的 reqwest 客户端实例
 /// - `user_agent` - 可选的 User-Agent 请求头
+/// - `compress_request` - 是否 gzip 压缩较大的 POST 请求体
+/// - `max_response_bytes` - 响应体大小上限
+/// - `idempotency_keys` - 是否为 POST 请求附加 `Idempotency-Key`
+/// - `host_limiter` - 可选的按 host 并发限制
 #[derive(Debug, Clone)]
 pub struct ReqwestClient {
     /// API 基础 URL
@@ -26,6 +44,44 @@ pub struct ReqwestClient {
     client: reqwest::Client,
     /// 可选的 User-Agent 头
     user_agent: Option<String>,
+    /// 是否压缩 POST 请求体
+    compress_request: bool,
+    /// 响应体大小上限
+    max_response_bytes: usize,
+    /// 是否为 POST 请求附加幂等键
+    idempotency_keys: bool,
+    /// 按 host 限制同时进行的请求数
+    host_limiter: Option<HostLimiter>,
+}
+
+/// 按 host 分配的并发许可池，克隆出的客户端共享同一组许可
+#[derive(Debug, Clone)]
+struct HostLimiter {
+    max_concurrent: usize,
+    semaphores: Arc<Mutex<HashMap<String, Arc<Semaphore>>>>,
+}
+
+impl HostLimiter {
+    fn new(max_concurrent: usize) -> Self {
+        Self {
+            max_concurrent,
+            semaphores: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// 等待 `url` 所在 host 的一个许可，许可在返回值 drop 时归还
+    async fn acquire(&self, url: &str) -> Result<OwnedSemaphorePermit, ClientError> {
+        let host = reqwest::Url::parse(url).ok().and_then(|url| url.host_str().map(String::from)).unwrap_or_default();
+        let semaphore = self
+            .semaphores
+            .lock()
+            .unwrap()
+            .entry(host)
+            .or_insert_with(|| Arc::new(Semaphore::new(self.max_concurrent)))
+            .clone();
+
+        semaphore.acquire_owned().await.map_err(|e| ClientError::Network(e.to_string()))
+    }
 }
 
 impl ReqwestClient {
@@ -42,6 +98,10 @@ impl ReqwestClient {
             base_url: url,
             client,
             user_agent: None,
+            compress_request: false,
+            max_response_bytes: DEFAULT_MAX_RESPONSE_BYTES,
+            idempotency_keys: false,
+            host_limiter: None,
         }
     }
 
@@ -59,6 +119,10 @@ impl ReqwestClient {
             base_url: url,
             client,
             user_agent: Some(user_agent),
+            compress_request: false,
+            max_response_bytes: DEFAULT_MAX_RESPONSE_BYTES,
+            idempotency_keys: false,
+            host_limiter: None,
         }
     }
 
@@ -86,6 +150,10 @@ impl ReqwestClient {
             base_url: url,
             client,
             user_agent: None,
+            compress_request: false,
+            max_response_bytes: DEFAULT_MAX_RESPONSE_BYTES,
+            idempotency_keys: false,
+            host_limiter: None,
         }
     }
 
@@ -114,6 +182,105 @@ impl ReqwestClient {
         self
     }
 
+    /// 开启 POST 请求体压缩
+    ///
+    /// 请求体超过 `REQUEST_COMPRESSION_THRESHOLD` 时使用 gzip 压缩并设置 `Content-Encoding: gzip`，
+    /// 仅适用于接受压缩请求体的节点。
+    ///
+    /// # 返回值
+    /// 更新后的 `ReqwestClient` 实例（链式调用）
+    pub fn with_request_compression(mut self, compress_request: bool) -> Self {
+        self.compress_request = compress_request;
+        self
+    }
+
+    /// 设置响应体大小上限，超过时返回 `ClientError::ResponseTooLarge`
+    ///
+    /// # 参数
+    /// - `max_response_bytes` - 允许的最大字节数
+    ///
+    /// # 返回值
+    /// 更新后的 `ReqwestClient` 实例（链式调用）
+    pub fn with_max_response_bytes(mut self, max_response_bytes: usize) -> Self {
+        self.max_response_bytes = max_response_bytes;
+        self
+    }
+
+    /// 为没有 `Idempotency-Key` 的 POST 请求生成一个幂等键
+    ///
+    /// 键在发送前生成一次，reqwest 重试策略重发时沿用同一个键；调用方已设置的键保持不变。
+    ///
+    /// # 返回值
+    /// 更新后的 `ReqwestClient` 实例（链式调用）
+    pub fn with_idempotency_keys(mut self, idempotency_keys: bool) -> Self {
+        self.idempotency_keys = idempotency_keys;
+        self
+    }
+
+    /// 限制每个 host 同时进行的请求数
+    ///
+    /// 超出限制的请求会等待许可而不是报错，许可在响应体读取完毕后释放；最小为 1。
+    ///
+    /// # 参数
+    /// - `max_concurrent_per_host` - 每个 host 允许的最大并发请求数
+    ///
+    /// # 返回值
+    /// 更新后的 `ReqwestClient` 实例（链式调用）
+    pub fn with_max_concurrent_per_host(mut self, max_concurrent_per_host: usize) -> Self {
+        self.host_limiter = Some(HostLimiter::new(max_concurrent_per_host.max(1)));
+        self
+    }
+
+    /// API 基础 URL
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// 发送原始请求并返回状态码和响应体，不检查状态码也不做反序列化
+    ///
+    /// # 参数
+    /// - `method` - HTTP 方法
+    /// - `url` - 完整的请求 URL
+    /// - `headers` - 可选的自定义请求头映射
+    /// - `body` - 可选的请求体
+    /// - `timeout` - 本次请求的超时时间，覆盖客户端默认值
+    ///
+    /// # 返回值
+    /// - `Ok((u16, Vec<u8>))` - 状态码和响应体
+    /// - `Err(ClientError)` - 网络错误或超时
+    pub async fn execute(
+        &self,
+        method: reqwest::Method,
+        url: &str,
+        headers: Option<HashMap<String, String>>,
+        body: Option<Vec<u8>>,
+        timeout: Option<Duration>,
+    ) -> Result<(u16, Vec<u8>), ClientError> {
+        let _permit = self.acquire_permit(url).await?;
+        let request = self.client.request(method, url);
+        let request = match body {
+            Some(body) => request.body(body),
+            None => request,
+        };
+        let request = match timeout {
+            Some(timeout) => request.timeout(timeout),
+            None => request,
+        };
+
+        let response = self.build_request(request, headers).send().await.map_err(Self::map_reqwest_error)?;
+        let status = response.status().as_u16();
+        let body = self.read_body(response).await?;
+        Ok((status, body))
+    }
+
+    /// 配置了并发限制时等待 `url` 所在 host 的许可
+    async fn acquire_permit(&self, url: &str) -> Result<Option<OwnedSemaphorePermit>, ClientError> {
+        match &self.host_limiter {
+            Some(limiter) => limiter.acquire(url).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
     /// 构建完整的请求 URL
     ///
     /// 将基础 URL 和路径拼接成完整的 URL。
@@ -127,6 +294,28 @@ impl ReqwestClient {
         format!("{}{}", self.base_url.trim_end_matches('/'), path)
     }
 
+    /// 按配置压缩请求体，压缩时在请求头中加入 `Content-Encoding: gzip`
+    ///
+    /// # 参数
+    /// - `body` - 原始请求体
+    /// - `headers` - 请求头映射
+    ///
+    /// # 返回值
+    /// 可能被压缩的请求体
+    fn compress_body(&self, body: Vec<u8>, headers: &mut HashMap<String, String>) -> Result<Vec<u8>, ClientError> {
+        if !self.compress_request || body.len() <= REQUEST_COMPRESSION_THRESHOLD {
+            return Ok(body);
+        }
+
+        let mut encoder = GzEncoder::new(Vec::with_capacity(body.len() / 2), Compression::default());
+        let compressed = encoder
+            .write_all(&body)
+            .and_then(|_| encoder.finish())
+            .map_err(|e| ClientError::Serialization(format!("Failed to compress request: {e}")))?;
+        headers.insert(CONTENT_ENCODING.to_string(), "gzip".to_string());
+        Ok(compressed)
+    }
+
     /// 构建带自定义请求头的请求
     ///
     /// 添加 User-Agent 和其他自定义请求头到请求中。
@@ -169,22 +358,133 @@ impl ReqwestClient {
         R: DeserializeOwned,
     {
         let status = response.status();
-        let body_bytes = response
-            .bytes()
-            .await
-            .map_err(|e| ClientError::Network(format!("Failed to read response body: {e}")))?;
-        let body = String::from_utf8_lossy(&body_bytes);
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let body_bytes = self.send_request_raw(response).await?;
+
+        if content_type.as_deref().is_some_and(is_cbor_content_type) {
+            return cbor::from_slice(&body_bytes);
+        }
+
+        serde_json::from_slice(&body_bytes).map_err(|e| match content_type.filter(|content_type| !is_json_content_type(content_type)) {
+            Some(content_type) => ClientError::Serialization(format!("Unexpected content type '{}': status {} {}", content_type, status, e)),
+            None => ClientError::Serialization(format!("Failed to deserialize response: status {} {}", status, e)),
+        })
+    }
+
+    /// 读取 HTTP 响应体，状态码非 2xx 时返回 `ClientError::Http`
+    ///
+    /// # 参数
+    /// - `response` - reqwest Response 对象
+    ///
+    /// # 返回值
+    /// - `Ok(Vec<u8>)` - 原始响应体
+    /// - `Err(ClientError)` - 网络错误或 HTTP 错误
+    async fn send_request_raw(&self, response: reqwest::Response) -> Result<Vec<u8>, ClientError> {
+        let status = response.status();
+        let body_bytes = self.read_body(response).await?;
 
         if status.is_success() {
-            serde_json::from_slice(&body_bytes).map_err(|e| ClientError::Serialization(format!("Failed to deserialize response: status {} {}", status, e)))
+            Ok(body_bytes)
         } else {
             Err(ClientError::Http {
                 status: status.as_u16(),
-                len: body.len(),
+                len: String::from_utf8_lossy(&body_bytes).len(),
             })
         }
     }
 
+    /// 分块读取响应体，超过 `max_response_bytes` 时立即中止
+    ///
+    /// # 参数
+    /// - `response` - reqwest Response 对象
+    ///
+    /// # 返回值
+    /// - `Ok(Vec<u8>)` - 完整的响应体
+    /// - `Err(ClientError)` - 读取失败或超过大小限制
+    async fn read_body(&self, mut response: reqwest::Response) -> Result<Vec<u8>, ClientError> {
+        let limit = self.max_response_bytes;
+        if response.content_length().is_some_and(|length| length > limit as u64) {
+            return Err(ClientError::ResponseTooLarge { limit });
+        }
+
+        let mut body = Vec::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| ClientError::Network(format!("Failed to read response body: {e}")))?
+        {
+            if body.len() + chunk.len() > limit {
+                return Err(ClientError::ResponseTooLarge { limit });
+            }
+            body.extend_from_slice(&chunk);
+        }
+        Ok(body)
+    }
+
+    /// 发送 GET 请求
+    ///
+    /// # 参数
+    /// - `path` - 请求路径
+    /// - `headers` - 可选的自定义请求头映射
+    async fn get_response(&self, path: &str, headers: Option<HashMap<String, String>>) -> Result<reqwest::Response, ClientError> {
+        let url = self.build_url(path);
+        let request = self.build_request(self.client.get(&url), headers);
+
+        request.send().await.map_err(Self::map_reqwest_error)
+    }
+
+    /// 按 `Content-Type` 编码请求体并发送 POST 请求
+    ///
+    /// # 参数
+    /// - `path` - 请求路径
+    /// - `body` - 请求体
+    /// - `headers` - 可选的自定义请求头映射，缺省为 JSON
+    async fn post_response<T>(&self, path: &str, body: &T, headers: Option<HashMap<String, String>>) -> Result<reqwest::Response, ClientError>
+    where
+        T: Serialize + Send + Sync,
+    {
+        let url = self.build_url(path);
+        let mut headers = headers.unwrap_or_else(|| HashMap::from([(CONTENT_TYPE.to_string(), ContentType::ApplicationJson.as_str().to_string())]));
+
+        let content_type = headers.get(CONTENT_TYPE).and_then(|s| ContentType::from_str(s).ok());
+
+        let request_body = match content_type {
+            Some(ContentType::TextPlain) | Some(ContentType::ApplicationFormUrlEncoded) | Some(ContentType::ApplicationXBinary) => {
+                let json_value = serde_json::to_value(body).map_err(|e| ClientError::Serialization(format!("Failed to serialize request: {e}")))?;
+                match json_value {
+                    serde_json::Value::String(s) => {
+                        if content_type == Some(ContentType::ApplicationXBinary) {
+                            // For binary content, decode hex string to bytes
+                            hex::decode(&s).map_err(|e| ClientError::Serialization(format!("Failed to decode hex string: {e}")))?
+                        } else {
+                            s.into_bytes()
+                        }
+                    }
+                    _ => {
+                        return Err(ClientError::Serialization(
+                            "Expected string body for text/plain or binary content-type".to_string(),
+                        ))
+                    }
+                }
+            }
+            Some(ContentType::ApplicationCbor) => cbor::to_vec(body)?,
+            _ => serde_json::to_vec(body).map_err(|e| ClientError::Serialization(format!("Failed to serialize request: {e}")))?,
+        };
+
+        if self.idempotency_keys && !headers.keys().any(|key| key.eq_ignore_ascii_case(IDEMPOTENCY_KEY)) {
+            headers.insert(IDEMPOTENCY_KEY.to_string(), new_idempotency_key());
+        }
+
+        let request_body = self.compress_body(request_body, &mut headers)?;
+        let request = self.build_request(self.client.post(&url).body(request_body), Some(headers));
+
+        request.send().await.map_err(Self::map_reqwest_error)
+    }
+
     /// 将 reqwest 错误映射为 ClientError
     ///
     /// 根据错误类型转换为对应的 ClientError 枚举。
@@ -205,6 +505,16 @@ impl ReqwestClient {
     }
 }
 
+/// 判断响应的 Content-Type 是否为 JSON（忽略参数，兼容 `+json` 后缀）
+fn is_json_content_type(content_type: &str) -> bool {
+    let mime = content_type.split(';').next().unwrap_or_default().trim().to_ascii_lowercase();
+    mime == ContentType::ApplicationJson.as_str() || mime.ends_with("+json")
+}
+
+fn is_cbor_content_type(content_type: &str) -> bool {
+    content_type.split(';').next().unwrap_or_default().trim().eq_ignore_ascii_case(ContentType::ApplicationCbor.as_str())
+}
+
 #[async_trait]
 impl Client for ReqwestClient {
     async fn get<R>(&self, path: &str) -> Result<R, ClientError>
@@ -218,49 +528,365 @@ impl Client for ReqwestClient {
     where
         R: DeserializeOwned,
     {
-        let url = self.build_url(path);
-        let request = self.build_request(self.client.get(&url), headers);
-
-        let response = request.send().await.map_err(Self::map_reqwest_error)?;
+        let _permit = self.acquire_permit(&self.build_url(path)).await?;
+        let response = self.get_response(path, headers).await?;
         self.send_request(response).await
     }
 
+    async fn get_raw(&self, path: &str) -> Result<Vec<u8>, ClientError> {
+        let _permit = self.acquire_permit(&self.build_url(path)).await?;
+        let response = self.get_response(path, None).await?;
+        self.send_request_raw(response).await
+    }
+
     async fn post<T, R>(&self, path: &str, body: &T, headers: Option<HashMap<String, String>>) -> Result<R, ClientError>
     where
         T: Serialize + Send + Sync,
         R: DeserializeOwned,
     {
-        let url = self.build_url(path);
-        let headers = headers.unwrap_or_else(|| HashMap::from([(CONTENT_TYPE.to_string(), ContentType::ApplicationJson.as_str().to_string())]));
+        let _permit = self.acquire_permit(&self.build_url(path)).await?;
+        let response = self.post_response(path, body, headers).await?;
+        self.send_request(response).await
+    }
 
-        let content_type = headers.get(CONTENT_TYPE).and_then(|s| ContentType::from_str(s).ok());
+    async fn post_raw<T>(&self, path: &str, body: &T) -> Result<Vec<u8>, ClientError>
+    where
+        T: Serialize + Send + Sync,
+    {
+        let _permit = self.acquire_permit(&self.build_url(path)).await?;
+        let response = self.post_response(path, body, None).await?;
+        self.send_request_raw(response).await
+    }
+}
 
-        let request_body = match content_type {
-            Some(ContentType::TextPlain) | Some(ContentType::ApplicationFormUrlEncoded) | Some(ContentType::ApplicationXBinary) => {
-                let json_value = serde_json::to_value(body).map_err(|e| ClientError::Serialization(format!("Failed to serialize request: {e}")))?;
-                match json_value {
-                    serde_json::Value::String(s) => {
-                        if content_type == Some(ContentType::ApplicationXBinary) {
-                            // For binary content, decode hex string to bytes
-                            hex::decode(&s).map_err(|e| ClientError::Serialization(format!("Failed to decode hex string: {e}")))?
-                        } else {
-                            s.into_bytes()
-                        }
-                    }
-                    _ => {
-                        return Err(ClientError::Serialization(
-                            "Expected string body for text/plain or binary content-type".to_string(),
-                        ))
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::GzDecoder;
+    use std::io::Read;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use tokio::sync::mpsc;
+
+    // 记录收到的请求头（小写）和请求体，并返回 `{}` 的本地服务
+    async fn capture_server() -> (String, mpsc::UnboundedReceiver<(HashMap<String, String>, Vec<u8>)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let (sender, receiver) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut data = Vec::new();
+                let mut buffer = [0u8; 4096];
+                let header_end = loop {
+                    let read = stream.read(&mut buffer).await.unwrap();
+                    data.extend_from_slice(&buffer[..read]);
+                    if let Some(position) = data.windows(4).position(|window| window == b"\r\n\r\n") {
+                        break position + 4;
                     }
+                };
+                let headers: HashMap<String, String> = String::from_utf8_lossy(&data[..header_end])
+                    .lines()
+                    .skip(1)
+                    .filter_map(|line| line.split_once(": "))
+                    .map(|(key, value)| (key.to_lowercase(), value.to_string()))
+                    .collect();
+                let length: usize = headers.get("content-length").and_then(|value| value.parse().ok()).unwrap_or(0);
+                while data.len() < header_end + length {
+                    let read = stream.read(&mut buffer).await.unwrap();
+                    data.extend_from_slice(&buffer[..read]);
                 }
+                let _ = sender.send((headers, data[header_end..header_end + length].to_vec()));
+                let _ = stream.write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 2\r\n\r\n{}").await;
             }
-            _ => serde_json::to_vec(body).map_err(|e| ClientError::Serialization(format!("Failed to serialize request: {e}")))?,
-        };
+        });
+        (format!("http://{address}"), receiver)
+    }
 
-        let request = self.build_request(self.client.post(&url).body(request_body), Some(headers));
+    // 返回 `size` 字节的 JSON 字符串，`content_length` 为 false 时不带 Content-Length，读到连接关闭为止
+    async fn large_response_server(size: usize, content_length: bool) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut buffer = [0u8; 4096];
+                let _ = stream.read(&mut buffer).await;
+                let body = format!("\"{}\"", "a".repeat(size - 2));
+                let header = if content_length {
+                    format!("HTTP/1.1 200 OK\r\ncontent-length: {}\r\n\r\n", body.len())
+                } else {
+                    "HTTP/1.1 200 OK\r\nconnection: close\r\n\r\n".to_string()
+                };
+                let _ = stream.write_all(header.as_bytes()).await;
+                let _ = stream.write_all(body.as_bytes()).await;
+            }
+        });
+        format!("http://{address}")
+    }
 
-        let response = request.send().await.map_err(Self::map_reqwest_error)?;
+    #[tokio::test]
+    async fn test_response_exceeding_limit() {
+        for content_length in [true, false] {
+            let url = large_response_server(4096, content_length).await;
+            let client = ReqwestClient::new(url, reqwest::Client::new()).with_max_response_bytes(1024);
 
-        self.send_request(response).await
+            let result = client.get::<String>("/").await;
+            assert!(matches!(result, Err(ClientError::ResponseTooLarge { limit: 1024 })), "content_length: {content_length}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_response_within_limit() {
+        let url = large_response_server(1024, false).await;
+        let client = ReqwestClient::new(url, reqwest::Client::new()).with_max_response_bytes(1024);
+
+        assert_eq!(client.get::<String>("/").await.unwrap().len(), 1022);
     }
+
+    fn large_body() -> serde_json::Value {
+        serde_json::json!({ "params": vec!["0x0000000000000000000000000000000000000000"; 64] })
+    }
+
+    // 返回固定 Content-Type 和响应体的本地服务
+    async fn static_server(content_type: &'static str, body: &'static [u8]) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut buffer = [0u8; 4096];
+                let _ = stream.read(&mut buffer).await;
+                let header = format!("HTTP/1.1 200 OK\r\ncontent-type: {}\r\ncontent-length: {}\r\n\r\n", content_type, body.len());
+                let _ = stream.write_all(header.as_bytes()).await;
+                let _ = stream.write_all(body).await;
+            }
+        });
+        format!("http://{address}")
+    }
+
+    #[tokio::test]
+    async fn test_html_response_reports_content_type() {
+        let url = static_server("text/html; charset=utf-8", b"<html>Bad Gateway</html>").await;
+        let client = ReqwestClient::new(url, reqwest::Client::new());
+
+        match client.get::<serde_json::Value>("/").await {
+            Err(ClientError::Serialization(message)) => assert!(message.contains("Unexpected content type 'text/html; charset=utf-8'"), "{message}"),
+            other => panic!("Expected Serialization error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalid_json_response_keeps_deserialize_error() {
+        let url = static_server("application/json; charset=utf-8", b"{").await;
+        let client = ReqwestClient::new(url, reqwest::Client::new());
+
+        match client.get::<serde_json::Value>("/").await {
+            Err(ClientError::Serialization(message)) => assert!(message.starts_with("Failed to deserialize response"), "{message}"),
+            other => panic!("Expected Serialization error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_is_json_content_type() {
+        assert!(is_json_content_type("application/json"));
+        assert!(is_json_content_type("Application/JSON; charset=utf-8"));
+        assert!(is_json_content_type("application/problem+json"));
+        assert!(!is_json_content_type("text/html"));
+    }
+
+    #[cfg(feature = "cbor")]
+    #[tokio::test]
+    async fn test_post_cbor_body() {
+        let (url, mut requests) = capture_server().await;
+        let client = ReqwestClient::new(url, reqwest::Client::new());
+        let body = serde_json::json!({ "method": "getSlot", "params": [1] });
+        let headers = HashMap::from([(CONTENT_TYPE.to_string(), ContentType::ApplicationCbor.as_str().to_string())]);
+
+        let _: serde_json::Value = client.post("/", &body, Some(headers)).await.unwrap();
+        let (headers, data) = requests.recv().await.unwrap();
+
+        assert_eq!(headers.get("content-type").map(String::as_str), Some("application/cbor"));
+        assert_eq!(cbor::from_slice::<serde_json::Value>(&data).unwrap(), body);
+    }
+
+    #[cfg(feature = "cbor")]
+    #[tokio::test]
+    async fn test_cbor_response() {
+        // CBOR 编码的 {"a": 1}
+        let url = static_server("application/cbor", &[0xa1, 0x61, 0x61, 0x01]).await;
+        let client = ReqwestClient::new(url, reqwest::Client::new());
+
+        assert_eq!(client.get::<serde_json::Value>("/").await.unwrap(), serde_json::json!({ "a": 1 }));
+    }
+
+    // 第一次请求返回 503，之后返回 `{}`，记录每次请求的幂等键
+    async fn flaky_server() -> (String, mpsc::UnboundedReceiver<Option<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let (sender, receiver) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let mut attempts = 0;
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut buffer = [0u8; 4096];
+                let read = stream.read(&mut buffer).await.unwrap();
+                let request = String::from_utf8_lossy(&buffer[..read]).to_lowercase();
+                let key = request.lines().find_map(|line| line.strip_prefix("idempotency-key: ").map(str::to_string));
+                let _ = sender.send(key);
+                attempts += 1;
+                let response: &[u8] = if attempts == 1 {
+                    b"HTTP/1.1 503 Service Unavailable\r\nconnection: close\r\ncontent-length: 0\r\n\r\n"
+                } else {
+                    b"HTTP/1.1 200 OK\r\nconnection: close\r\ncontent-length: 2\r\n\r\n{}"
+                };
+                let _ = stream.write_all(response).await;
+            }
+        });
+        (format!("http://{address}"), receiver)
+    }
+
+    #[tokio::test]
+    async fn test_retries_reuse_idempotency_key() {
+        let (url, mut keys) = flaky_server().await;
+        let reqwest_client = crate::client_config::builder().retry(retry_policy("127.0.0.1", 1)).build().unwrap();
+        let client = ReqwestClient::new(url, reqwest_client).with_idempotency_keys(true);
+
+        let _: serde_json::Value = client.post("/", &serde_json::json!({ "id": 1 }), None).await.unwrap();
+        let first = keys.recv().await.unwrap();
+        let second = keys.recv().await.unwrap();
+
+        assert!(first.is_some());
+        assert_eq!(first, second);
+
+        let _: serde_json::Value = client.post("/", &serde_json::json!({ "id": 2 }), None).await.unwrap();
+        assert_ne!(keys.recv().await.unwrap(), first);
+    }
+
+    #[tokio::test]
+    async fn test_post_keeps_provided_idempotency_key() {
+        let (url, mut requests) = capture_server().await;
+        let client = ReqwestClient::new(url, reqwest::Client::new()).with_idempotency_keys(true);
+        let headers = HashMap::from([(CONTENT_TYPE.to_string(), ContentType::ApplicationJson.as_str().to_string()), (IDEMPOTENCY_KEY.to_string(), "broadcast-1".to_string())]);
+
+        let _: serde_json::Value = client.post("/", &serde_json::json!({}), Some(headers)).await.unwrap();
+        let (headers, _) = requests.recv().await.unwrap();
+
+        assert_eq!(headers.get("idempotency-key").map(String::as_str), Some("broadcast-1"));
+    }
+
+    #[tokio::test]
+    async fn test_get_raw_and_post_raw() {
+        let (url, mut requests) = capture_server().await;
+        let client = ReqwestClient::new(url, reqwest::Client::new());
+
+        assert_eq!(client.get_raw("/").await.unwrap(), b"{}");
+        assert_eq!(client.post_raw("/", &serde_json::json!({ "id": 1 })).await.unwrap(), b"{}");
+
+        requests.recv().await.unwrap();
+        let (_, body) = requests.recv().await.unwrap();
+        assert_eq!(body, br#"{"id":1}"#);
+    }
+
+    #[tokio::test]
+    async fn test_post_compresses_large_body() {
+        let (url, mut requests) = capture_server().await;
+        let client = ReqwestClient::new(url, reqwest::Client::new()).with_request_compression(true);
+
+        let _: serde_json::Value = client.post("/", &large_body(), None).await.unwrap();
+        let (headers, body) = requests.recv().await.unwrap();
+
+        assert_eq!(headers.get("content-encoding").map(String::as_str), Some("gzip"));
+        let mut decompressed = Vec::new();
+        GzDecoder::new(body.as_slice()).read_to_end(&mut decompressed).unwrap();
+        assert_eq!(serde_json::from_slice::<serde_json::Value>(&decompressed).unwrap(), large_body());
+    }
+
+    #[tokio::test]
+    async fn test_post_skips_compression() {
+        let (url, mut requests) = capture_server().await;
+
+        let client = ReqwestClient::new(url.clone(), reqwest::Client::new()).with_request_compression(true);
+        let _: serde_json::Value = client.post("/", &serde_json::json!({ "id": 1 }), None).await.unwrap();
+        let (headers, body) = requests.recv().await.unwrap();
+        assert!(!headers.contains_key("content-encoding"));
+        assert_eq!(body, br#"{"id":1}"#);
+
+        let client = ReqwestClient::new(url, reqwest::Client::new());
+        let _: serde_json::Value = client.post("/", &large_body(), None).await.unwrap();
+        let (headers, body) = requests.recv().await.unwrap();
+        assert!(!headers.contains_key("content-encoding"));
+        assert_eq!(serde_json::from_slice::<serde_json::Value>(&body).unwrap(), large_body());
+    }
+
+    // 接受连接后等待 `delay` 再返回响应的本地服务
+    async fn delayed_server(delay: Duration) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    tokio::time::sleep(delay).await;
+                    let _ = stream.write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 2\r\n\r\n{}").await;
+                });
+            }
+        });
+        format!("http://{address}")
+    }
+
+    #[tokio::test]
+    async fn test_execute_timeout_overrides_client_default() {
+        let url = delayed_server(Duration::from_millis(500)).await;
+        let client = ReqwestClient::new(url.clone(), reqwest::Client::new());
+
+        let result = client.execute(reqwest::Method::GET, &url, None, None, Some(Duration::from_millis(50))).await;
+        assert!(matches!(result, Err(ClientError::Timeout)));
+
+        let (status, body) = client.execute(reqwest::Method::GET, &url, None, None, None).await.unwrap();
+        assert_eq!(status, 200);
+        assert_eq!(body, b"{}");
+    }
+
+    // 记录同时处理中的请求数峰值的本地服务，每个请求处理 50ms
+    async fn concurrency_server() -> (String, Arc<std::sync::atomic::AtomicUsize>) {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let server_peak = peak.clone();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let in_flight = in_flight.clone();
+                let peak = server_peak.clone();
+                tokio::spawn(async move {
+                    let mut buffer = [0u8; 1024];
+                    let _ = stream.read(&mut buffer).await;
+                    let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(current, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    let _ = stream.write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 2\r\nconnection: close\r\n\r\n{}").await;
+                });
+            }
+        });
+        (format!("http://{address}"), peak)
+    }
+
+    #[tokio::test]
+    async fn test_max_concurrent_per_host() {
+        let (url, peak) = concurrency_server().await;
+        let client = ReqwestClient::new(url, reqwest::Client::new()).with_max_concurrent_per_host(2);
+
+        let tasks: Vec<_> = (0..8)
+            .map(|_| {
+                let client = client.clone();
+                tokio::spawn(async move { client.get::<serde_json::Value>("/").await })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap().unwrap();
+        }
+
+        let peak = peak.load(std::sync::atomic::Ordering::SeqCst);
+        assert!(peak > 0 && peak <= 2, "peak concurrency {peak}");
+    }
+
 }
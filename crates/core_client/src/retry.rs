@@ -87,6 +87,29 @@ where
 /// ).await?;
 /// ```
 pub async fn retry<T, E, F, Fut, P>(operation: F, max_retries: u32, should_retry_fn: Option<P>) -> Result<T, E>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    E: std::fmt::Display,
+    P: Fn(&E) -> bool,
+{
+    retry_with_delay(operation, max_retries, should_retry_fn, DEFAULT_RETRY_DELAY).await
+}
+
+/// `retry` 默认的退避基数，第 n 次重试延迟 `2^n` 倍
+pub const DEFAULT_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// 指定退避基数的 `retry`，第 n 次重试延迟 `base_delay * 2^n`，最大延迟 30 分钟
+///
+/// # 示例
+/// ```ignore
+/// use core_client::retry_with_delay;
+/// use std::time::Duration;
+///
+/// // 100ms, 200ms, 400ms
+/// let result = retry_with_delay(|| async { fetch_data().await }, 3, None, Duration::from_millis(50)).await?;
+/// ```
+pub async fn retry_with_delay<T, E, F, Fut, P>(operation: F, max_retries: u32, should_retry_fn: Option<P>, base_delay: Duration) -> Result<T, E>
 where
     F: Fn() -> Fut,
     Fut: Future<Output = Result<T, E>>,
@@ -106,8 +129,8 @@ where
 
                 if should_retry_error && attempt < max_retries {
                     attempt += 1;
-                    // Exponential backoff: 2^attempt seconds (2s, 4s, 8s, ...) with max cap
-                    let delay = Duration::from_secs(2_u64.saturating_pow(attempt).min(1800)); // Cap at 30 minutes
+                    // Exponential backoff: base * 2^attempt (2s, 4s, 8s, ... for the default base) with max cap
+                    let delay = base_delay.saturating_mul(2_u32.saturating_pow(attempt)).min(Duration::from_secs(1800)); // Cap at 30 minutes
 
                     #[cfg(feature = "reqwest")]
                     sleep(delay).await;
@@ -248,7 +271,7 @@ mod tests {
         let call_count = Arc::new(AtomicU32::new(0));
         let call_count_clone = call_count.clone();
 
-        let result = retry(
+        let result = retry_with_delay(
             move || {
                 let count = call_count_clone.clone();
                 async move {
@@ -262,6 +285,7 @@ mod tests {
             },
             5,
             None::<fn(&String) -> bool>,
+            Duration::ZERO,
         )
         .await;
 
@@ -277,7 +301,7 @@ mod tests {
         let call_count = Arc::new(AtomicU32::new(0));
         let call_count_clone = call_count.clone();
 
-        let result = retry(
+        let result = retry_with_delay(
             move || {
                 let count = call_count_clone.clone();
                 async move {
@@ -287,6 +311,7 @@ mod tests {
             },
             2,
             None::<fn(&String) -> bool>,
+            Duration::ZERO,
         )
         .await;
 
@@ -328,7 +353,7 @@ mod tests {
         let call_count_clone = call_count.clone();
         let custom_predicate = |e: &String| e.contains("custom");
 
-        let result = retry(
+        let result = retry_with_delay(
             move || {
                 let count = call_count_clone.clone();
                 async move {
@@ -342,6 +367,7 @@ mod tests {
             },
             3,
             Some(custom_predicate),
+            Duration::ZERO,
         )
         .await;
 
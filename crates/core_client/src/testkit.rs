@@ -0,0 +1,105 @@
+//! 测试辅助工具
+//!
+//! 提供一个可编程的 `MockClient`，用于在不发起真实网络请求的情况下测试依赖 `Client` 的代码。
+
+use crate::{Client, ClientError};
+use async_trait::async_trait;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+/// 被 `MockClient` 记录下来的一次请求
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockRequest {
+    /// HTTP 方法（`GET` 或 `POST`）
+    pub method: &'static str,
+    /// 请求路径
+    pub path: String,
+    /// 请求体（仅 POST）
+    pub body: Option<Value>,
+    /// 请求头
+    pub headers: Option<HashMap<String, String>>,
+}
+
+type MockHandler = dyn Fn(&MockRequest) -> Result<Value, ClientError> + Send + Sync;
+
+/// 可编程的模拟客户端
+///
+/// 每次请求都会被记录，并交给处理函数生成 JSON 响应。
+#[derive(Clone)]
+pub struct MockClient {
+    handler: Arc<MockHandler>,
+    requests: Arc<Mutex<Vec<MockRequest>>>,
+}
+
+impl MockClient {
+    /// 使用处理函数创建模拟客户端
+    ///
+    /// # 参数
+    /// - `handler` - 根据请求返回 JSON 响应或错误的函数
+    pub fn new<F>(handler: F) -> Self
+    where
+        F: Fn(&MockRequest) -> Result<Value, ClientError> + Send + Sync + 'static,
+    {
+        Self {
+            handler: Arc::new(handler),
+            requests: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// 返回目前为止记录的所有请求
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    fn respond<R: DeserializeOwned>(&self, request: MockRequest) -> Result<R, ClientError> {
+        self.requests.lock().unwrap().push(request.clone());
+        let value = (self.handler)(&request)?;
+        Ok(serde_json::from_value(value)?)
+    }
+}
+
+impl fmt::Debug for MockClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockClient").field("requests", &self.requests.lock().unwrap().len()).finish()
+    }
+}
+
+#[async_trait]
+impl Client for MockClient {
+    async fn get<R>(&self, path: &str) -> Result<R, ClientError>
+    where
+        R: DeserializeOwned,
+    {
+        self.get_with_headers(path, None).await
+    }
+
+    async fn get_with_headers<R>(&self, path: &str, headers: Option<HashMap<String, String>>) -> Result<R, ClientError>
+    where
+        R: DeserializeOwned,
+    {
+        self.respond(MockRequest {
+            method: "GET",
+            path: path.to_string(),
+            body: None,
+            headers,
+        })
+    }
+
+    async fn post<T, R>(&self, path: &str, body: &T, headers: Option<HashMap<String, String>>) -> Result<R, ClientError>
+    where
+        T: Serialize + Send + Sync,
+        R: DeserializeOwned,
+    {
+        self.respond(MockRequest {
+            method: "POST",
+            path: path.to_string(),
+            body: Some(serde_json::to_value(body)?),
+            headers,
+        })
+    }
+}
@@ -13,6 +13,7 @@ use std::fmt;
 /// - `Timeout` - 请求超时
 /// - `Http` - HTTP 响应错误（非 2xx 状态码）
 /// - `Serialization` - 序列化/反序列化错误
+/// - `ResponseTooLarge` - 响应体超过大小限制
 #[derive(Debug)]
 pub enum ClientError {
     /// 网络连接错误，包含错误描述信息
@@ -28,6 +29,11 @@ pub enum ClientError {
     },
     /// 序列化或反序列化错误，包含错误描述信息
     Serialization(String),
+    /// 响应体超过大小限制，包含限制的字节数
+    ResponseTooLarge {
+        /// 允许的最大字节数
+        limit: usize,
+    },
 }
 
 impl fmt::Display for ClientError {
@@ -40,6 +46,7 @@ impl fmt::Display for ClientError {
             Self::Timeout => write!(f, "Timeout error"),
             Self::Http { status, len } => write!(f, "HTTP error: status {}, body len: {}", status, len),
             Self::Serialization(msg) => write!(f, "Serialization error: {}", msg),
+            Self::ResponseTooLarge { limit } => write!(f, "Response body exceeds limit of {} bytes", limit),
         }
     }
 }
@@ -92,6 +99,12 @@ mod tests {
         assert_eq!(err.to_string(), "Serialization error: Invalid JSON");
     }
 
+    #[test]
+    fn test_response_too_large_display() {
+        let err = ClientError::ResponseTooLarge { limit: 1024 };
+        assert_eq!(err.to_string(), "Response body exceeds limit of 1024 bytes");
+    }
+
     #[test]
     fn test_from_serde_json_error() {
         let json_err = serde_json::from_str::<serde_json::Value>("invalid json").unwrap_err();
@@ -1,6 +1,8 @@
 use alloy_primitives::{Address, AddressError};
 use std::str::FromStr;
 
+use crate::EvmError;
+
 pub fn ethereum_address_checksum(address: &str) -> Result<String, AddressError> {
     let address = Address::from_str(address)?;
     Ok(address.to_checksum(None))
@@ -9,3 +11,7 @@ pub fn ethereum_address_checksum(address: &str) -> Result<String, AddressError>
 pub fn ethereum_address_from_topic(topic: &str) -> Option<String> {
     ethereum_address_checksum(topic.trim_start_matches("0x000000000000000000000000")).ok()
 }
+
+pub(crate) fn parse_address(address: &str) -> Result<Address, EvmError> {
+    Address::from_str(address).map_err(|_| EvmError::InvalidAddress(address.to_string()))
+}
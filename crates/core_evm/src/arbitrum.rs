@@ -0,0 +1,14 @@
+use alloy_sol_types::sol;
+
+// https://docs.arbitrum.io/build-decentralized-apps/nodeinterface/reference
+pub const NODE_INTERFACE_ADDRESS: &str = "0x00000000000000000000000000000000000000C8";
+
+sol! {
+    #[derive(Debug)]
+    interface INodeInterface {
+        function gasEstimateL1Component(address to, bool contractCreation, bytes calldata data)
+          external
+          payable
+          returns (uint64 gasEstimateForL1, uint256 baseFee, uint256 l1BaseFeeEstimate);
+    }
+}
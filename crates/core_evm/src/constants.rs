@@ -1 +1,3 @@
 pub const STAKING_VALIDATORS_LIMIT: u16 = 128;
+pub const FEE_HISTORY_BLOCKS: u64 = 10;
+pub const FEE_HISTORY_REWARD_PERCENTILE: u64 = 50;
@@ -0,0 +1,122 @@
+use alloy_primitives::hex::FromHexError;
+use core_jsonrpc::types::JsonRpcError;
+use std::{fmt, num::ParseIntError};
+
+// 节点执行回滚时使用的错误码（geth 等）
+const ERROR_EXECUTION_REVERTED: i32 = 3;
+
+/// EVM 客户端错误
+#[derive(Debug, Clone)]
+pub enum EvmError {
+    /// 节点返回的 JSON-RPC 错误或传输层错误
+    RpcError(JsonRpcError),
+    /// 返回数据无法解码
+    DecodeError(String),
+    /// 合约执行回滚，包含节点返回的回滚信息
+    Reverted(String),
+    /// 当前链或节点不支持该操作
+    Unsupported(String),
+    /// 地址格式无效
+    InvalidAddress(String),
+}
+
+impl EvmError {
+    pub(crate) fn decode(error: impl fmt::Display) -> Self {
+        Self::DecodeError(error.to_string())
+    }
+}
+
+impl fmt::Display for EvmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RpcError(error) => write!(f, "RPC error: {}", error),
+            Self::DecodeError(msg) => write!(f, "Decode error: {}", msg),
+            Self::Reverted(msg) => write!(f, "Execution reverted: {}", msg),
+            Self::Unsupported(msg) => write!(f, "Unsupported: {}", msg),
+            Self::InvalidAddress(address) => write!(f, "Invalid address: {}", address),
+        }
+    }
+}
+
+impl std::error::Error for EvmError {
+    /// `RpcError` 暴露内部的 `JsonRpcError`，便于上层按错误码判断是否可重试
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::RpcError(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<JsonRpcError> for EvmError {
+    /// 回滚错误（错误码 3 或 `execution reverted` 消息）转换为 `Reverted`，其余保留为 `RpcError`
+    fn from(error: JsonRpcError) -> Self {
+        if error.code == ERROR_EXECUTION_REVERTED || error.message.to_lowercase().starts_with("execution reverted") {
+            Self::Reverted(error.message)
+        } else {
+            Self::RpcError(error)
+        }
+    }
+}
+
+impl From<FromHexError> for EvmError {
+    fn from(error: FromHexError) -> Self {
+        Self::decode(error)
+    }
+}
+
+impl From<alloy_sol_types::Error> for EvmError {
+    fn from(error: alloy_sol_types::Error) -> Self {
+        Self::decode(error)
+    }
+}
+
+impl From<serde_json::Error> for EvmError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::decode(error)
+    }
+}
+
+impl From<ParseIntError> for EvmError {
+    fn from(error: ParseIntError) -> Self {
+        Self::decode(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core_jsonrpc::types::{ERROR_LIMIT_EXCEEDED, ERROR_SERVER_ERROR};
+    use std::error::Error;
+
+    fn rpc_error(code: i32, message: &str) -> JsonRpcError {
+        JsonRpcError {
+            code,
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_from_jsonrpc_error_reverted() {
+        let error = EvmError::from(rpc_error(3, "execution reverted: ERC20: transfer amount exceeds balance"));
+        assert!(matches!(error, EvmError::Reverted(msg) if msg == "execution reverted: ERC20: transfer amount exceeds balance"));
+
+        let error = EvmError::from(rpc_error(ERROR_SERVER_ERROR, "execution reverted"));
+        assert!(matches!(error, EvmError::Reverted(_)));
+    }
+
+    #[test]
+    fn test_from_jsonrpc_error_rpc() {
+        let error = EvmError::from(rpc_error(ERROR_SERVER_ERROR, "header not found"));
+        assert!(matches!(error, EvmError::RpcError(error) if error.message == "header not found"));
+    }
+
+    #[test]
+    fn test_source_exposes_jsonrpc_error() {
+        let error = EvmError::from(rpc_error(ERROR_LIMIT_EXCEEDED, "request limit exceeded"));
+        let source = error.source().and_then(|source| source.downcast_ref::<JsonRpcError>()).unwrap();
+        assert_eq!(source.code, ERROR_LIMIT_EXCEEDED);
+
+        assert!(EvmError::DecodeError("invalid hex".to_string()).source().is_none());
+    }
+}
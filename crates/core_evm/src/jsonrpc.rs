@@ -16,6 +16,8 @@ pub struct TransactionObject {
     pub data: String,
 }
 
+pub type TransactionRequest = TransactionObject;
+
 impl TransactionObject {
     pub fn new_call(to: &str, data: Vec<u8>) -> Self {
         Self {
@@ -2,10 +2,13 @@ pub mod jsonrpc;
 pub mod rpc;
 pub mod models;
 pub mod multicall3;
+pub mod arbitrum;
 pub mod provider;
 pub mod address;
 pub mod constants;
 pub mod everstake;
+pub mod error;
 
 
-pub use address::ethereum_address_checksum;
\ No newline at end of file
+pub use address::ethereum_address_checksum;
+pub use error::EvmError;
\ No newline at end of file
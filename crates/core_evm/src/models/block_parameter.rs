@@ -9,3 +9,31 @@ pub enum EthereumBlockParameter {
     Finalized,
     Safe,
 }
+
+/// `eth_call` 等方法的区块参数
+#[derive(Clone, Debug, PartialEq)]
+pub enum BlockTag {
+    Latest,
+    Pending,
+    Number(u64),
+    Hash(String),
+}
+
+impl BlockTag {
+    // 指定具体区块的查询需要归档节点
+    pub fn is_historical(&self) -> bool {
+        matches!(self, Self::Number(_) | Self::Hash(_))
+    }
+}
+
+impl From<&BlockTag> for serde_json::Value {
+    fn from(val: &BlockTag) -> Self {
+        match val {
+            BlockTag::Latest => serde_json::json!("latest"),
+            BlockTag::Pending => serde_json::json!("pending"),
+            BlockTag::Number(number) => serde_json::json!(format!("0x{number:x}")),
+            // https://eips.ethereum.org/EIPS/eip-1898
+            BlockTag::Hash(hash) => serde_json::json!({ "blockHash": hash }),
+        }
+    }
+}
@@ -1,4 +1,5 @@
-use num_bigint::BigInt;
+use num_bigint::{BigInt, BigUint};
+use primitives::TransactionType;
 use serde::{Deserialize, Serialize};
 use serde_serializers::{deserialize_bigint_vec_from_hex_str, deserialize_u64_from_str};
 
@@ -12,3 +13,60 @@ pub struct EthereumFeeHistory {
     #[serde(deserialize_with = "deserialize_u64_from_str")]
     pub oldest_block: u64,
 }
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Eip1559Fees {
+    pub base_fee_per_gas: BigUint,
+    pub max_priority_fee_per_gas: BigUint,
+    pub max_fee_per_gas: BigUint,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeePreview {
+    pub gas_limit: BigUint,
+    pub max_fee_per_gas: BigUint,
+    pub max_priority_fee_per_gas: BigUint,
+    // L2 上 L1 数据费的明细，已包含在 gas_limit 中，非 L2 链为 0
+    pub l1_data_fee: BigUint,
+    pub total_fee: BigUint,
+}
+
+pub const DEFAULT_GAS_LIMIT_MULTIPLIER: f64 = 1.2;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GasLimitMultipliers {
+    default: f64,
+    overrides: Vec<(TransactionType, f64)>,
+}
+
+impl GasLimitMultipliers {
+    pub fn new(default: f64) -> Self {
+        Self { default, overrides: vec![] }
+    }
+
+    pub fn with(mut self, transaction_type: TransactionType, multiplier: f64) -> Self {
+        self.overrides.retain(|(existing, _)| existing != &transaction_type);
+        self.overrides.push((transaction_type, multiplier));
+        self
+    }
+
+    pub fn multiplier(&self, transaction_type: &TransactionType) -> f64 {
+        self.overrides
+            .iter()
+            .find(|(existing, _)| existing == transaction_type)
+            .map(|(_, multiplier)| *multiplier)
+            .unwrap_or(self.default)
+    }
+}
+
+impl Default for GasLimitMultipliers {
+    fn default() -> Self {
+        Self::new(DEFAULT_GAS_LIMIT_MULTIPLIER)
+            .with(TransactionType::Swap, 1.5)
+            .with(TransactionType::StakeDelegate, 1.5)
+            .with(TransactionType::StakeUndelegate, 1.5)
+            .with(TransactionType::StakeRedelegate, 1.5)
+            .with(TransactionType::StakeRewards, 1.5)
+            .with(TransactionType::StakeWithdraw, 1.5)
+    }
+}
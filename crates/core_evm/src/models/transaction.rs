@@ -1,3 +1,4 @@
+use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -9,3 +10,11 @@ pub struct EthereumTransactionReciept {
     #[serde(rename = "l1Fee")]
     pub l1_fee: Option<String>,
 }
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenTransfer {
+    pub contract: String,
+    pub from: String,
+    pub to: String,
+    pub value: BigUint,
+}
@@ -1,5 +1,6 @@
 use alloy_sol_types::{SolCall, sol};
-use primitives::EVMChain;
+
+use crate::EvmError;
 
 // https://www.multicall3.com/
 sol! {
@@ -47,6 +48,15 @@ sol! {
     }
 }
 
+sol! {
+    #[derive(Debug)]
+    interface IERC20 {
+        event Transfer(address indexed from, address indexed to, uint256 value);
+
+        function balanceOf(address account) external view returns (uint256);
+    }
+}
+
 pub fn create_call3(target: &str, call: impl SolCall) -> IMulticall3::Call3 {
     IMulticall3::Call3 {
         target: target.parse().unwrap(),
@@ -55,21 +65,11 @@ pub fn create_call3(target: &str, call: impl SolCall) -> IMulticall3::Call3 {
     }
 }
 
-pub fn decode_call3_return<T: SolCall>(result: &IMulticall3::Result) -> Result<T::Return, Box<dyn std::error::Error + Send + Sync>> {
+pub fn decode_call3_return<T: SolCall>(result: &IMulticall3::Result) -> Result<T::Return, EvmError> {
     if result.success {
-        let decoded = T::abi_decode_returns(&result.returnData).map_err(|e| format!("{:?} abi decode error: {:?}", T::SIGNATURE, e))?;
+        let decoded = T::abi_decode_returns(&result.returnData).map_err(|e| EvmError::DecodeError(format!("{:?} abi decode error: {:?}", T::SIGNATURE, e)))?;
         Ok(decoded)
     } else {
-        Err(format!("{:?} failed", T::SIGNATURE).into())
-    }
-}
-
-pub fn deployment_by_chain(chain: &EVMChain) -> &'static str {
-    match chain {
-        EVMChain::Ethereum
-        | EVMChain::Arbitrum
-        | EVMChain::SmartChain
-        | EVMChain::Polygon
-         => "0xcA11bde05977b3631167028862bE2a173976CA11",
+        Err(EvmError::Reverted(format!("{:?} failed", T::SIGNATURE)))
     }
 }
@@ -5,8 +5,34 @@ use core_chain_traits::ChainBalances;
 use primitives::{AssetBalance, EVMChain};
 
 use crate::provider::balances_mapper::{map_assets_balances, map_balance_coin, map_balance_tokens};
+use crate::EvmError;
 use crate::rpc::client::EthereumClient;
 use core_client::Client;
+use num_traits::Zero;
+
+impl<C: Client + Clone> EthereumClient<C> {
+    /// 查询给定代币列表的余额并过滤掉零余额，不依赖第三方索引服务
+    pub async fn get_balance_assets_from_list(&self, address: &str, tokens: &[String]) -> Result<Vec<AssetBalance>, EvmError> {
+        if tokens.is_empty() {
+            return Ok(vec![]);
+        }
+        let balance_results = self.fetch_token_balances(address, tokens).await?;
+        let balances = map_balance_tokens(balance_results, tokens.to_vec(), self.get_chain())?;
+        Ok(balances.into_iter().filter(|balance| !balance.balance.available.is_zero()).collect())
+    }
+
+    async fn fetch_token_balances(&self, address: &str, tokens: &[String]) -> Result<Vec<String>, EvmError> {
+        // 已部署 Multicall3 的链优先单次请求；调用失败时回退到逐个 eth_call
+        let multicall_results = match self.chain.multicall3_address() {
+            Some(_) => self.multicall_token_balance_calls(address, tokens).await.ok(),
+            None => None,
+        };
+        match multicall_results {
+            Some(results) => Ok(results),
+            None => self.batch_token_balance_calls(address, tokens).await,
+        }
+    }
+}
 
 #[async_trait]
 impl<C: Client + Clone> ChainBalances for EthereumClient<C> {
@@ -14,7 +40,7 @@ impl<C: Client + Clone> ChainBalances for EthereumClient<C> {
         &self,
         address: String,
     ) -> Result<AssetBalance, Box<dyn Error + Sync + Send>> {
-        map_balance_coin(self.get_eth_balance(&address).await?, self.get_chain())
+        Ok(map_balance_coin(self.get_eth_balance(&address).await?, self.get_chain())?)
     }
 
     async fn get_balance_tokens(
@@ -22,8 +48,11 @@ impl<C: Client + Clone> ChainBalances for EthereumClient<C> {
         address: String,
         token_ids: Vec<String>,
     ) -> Result<Vec<AssetBalance>, Box<dyn Error + Sync + Send>> {
-        let balance_results = self.batch_token_balance_calls(&address, &token_ids).await?;
-        map_balance_tokens(balance_results, token_ids, self.get_chain())
+        if token_ids.is_empty() {
+            return Ok(vec![]);
+        }
+        let balance_results = self.fetch_token_balances(&address, &token_ids).await?;
+        Ok(map_balance_tokens(balance_results, token_ids, self.get_chain())?)
     }
 
     async fn get_balance_staking(
@@ -55,6 +84,109 @@ impl<C: Client + Clone> ChainBalances for EthereumClient<C> {
                 .collect();
             return Ok(map_assets_balances(balances, self.get_chain()));
         }
-        Ok(vec![])
+        // 返回错误而不是空列表，避免调用方把"未做资产发现"误认为"没有资产"
+        Err(format!("Asset discovery unavailable on {}: no Ankr client configured", self.chain.as_ref()).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::multicall3::IMulticall3;
+    use alloy_primitives::{U256, hex};
+    use alloy_sol_types::SolCall;
+    use core_jsonrpc::{JsonRpcClient, testkit::{MockClient, mock_client}};
+    use num_bigint::BigUint;
+    use primitives::AssetId;
+    use serde_json::{Value, json};
+
+    const ADDRESS: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+    const USDT: &str = "0xdAC17F958D2ee523a2206206994597C13D831ec7";
+    const USDC: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+
+    fn balance_of(token: &str) -> U256 {
+        match token.to_lowercase().as_str() {
+            t if t == USDT.to_lowercase() => U256::from(1_500_000u64),
+            t if t == USDC.to_lowercase() => U256::ZERO,
+            _ => panic!("unexpected token {token}"),
+        }
+    }
+
+    fn client(multicall_deployed: bool) -> (EthereumClient<MockClient>, MockClient) {
+        let mock = mock_client(move |method, params| {
+            assert_eq!(method, "eth_call");
+            let to = params[0]["to"].as_str().unwrap();
+            if EVMChain::Ethereum.multicall3_address() == Some(to) {
+                if !multicall_deployed {
+                    return Ok(json!("0x"));
+                }
+                let data = hex::decode(params[0]["data"].as_str().unwrap()).unwrap();
+                let calls = IMulticall3::aggregate3Call::abi_decode(&data).unwrap().calls;
+                let results: Vec<IMulticall3::Result> = calls
+                    .iter()
+                    .map(|call| IMulticall3::Result {
+                        success: true,
+                        returnData: balance_of(&call.target.to_string()).to_be_bytes::<32>().to_vec().into(),
+                    })
+                    .collect();
+                return Ok(Value::String(hex::encode_prefixed(IMulticall3::aggregate3Call::abi_encode_returns(&results))));
+            }
+            Ok(Value::String(hex::encode_prefixed(balance_of(to).to_be_bytes::<32>())))
+        });
+        (EthereumClient::new(JsonRpcClient::new(mock.clone()), EVMChain::Ethereum), mock)
+    }
+
+    fn token_ids() -> Vec<String> {
+        vec![USDT.to_string(), USDC.to_string()]
+    }
+
+    #[tokio::test]
+    async fn test_get_balance_tokens_multicall_matches_sequential() {
+        let (client, mock) = client(true);
+        let multicall = client.multicall_token_balance_calls(ADDRESS, &token_ids()).await.unwrap();
+        let sequential = client.batch_token_balance_calls(ADDRESS, &token_ids()).await.unwrap();
+
+        let chain = client.get_chain();
+        assert_eq!(
+            map_balance_tokens(multicall, token_ids(), chain).unwrap(),
+            map_balance_tokens(sequential, token_ids(), chain).unwrap()
+        );
+
+        let balances = client.get_balance_tokens(ADDRESS.to_string(), token_ids()).await.unwrap();
+        assert_eq!(balances.len(), 2);
+        assert_eq!(balances[0].asset_id, AssetId::from_token(chain, USDT));
+        assert_eq!(balances[0].balance.available, BigUint::from(1_500_000u64));
+        assert_eq!(balances[1].balance.available, BigUint::from(0u64));
+        assert_eq!(mock.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_get_balance_tokens_falls_back_without_multicall() {
+        let (client, _) = client(false);
+        let balances = client.get_balance_tokens(ADDRESS.to_string(), token_ids()).await.unwrap();
+
+        assert_eq!(balances.len(), 2);
+        assert_eq!(balances[0].balance.available, BigUint::from(1_500_000u64));
+        assert_eq!(balances[1].balance.available, BigUint::from(0u64));
+    }
+
+    #[tokio::test]
+    async fn test_get_balance_assets_without_ankr() {
+        let (client, mock) = client(true);
+
+        let error = client.get_balance_assets(ADDRESS.to_string()).await.unwrap_err();
+        assert_eq!(error.to_string(), "Asset discovery unavailable on ethereum: no Ankr client configured");
+        assert!(mock.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_balance_assets_from_list_filters_zero_balances() {
+        let (client, mock) = client(true);
+
+        let balances = client.get_balance_assets_from_list(ADDRESS, &token_ids()).await.unwrap();
+        assert_eq!(balances.len(), 1);
+        assert_eq!(balances[0].asset_id, AssetId::from_token(client.get_chain(), USDT));
+        assert_eq!(balances[0].balance.available, BigUint::from(1_500_000u64));
+        assert_eq!(mock.requests().len(), 1);
     }
 }
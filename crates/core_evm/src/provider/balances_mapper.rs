@@ -3,32 +3,30 @@ use num_bigint::BigUint;
 use num_traits::Zero;
 use primitives::{AssetBalance, AssetId, Balance, Chain};
 use serde_serializers::biguint_from_hex_str;
-use std::error::Error;
 
-pub fn map_balance_coin(balance_hex: String, chain: Chain) -> Result<AssetBalance, Box<dyn Error + Send + Sync>> {
+use crate::EvmError;
+
+pub fn map_balance_coin(balance_hex: String, chain: Chain) -> Result<AssetBalance, EvmError> {
     Ok(AssetBalance::new_balance(
         chain.as_asset_id(),
-        Balance::coin_balance(biguint_from_hex_str(&balance_hex)?),
+        Balance::coin_balance(biguint_from_hex_str(&balance_hex).map_err(EvmError::decode)?),
     ))
 }
 
-pub fn map_balance_tokens(balance_data: Vec<String>, token_ids: Vec<String>, chain: Chain) -> Result<Vec<AssetBalance>, Box<dyn Error + Send + Sync>> {
+pub fn map_balance_tokens(balance_data: Vec<String>, token_ids: Vec<String>, chain: Chain) -> Result<Vec<AssetBalance>, EvmError> {
     if balance_data.len() != token_ids.len() {
-        return Err("Balance data and token IDs length mismatch".into());
+        return Err(EvmError::DecodeError("Balance data and token IDs length mismatch".to_string()));
     }
 
     balance_data
         .into_iter()
         .zip(token_ids)
         .map(|(balance_hex, token_id)| {
-            let asset_id = primitives::AssetId {
-                chain,
-                token_id: Some(token_id),
-            };
-            let balance = serde_serializers::biguint_from_hex_str(&balance_hex)?;
+            let asset_id = primitives::AssetId::from_token(chain, &token_id);
+            let balance = serde_serializers::biguint_from_hex_str(&balance_hex).map_err(EvmError::decode)?;
             Ok(AssetBalance::new_balance(asset_id, Balance::coin_balance(balance)))
         })
-        .collect::<Result<Vec<_>, Box<dyn Error + Send + Sync>>>()
+        .collect()
 }
 
 pub fn map_balance_staking(_staking_data: String) -> Option<AssetBalance> {
@@ -0,0 +1,169 @@
+use crate::arbitrum::{INodeInterface, NODE_INTERFACE_ADDRESS};
+use crate::constants::{FEE_HISTORY_BLOCKS, FEE_HISTORY_REWARD_PERCENTILE};
+use crate::jsonrpc::TransactionRequest;
+use crate::models::fee::{Eip1559Fees, FeePreview};
+use crate::provider::fee_mapper;
+use crate::rpc::client::EthereumClient;
+use crate::{EvmError, address::parse_address};
+use alloy_primitives::{Address, hex};
+use alloy_sol_types::SolCall;
+use core_client::Client;
+use num_bigint::BigUint;
+use primitives::TransactionType;
+use serde_json::json;
+use serde_serializers::biguint_from_hex_str;
+
+impl<C: Client + Clone> EthereumClient<C> {
+    pub async fn estimate_eip1559_fees(&self) -> Result<Eip1559Fees, EvmError> {
+        let fee_history = self.get_fee_history(FEE_HISTORY_BLOCKS, vec![FEE_HISTORY_REWARD_PERCENTILE]).await?;
+        fee_mapper::map_eip1559_fees(&fee_history, self.chain.min_priority_fee())
+    }
+
+    /// 估算 L2 交易中 L1 数据费的部分，非 L2 链返回 0
+    ///
+    /// 该部分已包含在 L2 的 `eth_estimateGas` 结果中，仅作为费用明细；`to` 为空时按合约部署估算
+    pub async fn estimate_l1_data_fee(&self, tx: &TransactionRequest) -> Result<BigUint, EvmError> {
+        if !self.chain.is_ethereum_layer2() {
+            return Ok(BigUint::ZERO);
+        }
+
+        let contract_creation = tx.to.trim_start_matches("0x").is_empty();
+        let call = INodeInterface::gasEstimateL1ComponentCall {
+            to: if contract_creation { Address::ZERO } else { parse_address(&tx.to)? },
+            contractCreation: contract_creation,
+            data: hex::decode(&tx.data)?.into(),
+        };
+        let mut params = json!({
+            "to": NODE_INTERFACE_ADDRESS,
+            "data": hex::encode_prefixed(call.abi_encode())
+        });
+        if let Some(from) = &tx.from {
+            params["from"] = json!(from);
+        }
+
+        let result: String = self.call("eth_call".to_string(), json!([params, "latest"])).await?;
+        let estimate = INodeInterface::gasEstimateL1ComponentCall::abi_decode_returns(&hex::decode(&result)?)?;
+
+        // L1 数据费以 L2 gas 计价，按当前 L2 base fee 折算
+        let base_fee = BigUint::from_bytes_be(&estimate.baseFee.to_be_bytes::<32>());
+        Ok(base_fee * estimate.gasEstimateForL1)
+    }
+
+    pub async fn preview_fee(&self, tx_type: TransactionType, tx: &TransactionRequest) -> Result<FeePreview, EvmError> {
+        let gas_estimate = self.estimate_gas(tx.from.as_deref(), &tx.to, tx.value.as_deref(), Some(&tx.data)).await?;
+        let gas_estimate = biguint_from_hex_str(&gas_estimate).map_err(EvmError::decode)?;
+        let fees = self.estimate_eip1559_fees().await?;
+
+        // 不带 calldata 的原生转账 gas 是确定的，无需放大
+        let gas_limit = if tx.data.trim_start_matches("0x").is_empty() {
+            gas_estimate
+        } else {
+            fee_mapper::apply_gas_limit_multiplier(&gas_estimate, self.gas_limit_multipliers.multiplier(&tx_type))
+        };
+
+        let l1_data_fee = self.estimate_l1_data_fee(tx).await?;
+
+        Ok(fee_mapper::map_fee_preview(gas_limit, &fees, l1_data_fee))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jsonrpc::TransactionObject;
+    use crate::models::fee::GasLimitMultipliers;
+    use core_jsonrpc::{JsonRpcClient, testkit::mock_client};
+    use primitives::EVMChain;
+
+    fn client(gas_estimate: &'static str) -> EthereumClient<core_jsonrpc::testkit::MockClient> {
+        client_for_chain(EVMChain::Ethereum, gas_estimate)
+    }
+
+    fn client_for_chain(chain: EVMChain, gas_estimate: &'static str) -> EthereumClient<core_jsonrpc::testkit::MockClient> {
+        let mock = mock_client(move |method, params| match method {
+            "eth_estimateGas" => Ok(json!(gas_estimate)),
+            "eth_feeHistory" => Ok(json!({
+                "baseFeePerGas": ["0x3b9aca00", "0x77359400"],
+                "gasUsedRatio": [0.5],
+                "oldestBlock": "0x10",
+                "reward": [["0x3b9aca00"]]
+            })),
+            "eth_call" => {
+                assert_eq!(params[0]["to"], NODE_INTERFACE_ADDRESS);
+                // gasEstimateForL1 = 1000, baseFee = 0.1 gwei, l1BaseFeeEstimate = 30 gwei
+                Ok(json!(format!("0x{:064x}{:064x}{:064x}", 1_000u64, 100_000_000u64, 30_000_000_000u64)))
+            }
+            _ => panic!("unexpected method {method}"),
+        });
+        EthereumClient::new(JsonRpcClient::new(mock), chain)
+    }
+
+    #[tokio::test]
+    async fn test_preview_fee_transfer() {
+        let tx = TransactionObject::new_call_to_value("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed", "0xde0b6b3a7640000", vec![]);
+        let preview = client("0x5208").preview_fee(TransactionType::Transfer, &tx).await.unwrap();
+
+        assert_eq!(preview.gas_limit, BigUint::from(21_000u64));
+        assert_eq!(preview.max_priority_fee_per_gas, BigUint::from(1_000_000_000u64));
+        assert_eq!(preview.max_fee_per_gas, BigUint::from(5_000_000_000u64));
+        assert_eq!(preview.l1_data_fee, BigUint::ZERO);
+        assert_eq!(preview.total_fee, BigUint::from(105_000_000_000_000u64));
+    }
+
+    #[tokio::test]
+    async fn test_estimate_l1_data_fee() {
+        let tx = TransactionObject::new_call_to_value("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed", "0xde0b6b3a7640000", vec![]);
+
+        let fee = client_for_chain(EVMChain::Arbitrum, "0x5208").estimate_l1_data_fee(&tx).await.unwrap();
+        assert_eq!(fee, BigUint::from(100_000_000_000u64));
+
+        let fee = client("0x5208").estimate_l1_data_fee(&tx).await.unwrap();
+        assert_eq!(fee, BigUint::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_preview_fee_arbitrum_reports_l1_data_fee() {
+        let tx = TransactionObject::new_call_to_value("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed", "0xde0b6b3a7640000", vec![]);
+        let preview = client_for_chain(EVMChain::Arbitrum, "0x5208").preview_fee(TransactionType::Transfer, &tx).await.unwrap();
+
+        assert_eq!(preview.gas_limit, BigUint::from(21_000u64));
+        assert_eq!(preview.l1_data_fee, BigUint::from(100_000_000_000u64));
+        // L1 部分已包含在 gas 估算中，不重复计入总费用
+        assert_eq!(preview.total_fee, BigUint::from(105_000_000_000_000u64));
+    }
+
+    #[tokio::test]
+    async fn test_estimate_l1_data_fee_contract_creation() {
+        let mock = mock_client(|method, params| {
+            assert_eq!(method, "eth_call");
+            let data = hex::decode(params[0]["data"].as_str().unwrap()).unwrap();
+            let call = INodeInterface::gasEstimateL1ComponentCall::abi_decode(&data).unwrap();
+            assert!(call.contractCreation);
+            assert_eq!(call.to, Address::ZERO);
+            Ok(json!(format!("0x{:064x}{:064x}{:064x}", 2_000u64, 100_000_000u64, 30_000_000_000u64)))
+        });
+        let client = EthereumClient::new(JsonRpcClient::new(mock), EVMChain::Arbitrum);
+        let tx = TransactionObject::new_call("", hex::decode("6080604052").unwrap());
+
+        let fee = client.estimate_l1_data_fee(&tx).await.unwrap();
+        assert_eq!(fee, BigUint::from(200_000_000_000u64));
+    }
+
+    #[tokio::test]
+    async fn test_preview_fee_token_transfer() {
+        let data = hex::decode("a9059cbb0000000000000000000000005aaeb6053f3e94c9b9a09f33669435e7ef1beaed0000000000000000000000000000000000000000000000000000000000000001").unwrap();
+        let tx = TransactionObject::new_call("0xdac17f958d2ee523a2206206994597c13d831ec7", data);
+
+        let preview = client("0xfde8").preview_fee(TransactionType::Transfer, &tx).await.unwrap();
+        assert_eq!(preview.gas_limit, BigUint::from(78_000u64));
+        assert_eq!(preview.total_fee, BigUint::from(390_000_000_000_000u64));
+
+        let multipliers = GasLimitMultipliers::default().with(TransactionType::Transfer, 2.0);
+        let preview = client("0xfde8")
+            .with_gas_limit_multipliers(multipliers)
+            .preview_fee(TransactionType::Transfer, &tx)
+            .await
+            .unwrap();
+        assert_eq!(preview.gas_limit, BigUint::from(130_000u64));
+    }
+}
@@ -0,0 +1,88 @@
+use crate::models::fee::{Eip1559Fees, EthereumFeeHistory, FeePreview};
+use num_bigint::BigUint;
+use serde_serializers::biguint_from_hex_str;
+
+use crate::EvmError;
+
+pub fn map_eip1559_fees(fee_history: &EthereumFeeHistory, min_priority_fee: u64) -> Result<Eip1559Fees, EvmError> {
+    // 最后一项是下一个区块的 base fee
+    let base_fee_per_gas = fee_history
+        .base_fee_per_gas
+        .last()
+        .and_then(|fee| fee.to_biguint())
+        .ok_or_else(|| EvmError::DecodeError("Missing base fee in fee history".to_string()))?;
+
+    let mut rewards = fee_history
+        .reward
+        .iter()
+        .filter_map(|rewards| rewards.first())
+        .map(|reward| biguint_from_hex_str(reward).map_err(EvmError::decode))
+        .collect::<Result<Vec<_>, _>>()?;
+    rewards.sort();
+
+    let min_priority_fee = BigUint::from(min_priority_fee);
+    let max_priority_fee_per_gas = rewards.get(rewards.len() / 2).cloned().unwrap_or_default().max(min_priority_fee);
+    let max_fee_per_gas = &base_fee_per_gas * 2u32 + &max_priority_fee_per_gas;
+
+    Ok(Eip1559Fees {
+        base_fee_per_gas,
+        max_priority_fee_per_gas,
+        max_fee_per_gas,
+    })
+}
+
+pub fn apply_gas_limit_multiplier(gas_limit: &BigUint, multiplier: f64) -> BigUint {
+    let percent = (multiplier * 100.0).round().max(0.0) as u64;
+    gas_limit * percent / 100u32
+}
+
+pub fn map_fee_preview(gas_limit: BigUint, fees: &Eip1559Fees, l1_data_fee: BigUint) -> FeePreview {
+    // L2 的 eth_estimateGas 已包含 L1 部分，l1_data_fee 只用于展示，不重复计入总费用
+    FeePreview {
+        total_fee: &gas_limit * &fees.max_fee_per_gas,
+        gas_limit,
+        l1_data_fee,
+        max_fee_per_gas: fees.max_fee_per_gas.clone(),
+        max_priority_fee_per_gas: fees.max_priority_fee_per_gas.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigInt;
+
+    fn fee_history(base_fees: &[u64], rewards: &[&str]) -> EthereumFeeHistory {
+        EthereumFeeHistory {
+            reward: rewards.iter().map(|reward| vec![reward.to_string()]).collect(),
+            base_fee_per_gas: base_fees.iter().map(|fee| BigInt::from(*fee)).collect(),
+            gas_used_ratio: vec![0.5; rewards.len()],
+            oldest_block: 1,
+        }
+    }
+
+    #[test]
+    fn test_map_eip1559_fees() {
+        let history = fee_history(&[1_000_000_000, 2_000_000_000], &["0x3b9aca00", "0x77359400", "0x1"]);
+        let fees = map_eip1559_fees(&history, 100_000_000).unwrap();
+
+        assert_eq!(fees.base_fee_per_gas, BigUint::from(2_000_000_000u64));
+        assert_eq!(fees.max_priority_fee_per_gas, BigUint::from(1_000_000_000u64));
+        assert_eq!(fees.max_fee_per_gas, BigUint::from(5_000_000_000u64));
+    }
+
+    #[test]
+    fn test_map_eip1559_fees_min_priority_fee() {
+        let history = fee_history(&[1_000_000_000], &[]);
+        let fees = map_eip1559_fees(&history, 100_000_000).unwrap();
+
+        assert_eq!(fees.max_priority_fee_per_gas, BigUint::from(100_000_000u64));
+        assert!(map_eip1559_fees(&fee_history(&[], &[]), 0).is_err());
+    }
+
+    #[test]
+    fn test_apply_gas_limit_multiplier() {
+        assert_eq!(apply_gas_limit_multiplier(&BigUint::from(65_000u64), 1.2), BigUint::from(78_000u64));
+        assert_eq!(apply_gas_limit_multiplier(&BigUint::from(21_000u64), 1.0), BigUint::from(21_000u64));
+    }
+}
@@ -2,7 +2,13 @@ pub mod accounts;
 pub mod balances;
 pub mod balances_mapper;
 pub mod balances_smartchain;
+pub mod fee;
+pub mod fee_mapper;
+pub mod scan;
+pub mod scan_mapper;
 pub mod state;
 pub mod state_mapper;
+pub mod transfers_mapper;
 pub mod staking_ethereum;
-pub mod staking_smartchain;
\ No newline at end of file
+pub mod staking_smartchain;
+pub mod staking_smartchain_mapper;
\ No newline at end of file
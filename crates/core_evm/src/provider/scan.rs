@@ -0,0 +1,54 @@
+use core_client::Client;
+use primitives::{AddressType, ScanAddress, ScanAddressTarget};
+
+use crate::provider::scan_mapper::{map_address_type, map_scan_address};
+use crate::EvmError;
+use crate::rpc::client::EthereumClient;
+
+impl<C: Client + Clone> EthereumClient<C> {
+    pub async fn get_address_type(&self, address: &str) -> Result<AddressType, EvmError> {
+        let code = self.get_code(address).await?;
+        Ok(map_address_type(&code))
+    }
+
+    pub async fn get_scan_address(&self, target: &ScanAddressTarget) -> Result<ScanAddress, EvmError> {
+        let address_type = self.get_address_type(&target.address).await?;
+        Ok(map_scan_address(target, address_type))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core_jsonrpc::{JsonRpcClient, testkit::{MockClient, mock_client}};
+    use primitives::{Chain, EVMChain};
+    use serde_json::json;
+
+    fn client(code: &'static str) -> EthereumClient<MockClient> {
+        let mock = mock_client(move |method, params| {
+            assert_eq!(method, "eth_getCode");
+            assert_eq!(params[1], json!("latest"));
+            Ok(json!(code))
+        });
+        EthereumClient::new(JsonRpcClient::new(mock), EVMChain::Ethereum)
+    }
+
+    #[tokio::test]
+    async fn test_get_address_type_eoa() {
+        let address_type = client("0x").get_address_type("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").await.unwrap();
+        assert!(matches!(address_type, AddressType::Address));
+    }
+
+    #[tokio::test]
+    async fn test_get_scan_address_contract() {
+        let target = ScanAddressTarget {
+            chain: Chain::Ethereum,
+            address: "0xdAC17F958D2ee523a2206206994597C13D831ec7".to_string(),
+        };
+        let scan_address = client("0x606060405260043610610111").get_scan_address(&target).await.unwrap();
+
+        assert_eq!(scan_address.chain, Chain::Ethereum);
+        assert_eq!(scan_address.address, target.address);
+        assert!(matches!(scan_address.address_type, Some(AddressType::Contract)));
+    }
+}
@@ -0,0 +1,35 @@
+use primitives::{AddressType, ScanAddress, ScanAddressTarget};
+
+/// `eth_getCode` 返回非空字节码为合约，否则为普通地址（EOA）
+pub fn map_address_type(code: &str) -> AddressType {
+    let code = code.strip_prefix("0x").unwrap_or(code);
+    if code.is_empty() {
+        AddressType::Address
+    } else {
+        AddressType::Contract
+    }
+}
+
+pub fn map_scan_address(target: &ScanAddressTarget, address_type: AddressType) -> ScanAddress {
+    ScanAddress {
+        chain: target.chain,
+        address: target.address.clone(),
+        name: None,
+        address_type: Some(address_type),
+        is_malicious: None,
+        is_memo_required: None,
+        is_verified: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_address_type() {
+        assert!(matches!(map_address_type("0x"), AddressType::Address));
+        assert!(matches!(map_address_type(""), AddressType::Address));
+        assert!(matches!(map_address_type("0x6080604052"), AddressType::Contract));
+    }
+}
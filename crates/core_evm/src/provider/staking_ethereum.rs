@@ -11,15 +11,7 @@ use crate::everstake::client::get_everstake_staking_apy;
 
 impl<C: Client + Clone> EthereumClient<C> {
     pub async fn get_ethereum_staking_apy(&self) -> Result<Option<f64>, Box<dyn Error + Sync + Send>> {
-        #[cfg(feature = "reqwest")]
-        {
-            get_everstake_staking_apy().await
-        }
-
-        #[cfg(not(feature = "reqwest"))]
-        {
-            Ok(None)
-        }
+        get_everstake_staking_apy().await
     }
 
     pub async fn get_ethereum_validators(&self, apy: f64) -> Result<Vec<DelegationValidator>, Box<dyn Error + Sync + Send>> {
@@ -5,13 +5,14 @@ use core_bsc::stake_hub::{
     HUB_READER_ADDRESS, STAKE_HUB_ADDRESS, decode_delegations_return, decode_undelegations_return, decode_validators_return, encode_delegations_call,
     encode_undelegations_call, encode_validators_call,
 };
+use crate::provider::staking_smartchain_mapper::map_smartchain_validators;
 use core_client::Client;
 use num_bigint::BigUint;
-use primitives::{AssetId, Chain, DelegationBase, DelegationState, DelegationValidator};
+use primitives::{AssetId, DelegationBase, DelegationState, DelegationValidator};
 use std::{error::Error, str::FromStr};
 
 impl<C: Client + Clone> EthereumClient<C> {
-    pub async fn get_smartchain_validators(&self, _apy: f64) -> Result<Vec<DelegationValidator>, Box<dyn Error + Sync + Send>> {
+    pub async fn get_smartchain_validators(&self) -> Result<Vec<DelegationValidator>, Box<dyn Error + Sync + Send>> {
         let limit = self.get_max_elected_validators().await?;
         let call_data = encode_validators_call(0, limit);
 
@@ -27,21 +28,11 @@ impl<C: Client + Clone> EthereumClient<C> {
         let result_data = hex::decode(result)?;
         let validators = decode_validators_return(&result_data)?;
 
-        Ok(validators
-            .into_iter()
-            .map(|v| DelegationValidator {
-                id: v.operator_address.clone(),
-                chain: Chain::SmartChain,
-                name: v.moniker,
-                is_active: !v.jailed,
-                commission: v.commission as f64 / 10000.0,
-                apr: v.apy as f64 / 100.0,
-            })
-            .collect())
+        Ok(map_smartchain_validators(validators))
     }
 
     pub async fn get_smartchain_staking_apy(&self) -> Result<Option<f64>, Box<dyn Error + Sync + Send>> {
-        let validators = self.get_smartchain_validators(0.0).await?;
+        let validators = self.get_smartchain_validators().await?;
         let max_apr = validators
             .into_iter()
             .filter(|validator| validator.is_active)
@@ -58,10 +49,7 @@ impl<C: Client + Clone> EthereumClient<C> {
 
         let mut result = Vec::new();
 
-        let asset_id = AssetId {
-            chain: self.get_chain(),
-            token_id: None,
-        };
+        let asset_id = AssetId::native(self.get_chain());
 
         for delegation in delegations {
             if let Ok(balance) = BigUint::from_str(&delegation.amount) {
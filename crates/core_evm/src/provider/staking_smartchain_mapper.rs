@@ -0,0 +1,90 @@
+use core_bsc::stake_hub::BscValidator;
+use primitives::{Chain, DelegationValidator};
+
+/// StakeHub 中 commission 的精度（基点）
+const COMMISSION_BASIS_POINTS: f64 = 10_000.0;
+/// HubReader 返回的 apy 精度（万分之一，372 -> 3.72%）
+const APY_BASIS_POINTS: f64 = 100.0;
+
+pub fn map_smartchain_validators(validators: Vec<BscValidator>) -> Vec<DelegationValidator> {
+    let mut validators: Vec<DelegationValidator> = validators
+        .into_iter()
+        .map(|v| DelegationValidator {
+            id: v.operator_address,
+            chain: Chain::SmartChain,
+            name: v.moniker,
+            is_active: !v.jailed,
+            commission: v.commission as f64 / COMMISSION_BASIS_POINTS,
+            apr: v.apy as f64 / APY_BASIS_POINTS,
+        })
+        .collect();
+
+    // 链上未返回 APR 的验证者：用其它活跃验证者扣除佣金前的平均奖励率，按自身佣金折算
+    if let Some(reward_rate) = average_reward_rate(&validators) {
+        for validator in validators.iter_mut().filter(|v| v.is_active && v.apr == 0.0) {
+            validator.apr = reward_rate * (1.0 - validator.commission);
+        }
+    }
+    validators
+}
+
+fn average_reward_rate(validators: &[DelegationValidator]) -> Option<f64> {
+    let rates: Vec<f64> = validators
+        .iter()
+        .filter(|v| v.is_active && v.apr > 0.0 && v.commission < 1.0)
+        .map(|v| v.apr / (1.0 - v.commission))
+        .collect();
+    if rates.is_empty() {
+        return None;
+    }
+    Some(rates.iter().sum::<f64>() / rates.len() as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::Address;
+    use alloy_sol_types::SolCall;
+    use core_bsc::stake_hub::{IHubReader, decode_validators_return};
+
+    fn validator(operator: &str, jailed: bool, moniker: &str, commission: u64, apy: u64) -> IHubReader::Validator {
+        IHubReader::Validator {
+            operatorAddress: operator.parse::<Address>().unwrap(),
+            jailed,
+            moniker: moniker.to_string(),
+            commission,
+            apy,
+        }
+    }
+
+    #[test]
+    fn test_map_smartchain_validators_from_tuple() {
+        let encoded = IHubReader::getValidatorsCall::abi_encode_returns(&vec![
+            validator("0x773760b0708a5Cc369c346993a0c225D8e4043B1", false, "Legend", 700, 372),
+            validator("0x343dA7Ff0446247ca47AA41e2A25c5Bbb230ED0A", true, "LegendII", 700, 201),
+        ]);
+        let validators = map_smartchain_validators(decode_validators_return(&encoded).unwrap());
+
+        assert_eq!(validators.len(), 2);
+        assert_eq!(validators[0].id, "0x773760b0708a5Cc369c346993a0c225D8e4043B1");
+        assert_eq!(validators[0].chain, Chain::SmartChain);
+        assert_eq!(validators[0].name, "Legend");
+        assert!(validators[0].is_active);
+        assert_eq!(validators[0].commission, 0.07);
+        assert_eq!(validators[0].apr, 3.72);
+        assert!(!validators[1].is_active);
+    }
+
+    #[test]
+    fn test_map_smartchain_validators_approximates_missing_apr() {
+        let encoded = IHubReader::getValidatorsCall::abi_encode_returns(&vec![
+            validator("0x773760b0708a5Cc369c346993a0c225D8e4043B1", false, "Legend", 2_000, 400),
+            validator("0x343dA7Ff0446247ca47AA41e2A25c5Bbb230ED0A", false, "LegendII", 5_000, 0),
+            validator("0xF2B1d86DC7459887B1f7Ce8d840db1D87613Ce7f", true, "LegendIII", 5_000, 0),
+        ]);
+        let validators = map_smartchain_validators(decode_validators_return(&encoded).unwrap());
+
+        assert_eq!(validators[1].apr, 2.5);
+        assert_eq!(validators[2].apr, 0.0);
+    }
+}
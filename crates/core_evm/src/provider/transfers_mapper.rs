@@ -0,0 +1,103 @@
+use crate::models::TokenTransfer;
+use crate::multicall3::IERC20;
+use crate::rpc::model::{Log, TransactionReciept};
+use alloy_primitives::{Address, B256, U256, hex};
+use alloy_sol_types::SolEvent;
+use num_bigint::BigUint;
+use std::str::FromStr;
+
+/// 从交易回执的日志中解码所有 ERC20 `Transfer` 事件，保持日志顺序
+pub fn decode_erc20_transfers(receipt: &TransactionReciept) -> Vec<TokenTransfer> {
+    receipt.logs.iter().filter_map(decode_erc20_transfer).collect()
+}
+
+fn decode_erc20_transfer(log: &Log) -> Option<TokenTransfer> {
+    // ERC721 的 Transfer 签名相同，但 tokenId 也是 indexed（共 4 个 topic），需要排除
+    if log.topics.len() != 3 || B256::from_str(&log.topics[0]).ok()? != IERC20::Transfer::SIGNATURE_HASH {
+        return None;
+    }
+
+    let from = Address::from_word(B256::from_str(&log.topics[1]).ok()?);
+    let to = Address::from_word(B256::from_str(&log.topics[2]).ok()?);
+    let value = U256::try_from_be_slice(&hex::decode(&log.data).ok()?)?;
+
+    Some(TokenTransfer {
+        contract: Address::from_str(&log.address).ok()?.to_string(),
+        from: from.to_string(),
+        to: to.to_string(),
+        value: BigUint::from_bytes_be(&value.to_be_bytes::<32>()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const TRANSFER_TOPIC: &str = "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+    const USDT: &str = "0xdAC17F958D2ee523a2206206994597C13D831ec7";
+    const USDC: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+    const SENDER: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+    const RECIPIENT: &str = "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359";
+
+    fn topic(address: &str) -> String {
+        format!("0x{:0>64}", address.trim_start_matches("0x").to_lowercase())
+    }
+
+    fn transfer_log(contract: &str, from: &str, to: &str, value: u64) -> serde_json::Value {
+        json!({
+            "address": contract.to_lowercase(),
+            "topics": [TRANSFER_TOPIC, topic(from), topic(to)],
+            "data": format!("0x{value:064x}")
+        })
+    }
+
+    #[test]
+    fn test_decode_erc20_transfers() {
+        let receipt: TransactionReciept = serde_json::from_value(json!({
+            "gasUsed": "0x5208",
+            "effectiveGasPrice": "0x3b9aca00",
+            "status": "0x1",
+            "blockNumber": "0x10",
+            "logs": [
+                transfer_log(USDT, SENDER, RECIPIENT, 1_500_000),
+                // Approval 事件应被忽略
+                {
+                    "address": USDC.to_lowercase(),
+                    "topics": ["0x8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925", topic(SENDER), topic(RECIPIENT)],
+                    "data": format!("0x{:064x}", 1)
+                },
+                transfer_log(USDC, RECIPIENT, SENDER, 42),
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(
+            decode_erc20_transfers(&receipt),
+            vec![
+                TokenTransfer {
+                    contract: USDT.to_string(),
+                    from: SENDER.to_string(),
+                    to: RECIPIENT.to_string(),
+                    value: BigUint::from(1_500_000u64),
+                },
+                TokenTransfer {
+                    contract: USDC.to_string(),
+                    from: RECIPIENT.to_string(),
+                    to: SENDER.to_string(),
+                    value: BigUint::from(42u64),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_erc20_transfers_skips_erc721() {
+        let mut log = transfer_log(USDT, SENDER, RECIPIENT, 0);
+        log["topics"].as_array_mut().unwrap().push(json!(format!("0x{:064x}", 7)));
+        log["data"] = json!("0x");
+        let log: Log = serde_json::from_value(log).unwrap();
+
+        assert_eq!(decode_erc20_transfer(&log), None);
+    }
+}
@@ -1,11 +1,11 @@
-use std::error::Error;
-
 use core_client::Client;
 use core_jsonrpc::client::JsonRpcClient as GenericJsonRpcClient;
-use primitives::EVMChain;
+use primitives::{AssetBalance, EVMChain};
 use serde_json::json;
 
-use crate::rpc::ankr::model::{TokenBalances, Transactions, ankr_chain};
+use crate::EvmError;
+use crate::rpc::ankr::AnkrMapper;
+use crate::rpc::ankr::model::{TokenBalances, Transaction, Transactions, ankr_chain};
 
 #[derive(Debug, Clone)]
 pub struct AnkrClient<C: Client + Clone> {
@@ -21,7 +21,7 @@ impl<C: Client + Clone> AnkrClient<C> {
 
 impl<C: Client + Clone> AnkrClient<C> {
     /// Reference: https://www.ankr.com/docs/advanced-api/query-methods/#ankr_gettransactionsbyaddress
-    pub async fn get_ankr_transactions_by_address(&self, address: &str, limit: Option<usize>) -> Result<Transactions, Box<dyn Error + Send + Sync>> {
+    pub async fn get_ankr_transactions_by_address(&self, address: &str, limit: Option<usize>) -> Result<Transactions, EvmError> {
         if let Some(chain) = ankr_chain(self.chain) {
             let params = serde_json::json!({
                 "address": address,
@@ -35,8 +35,13 @@ impl<C: Client + Clone> AnkrClient<C> {
         }
     }
 
+    /// 按时间倒序返回地址最近的 `page_size` 笔交易，Ankr 不支持的链返回空列表
+    pub async fn get_transactions(&self, address: &str, page_size: u32) -> Result<Vec<Transaction>, EvmError> {
+        Ok(self.get_ankr_transactions_by_address(address, Some(page_size as usize)).await?.transactions)
+    }
+
     /// Reference: https://www.ankr.com/docs/advanced-api/token-methods/#ankr_getaccountbalance
-    pub async fn get_token_balances(&self, address: &str) -> Result<TokenBalances, Box<dyn Error + Send + Sync>> {
+    pub async fn get_token_balances(&self, address: &str) -> Result<TokenBalances, EvmError> {
         if let Some(chain) = ankr_chain(self.chain) {
             let params = json!([
                 {
@@ -51,4 +56,79 @@ impl<C: Client + Clone> AnkrClient<C> {
             Ok(TokenBalances { assets: vec![] })
         }
     }
+
+    /// 一次请求查询地址在所有 Ankr 支持的 EVM 链上的余额
+    pub async fn get_token_balances_all_chains(&self, address: &str) -> Result<Vec<AssetBalance>, EvmError> {
+        let chains: Vec<String> = EVMChain::all().into_iter().filter_map(ankr_chain).collect();
+        let params = json!([
+            {
+                "walletAddress": address,
+                "blockchain": chains,
+                "onlyWhitelisted": true,
+            }
+        ]);
+
+        let balances: TokenBalances = self.rpc_client.call("ankr_getAccountBalance", params).await?;
+        Ok(AnkrMapper::map_token_balances_all_chains(balances.assets))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core_jsonrpc::{JsonRpcClient, testkit::mock_client};
+    use num_bigint::BigUint;
+    use primitives::{AssetId, Chain};
+
+    #[tokio::test]
+    async fn test_get_transactions() {
+        let mock = mock_client(|method, params| {
+            assert_eq!(method, "ankr_getTransactionsByAddress");
+            assert_eq!(params["blockchain"], json!("arbitrum"));
+            assert_eq!(params["pageSize"], json!(2));
+            Ok(json!({
+                "transactions": [
+                    { "hash": "0x01", "timestamp": "0x6553f100", "blockNumber": "0x10" },
+                    { "hash": "0x02", "timestamp": "0x6553f0f0", "blockNumber": "0x0f" }
+                ],
+                "nextPageToken": ""
+            }))
+        });
+        let client = AnkrClient::new(JsonRpcClient::new(mock), EVMChain::Arbitrum);
+
+        let transactions = client.get_transactions("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed", 2).await.unwrap();
+        assert_eq!(transactions.len(), 2);
+        assert_eq!(transactions[0].hash, "0x01");
+        assert_eq!(transactions[0].timestamp, BigUint::from(1_700_000_000u64));
+        assert_eq!(transactions[1].hash, "0x02");
+        assert_eq!(transactions[1].timestamp, BigUint::from(1_699_999_984u64));
+    }
+
+    #[tokio::test]
+    async fn test_get_token_balances_all_chains() {
+        let mock = mock_client(|method, params| {
+            assert_eq!(method, "ankr_getAccountBalance");
+            assert_eq!(params[0]["blockchain"], json!(["eth", "bsc", "polygon", "arbitrum"]));
+            Ok(json!({
+                "assets": [
+                    { "blockchain": "eth", "tokenType": "NATIVE", "balanceRawInteger": "1000000000000000000" },
+                    { "blockchain": "eth", "tokenType": "ERC20", "contractAddress": "0xdac17f958d2ee523a2206206994597c13d831ec7", "balanceRawInteger": "1500000" },
+                    { "blockchain": "arbitrum", "tokenType": "ERC20", "contractAddress": "0xaf88d065e77c8cc2239327c5edb3a432268e5831", "balanceRawInteger": "2000000" },
+                    { "blockchain": "polygon", "tokenType": "ERC20", "contractAddress": "0x3c499c542cef5e3811e1192ce70d8cc03d5c3359", "balanceRawInteger": "0" }
+                ]
+            }))
+        });
+        let client = AnkrClient::new(JsonRpcClient::new(mock), EVMChain::Ethereum);
+
+        let balances = client.get_token_balances_all_chains("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").await.unwrap();
+        let balances: Vec<_> = balances.into_iter().map(|balance| (balance.asset_id, balance.balance.available)).collect();
+        assert_eq!(
+            balances,
+            vec![
+                (Chain::Ethereum.as_asset_id(), BigUint::from(1_000_000_000_000_000_000u64)),
+                (AssetId::from_token(Chain::Ethereum, "0xdAC17F958D2ee523a2206206994597C13D831ec7"), BigUint::from(1_500_000u64)),
+                (AssetId::from_token(Chain::Arbitrum, "0xaf88d065e77c8cC2239327C5EDb3A432268e5831"), BigUint::from(2_000_000u64)),
+            ]
+        );
+    }
 }
@@ -1,4 +1,8 @@
-use crate::rpc::ankr::Transaction;
+use crate::ethereum_address_checksum;
+use crate::rpc::ankr::model::chain_from_ankr;
+use crate::rpc::ankr::{TokenBalance, Transaction};
+use num_traits::Zero;
+use primitives::{AssetBalance, AssetId, Balance};
 
 pub struct AnkrMapper {}
 
@@ -6,4 +10,22 @@ impl AnkrMapper {
     pub fn map_transactions_ids(transactions: Vec<Transaction>) -> Vec<String> {
         transactions.into_iter().map(|x| x.hash).collect()
     }
+
+    /// 按资产所属链映射余额，原生币使用链的 `AssetId`，忽略零余额和不支持的链
+    pub fn map_token_balances_all_chains(assets: Vec<TokenBalance>) -> Vec<AssetBalance> {
+        assets
+            .into_iter()
+            .filter_map(|asset| {
+                if asset.balance_raw_integer.is_zero() {
+                    return None;
+                }
+                let chain = chain_from_ankr(asset.blockchain.as_deref()?)?.to_chain();
+                let asset_id = match asset.contract_address {
+                    Some(address) => AssetId::from_token(chain, &ethereum_address_checksum(&address).ok()?),
+                    None => chain.as_asset_id(),
+                };
+                Some(AssetBalance::new_balance(asset_id, Balance::coin_balance(asset.balance_raw_integer)))
+            })
+            .collect()
+    }
 }
@@ -26,6 +26,9 @@ pub struct TokenBalances {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TokenBalance {
+    // 多链查询时用于区分资产所属的链
+    #[serde(default)]
+    pub blockchain: Option<String>,
     pub contract_address: Option<String>,
     #[serde(deserialize_with = "deserialize_biguint_from_str")]
     pub balance_raw_integer: BigUint,
@@ -39,3 +42,7 @@ pub fn ankr_chain(chain: EVMChain) -> Option<String> {
         EVMChain::Arbitrum => Some("arbitrum".to_string()),
     }
 }
+
+pub fn chain_from_ankr(blockchain: &str) -> Option<EVMChain> {
+    EVMChain::all().into_iter().find(|chain| ankr_chain(*chain).as_deref() == Some(blockchain))
+}
@@ -1,14 +1,13 @@
-use alloy_primitives::{Address, Bytes, hex};
+use alloy_primitives::{Bytes, hex};
 use core_client::Client;
 use core_jsonrpc::client::JsonRpcClient as GenericJsonRpcClient;
 use core_jsonrpc::types::{ERROR_INTERNAL_ERROR, JsonRpcError, JsonRpcResult};
 
-use num_bigint::{BigInt, Sign};
+use num_bigint::{BigInt, BigUint, Sign};
 use serde::de::DeserializeOwned;
 use serde_json::json;
 use serde_serializers::biguint_from_hex_str;
 use std::any::TypeId;
-use std::str::FromStr;
 
 use super::{
     ankr::AnkrClient,
@@ -17,12 +16,16 @@ use super::{
         TransactionReplayTrace,
     },
 };
-use crate::models::fee::EthereumFeeHistory;
+use crate::address::parse_address;
+use crate::jsonrpc::TransactionRequest;
+use crate::models::BlockTag;
+use crate::models::fee::{EthereumFeeHistory, GasLimitMultipliers};
 use crate::multicall3::{
-    IMulticall3,
+    IERC20, IMulticall3,
     IMulticall3::{Call3, Result as MulticallResult},
-    deployment_by_chain,
+    create_call3, decode_call3_return,
 };
+use crate::EvmError;
 use alloy_sol_types::SolCall;
 use primitives::{Chain, EVMChain, NodeType};
 
@@ -36,6 +39,7 @@ pub struct EthereumClient<C: Client + Clone> {
     pub client: GenericJsonRpcClient<C>,
     pub(crate) node_type: NodeType,
     pub(crate) ankr_client: Option<AnkrClient<C>>,
+    pub(crate) gas_limit_multipliers: GasLimitMultipliers,
 }
 
 impl<C: Client + Clone> EthereumClient<C> {
@@ -45,6 +49,7 @@ impl<C: Client + Clone> EthereumClient<C> {
             client,
             node_type: NodeType::Default,
             ankr_client: None,
+            gas_limit_multipliers: GasLimitMultipliers::default(),
         }
     }
 
@@ -58,6 +63,11 @@ impl<C: Client + Clone> EthereumClient<C> {
         self
     }
 
+    pub fn with_gas_limit_multipliers(mut self, gas_limit_multipliers: GasLimitMultipliers) -> Self {
+        self.gas_limit_multipliers = gas_limit_multipliers;
+        self
+    }
+
     pub fn get_chain(&self) -> Chain {
         self.chain.to_chain()
     }
@@ -86,8 +96,8 @@ impl<C: Client + Clone> EthereumClient<C> {
         &self,
         contract_address: &str,
         call_data: &str,
-    ) -> Result<T, Box<dyn std::error::Error + Send + Sync>> {
-        let to_address = Address::from_str(contract_address)?;
+    ) -> Result<T, EvmError> {
+        let to_address = parse_address(contract_address)?;
 
         let params = json!([
             {
@@ -110,9 +120,29 @@ impl<C: Client + Clone> EthereumClient<C> {
         }
     }
 
-    pub async fn get_block(&self, block_number: u64) -> Result<Block, JsonRpcError> {
-        let params = json!([format!("0x{:x}", block_number), true]);
-        self.client.call("eth_getBlockByNumber", params).await
+    /// 在指定区块上执行 `eth_call`，返回十六进制格式的调用结果
+    ///
+    /// 指定区块号或区块哈希的历史查询要求节点类型为 `NodeType::Archival`
+    pub async fn call_at(&self, tx: &TransactionRequest, block: BlockTag) -> Result<String, EvmError> {
+        if block.is_historical() && self.node_type != NodeType::Archival {
+            return Err(EvmError::Unsupported(format!("eth_call at {block:?} requires an archival node")));
+        }
+
+        let params = json!([tx, serde_json::Value::from(&block)]);
+        Ok(self.client.call("eth_call", params).await?)
+    }
+
+    /// 获取区块，`full_tx` 为 `true` 时返回完整交易，否则只返回交易哈希；区块不存在时为 `None`
+    pub async fn get_block(&self, block: BlockTag, full_tx: bool) -> Result<Option<Block>, JsonRpcError> {
+        match block {
+            BlockTag::Hash(hash) => self.client.call("eth_getBlockByHash", json!([hash, full_tx])).await,
+            block => self.client.call("eth_getBlockByNumber", json!([serde_json::Value::from(&block), full_tx])).await,
+        }
+    }
+
+    /// 按哈希查询交易，交易不存在时为 `None`
+    pub async fn get_transaction(&self, hash: &str) -> Result<Option<Transaction>, JsonRpcError> {
+        self.client.call("eth_getTransactionByHash", json!([hash])).await
     }
 
     pub async fn get_block_receipts(
@@ -123,7 +153,7 @@ impl<C: Client + Clone> EthereumClient<C> {
         self.client.call("eth_getBlockReceipts", params).await
     }
 
-    pub async fn get_latest_block(&self) -> Result<u64, Box<dyn std::error::Error + Send + Sync>> {
+    pub async fn get_latest_block(&self) -> Result<u64, EvmError> {
         let block_hex: String = self.client.call("eth_blockNumber", json!([])).await?;
         let block_hex = block_hex.trim_start_matches("0x");
         Ok(u64::from_str_radix(block_hex, 16)?)
@@ -181,9 +211,9 @@ impl<C: Client + Clone> EthereumClient<C> {
 
         Ok(blocks
             .into_iter()
-            .zip(transactions.into_iter())
-            .zip(reciepts.into_iter())
-            .zip(traces.into_iter())
+            .zip(transactions)
+            .zip(reciepts)
+            .zip(traces)
             .map(|(((block, tx), receipt), trace)| (block, tx, receipt, trace))
             .collect())
     }
@@ -266,6 +296,24 @@ impl<C: Client + Clone> EthereumClient<C> {
         self.client.call("eth_getBalance", params).await
     }
 
+    /// 查询地址在指定区块的原生币余额，需要归档节点
+    pub async fn get_balance_at_block(&self, address: &str, block: u64) -> Result<BigUint, EvmError> {
+        let params = json!([address, serde_json::Value::from(&BlockTag::Number(block))]);
+        let balance: String = self.client.call("eth_getBalance", params).await.map_err(|error| {
+            if is_state_unavailable(&error) {
+                EvmError::Unsupported(format!("State at block {block} is unavailable, an archival node is required: {}", error.message))
+            } else {
+                EvmError::from(error)
+            }
+        })?;
+        biguint_from_hex_str(&balance).map_err(EvmError::decode)
+    }
+
+    pub async fn get_code(&self, address: &str) -> Result<String, JsonRpcError> {
+        let params = json!([address, "latest"]);
+        self.client.call("eth_getCode", params).await
+    }
+
     pub async fn gas_price(&self) -> Result<BigInt, JsonRpcError> {
         let value: String = self.client.call("eth_gasPrice", json!([])).await?;
         let biguint = biguint_from_hex_str(&value).map_err(|_| JsonRpcError {
@@ -301,7 +349,7 @@ impl<C: Client + Clone> EthereumClient<C> {
         &self,
         contract_address: &str,
         function_selectors: [&str; N],
-    ) -> Result<[String; N], Box<dyn std::error::Error + Sync + Send>> {
+    ) -> Result<[String; N], EvmError> {
         let calls: Vec<(String, serde_json::Value)> = function_selectors
             .iter()
             .map(|selector| {
@@ -314,7 +362,7 @@ impl<C: Client + Clone> EthereumClient<C> {
         let results = self.client.batch_call::<String>(calls).await?.extract();
         results
             .try_into()
-            .map_err(|_| "Array conversion failed".into())
+            .map_err(|_| EvmError::DecodeError("Array conversion failed".to_string()))
     }
 
     pub async fn get_fee_history(
@@ -330,7 +378,7 @@ impl<C: Client + Clone> EthereumClient<C> {
         &self,
         address: &str,
         contracts: &[String],
-    ) -> Result<Vec<String>, Box<dyn std::error::Error + Sync + Send>> {
+    ) -> Result<Vec<String>, EvmError> {
         let data = format!(
             "0x70a08231000000000000000000000000{:0>40}",
             address.strip_prefix("0x").unwrap_or(address)
@@ -347,6 +395,31 @@ impl<C: Client + Clone> EthereumClient<C> {
         Ok(self.client.batch_call::<String>(calls).await?.extract())
     }
 
+    /// 通过 Multicall3 `aggregate3` 在一次 `eth_call` 中查询多个 ERC20 余额
+    ///
+    /// 返回值与 `batch_token_balance_calls` 格式一致（十六进制字符串），顺序与 `contracts` 对应。
+    pub async fn multicall_token_balance_calls(
+        &self,
+        address: &str,
+        contracts: &[String],
+    ) -> Result<Vec<String>, EvmError> {
+        let account = parse_address(address)?;
+        let calls = contracts
+            .iter()
+            .map(|contract| parse_address(contract).map(|target| create_call3(&target.to_string(), IERC20::balanceOfCall { account })))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let results = self.multicall3(calls).await?;
+        if results.len() != contracts.len() {
+            return Err(EvmError::DecodeError("Unexpected number of multicall results".to_string()));
+        }
+
+        results
+            .iter()
+            .map(|result| decode_call3_return::<IERC20::balanceOfCall>(result).map(|balance| format!("{balance:#x}")))
+            .collect()
+    }
+
     pub async fn estimate_gas(
         &self,
         from: Option<&str>,
@@ -376,8 +449,11 @@ impl<C: Client + Clone> EthereumClient<C> {
     pub async fn multicall3(
         &self,
         calls: Vec<Call3>,
-    ) -> Result<Vec<MulticallResult>, Box<dyn std::error::Error + Sync + Send>> {
-        let multicall_address = deployment_by_chain(&self.chain);
+    ) -> Result<Vec<MulticallResult>, EvmError> {
+        let multicall_address = self
+            .chain
+            .multicall3_address()
+            .ok_or_else(|| EvmError::Unsupported(format!("Multicall3 is not deployed on {}", self.chain.as_ref())))?;
         let multicall_data = IMulticall3::aggregate3Call { calls }.abi_encode();
 
         let call = (
@@ -390,9 +466,258 @@ impl<C: Client + Clone> EthereumClient<C> {
 
         let result: String = self.call(call.0, call.1).await?;
         let result_data = hex::decode(&result)?;
-        let multicall_results = IMulticall3::aggregate3Call::abi_decode_returns(&result_data)
-            .map_err(|e| Box::new(e) as Box<dyn std::error::Error + Sync + Send>)?;
+        let multicall_results = IMulticall3::aggregate3Call::abi_decode_returns(&result_data)?;
 
         Ok(multicall_results)
     }
 }
+
+// 非归档节点查询已裁剪的历史状态时返回的错误
+fn is_state_unavailable(error: &JsonRpcError) -> bool {
+    let message = error.message.to_lowercase();
+    message.contains("missing trie node") || message.contains("state unavailable") || message.contains("state is not available")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jsonrpc::TransactionObject;
+    use crate::rpc::model::BlockTransactions;
+    use core_jsonrpc::{JsonRpcClient, testkit::{MockClient, mock_client}};
+
+    const RESULT: &str = "0x0000000000000000000000000000000000000000000000000000000000000001";
+
+    fn client(node_type: NodeType) -> (EthereumClient<MockClient>, MockClient) {
+        let mock = mock_client(|method, _params| {
+            assert_eq!(method, "eth_call");
+            Ok(json!(RESULT))
+        });
+        let client = EthereumClient::new(JsonRpcClient::new(mock.clone()), EVMChain::Ethereum).with_node_type(node_type);
+        (client, mock)
+    }
+
+    fn tx() -> TransactionObject {
+        TransactionObject::new_call("0xdac17f958d2ee523a2206206994597c13d831ec7", hex::decode("18160ddd").unwrap())
+    }
+
+    fn last_params(mock: &MockClient) -> serde_json::Value {
+        mock.requests().last().unwrap().body.as_ref().unwrap()["params"].clone()
+    }
+
+    #[tokio::test]
+    async fn test_call_at_latest() {
+        let (client, mock) = client(NodeType::Default);
+
+        assert_eq!(client.call_at(&tx(), BlockTag::Latest).await.unwrap(), RESULT);
+        assert_eq!(
+            last_params(&mock),
+            json!([{"to": "0xdac17f958d2ee523a2206206994597c13d831ec7", "data": "0x18160ddd"}, "latest"])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_call_at_block_number() {
+        let (client, mock) = client(NodeType::Archival);
+
+        assert_eq!(client.call_at(&tx(), BlockTag::Number(18_000_000)).await.unwrap(), RESULT);
+        assert_eq!(last_params(&mock)[1], json!("0x112a880"));
+
+        client.call_at(&tx(), BlockTag::Hash("0xabc".to_string())).await.unwrap();
+        assert_eq!(last_params(&mock)[1], json!({"blockHash": "0xabc"}));
+    }
+
+    #[tokio::test]
+    async fn test_call_at_historical_requires_archival_node() {
+        let (client, mock) = client(NodeType::Default);
+
+        let error = client.call_at(&tx(), BlockTag::Number(18_000_000)).await.unwrap_err();
+        assert!(error.to_string().contains("archival"));
+        assert!(matches!(error, EvmError::Unsupported(_)));
+        assert!(mock.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_call_at_reverted() {
+        let mock = mock_client(|_method, _params| {
+            Err(JsonRpcError {
+                code: 3,
+                message: "execution reverted: ERC20: transfer amount exceeds balance".to_string(),
+            })
+        });
+        let client = EthereumClient::new(JsonRpcClient::new(mock), EVMChain::Ethereum);
+
+        let error = client.call_at(&tx(), BlockTag::Latest).await.unwrap_err();
+        assert!(matches!(error, EvmError::Reverted(msg) if msg.contains("transfer amount exceeds balance")));
+    }
+
+    #[tokio::test]
+    async fn test_multicall3_decode_error() {
+        let mock = mock_client(|_method, _params| Ok(json!("0x1234")));
+        let client = EthereumClient::new(JsonRpcClient::new(mock), EVMChain::Ethereum);
+
+        let error = client.multicall3(vec![]).await.unwrap_err();
+        assert!(matches!(error, EvmError::DecodeError(_)));
+    }
+
+    fn balance_client(result: Result<serde_json::Value, JsonRpcError>) -> (EthereumClient<MockClient>, MockClient) {
+        let mock = mock_client(move |method, _params| {
+            assert_eq!(method, "eth_getBalance");
+            result.clone()
+        });
+        (EthereumClient::new(JsonRpcClient::new(mock.clone()), EVMChain::Ethereum), mock)
+    }
+
+    #[tokio::test]
+    async fn test_get_balance_at_block() {
+        let (client, mock) = balance_client(Ok(json!("0xde0b6b3a7640000")));
+
+        let balance = client.get_balance_at_block("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed", 15_000_000).await.unwrap();
+        assert_eq!(balance, BigUint::from(1_000_000_000_000_000_000u64));
+        assert_eq!(last_params(&mock), json!(["0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed", "0xe4e1c0"]));
+    }
+
+    #[tokio::test]
+    async fn test_get_balance_at_block_non_archival() {
+        let (client, _) = balance_client(Err(JsonRpcError {
+            code: -32000,
+            message: "missing trie node 3f2a (path ) state 0x3f2a is not available".to_string(),
+        }));
+
+        let error = client.get_balance_at_block("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed", 1).await.unwrap_err();
+        assert!(error.to_string().contains("archival node is required"));
+    }
+
+    fn block_client() -> (EthereumClient<MockClient>, MockClient) {
+        let mock = mock_client(|_, params| {
+            let transactions = if params[1] == json!(true) {
+                json!([{
+                    "from": "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed",
+                    "gas": "0x5208",
+                    "nonce": "0x7",
+                    "hash": "0x01",
+                    "input": "0x",
+                    "to": "0xdac17f958d2ee523a2206206994597c13d831ec7",
+                    "blockNumber": "0x10",
+                    "value": "0x1"
+                }])
+            } else {
+                json!(["0x01"])
+            };
+            Ok(json!({
+                "number": "0x10",
+                "hash": "0xaa",
+                "parentHash": "0xbb",
+                "timestamp": "0x64",
+                "transactions": transactions
+            }))
+        });
+        (EthereumClient::new(JsonRpcClient::new(mock.clone()), EVMChain::Ethereum), mock)
+    }
+
+    #[tokio::test]
+    async fn test_get_block_hashes() {
+        let (client, mock) = block_client();
+        let block = client.get_block(BlockTag::Number(16), false).await.unwrap().unwrap();
+
+        assert_eq!(mock.requests().last().unwrap().body.as_ref().unwrap()["method"], "eth_getBlockByNumber");
+        assert_eq!(last_params(&mock), json!(["0x10", false]));
+        assert_eq!(block.number, BigUint::from(16u32));
+        assert_eq!(block.hash.as_deref(), Some("0xaa"));
+        assert_eq!(block.parent_hash, "0xbb");
+        assert_eq!(block.timestamp, BigUint::from(100u32));
+        assert!(matches!(block.transactions, BlockTransactions::Hashes(hashes) if hashes == vec!["0x01".to_string()]));
+    }
+
+    #[tokio::test]
+    async fn test_get_block_full_transactions() {
+        let (client, mock) = block_client();
+        let block = client.get_block(BlockTag::Latest, true).await.unwrap().unwrap();
+
+        assert_eq!(last_params(&mock), json!(["latest", true]));
+        match block.transactions {
+            BlockTransactions::Full(transactions) => {
+                assert_eq!(transactions.len(), 1);
+                assert_eq!(transactions[0].hash, "0x01");
+                assert_eq!(transactions[0].gas, 21_000);
+            }
+            BlockTransactions::Hashes(_) => panic!("expected full transactions"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_block_by_hash() {
+        let (client, mock) = block_client();
+        let block = client.get_block(BlockTag::Hash("0xaa".to_string()), false).await.unwrap().unwrap();
+
+        assert_eq!(mock.requests().last().unwrap().body.as_ref().unwrap()["method"], "eth_getBlockByHash");
+        assert_eq!(last_params(&mock), json!(["0xaa", false]));
+        assert!(matches!(block.transactions, BlockTransactions::Hashes(hashes) if hashes == vec!["0x01".to_string()]));
+    }
+
+    #[tokio::test]
+    async fn test_get_block_pending() {
+        let mock = mock_client(|_, _| {
+            Ok(json!({
+                "number": "0x11",
+                "hash": null,
+                "parentHash": "0xaa",
+                "timestamp": "0x65",
+                "transactions": []
+            }))
+        });
+        let client = EthereumClient::new(JsonRpcClient::new(mock.clone()), EVMChain::Ethereum);
+        let block = client.get_block(BlockTag::Pending, false).await.unwrap().unwrap();
+
+        assert_eq!(last_params(&mock), json!(["pending", false]));
+        assert_eq!(block.number, BigUint::from(17u32));
+        assert_eq!(block.hash, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_block_not_found() {
+        let mock = mock_client(|_, _| Ok(serde_json::Value::Null));
+        let client = EthereumClient::new(JsonRpcClient::new(mock), EVMChain::Ethereum);
+
+        assert!(client.get_block(BlockTag::Number(u64::MAX), false).await.unwrap().is_none());
+    }
+
+    fn transaction_json(block_number: serde_json::Value) -> serde_json::Value {
+        json!({
+            "from": "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed",
+            "to": "0xdac17f958d2ee523a2206206994597c13d831ec7",
+            "value": "0xde0b6b3a7640000",
+            "nonce": "0x2a",
+            "gas": "0xfde8",
+            "hash": "0x01",
+            "input": "0xa9059cbb",
+            "blockNumber": block_number
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction() {
+        let mock = mock_client(|method, params| {
+            assert_eq!(method, "eth_getTransactionByHash");
+            match params[0].as_str().unwrap() {
+                "0xpending" => Ok(transaction_json(json!(null))),
+                "0xmined" => Ok(transaction_json(json!("0x112a880"))),
+                _ => Ok(json!(null)),
+            }
+        });
+        let client = EthereumClient::new(JsonRpcClient::new(mock), EVMChain::Ethereum);
+
+        let pending = client.get_transaction("0xpending").await.unwrap().unwrap();
+        assert_eq!(pending.block_number, None);
+        assert_eq!(pending.nonce, 42);
+        assert_eq!(pending.gas, 65_000);
+        assert_eq!(pending.input, "0xa9059cbb");
+        assert_eq!(pending.to.as_deref(), Some("0xdac17f958d2ee523a2206206994597c13d831ec7"));
+        assert_eq!(pending.value, BigUint::from(1_000_000_000_000_000_000u64));
+
+        let mined = client.get_transaction("0xmined").await.unwrap().unwrap();
+        assert_eq!(mined.block_number, Some(BigUint::from(18_000_000u64)));
+        assert_eq!(mined.from, "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed");
+
+        assert!(client.get_transaction("0xunknown").await.unwrap().is_none());
+    }
+}
@@ -23,9 +23,22 @@ pub struct EthSyncingInfo {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Block {
-    pub transactions: Vec<Transaction>,
+    #[serde(deserialize_with = "deserialize_biguint_from_hex_str")]
+    pub number: BigUint,
+    // pending 区块尚未确定哈希，此时为 None
+    pub hash: Option<String>,
+    pub parent_hash: String,
     #[serde(deserialize_with = "deserialize_biguint_from_hex_str")]
     pub timestamp: BigUint,
+    pub transactions: BlockTransactions,
+}
+
+// `eth_getBlockBy*` 的第二个参数决定返回交易哈希还是完整交易
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum BlockTransactions {
+    Hashes(Vec<String>),
+    Full(Vec<Transaction>),
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -48,8 +61,11 @@ pub struct Transaction {
     pub hash: String,
     pub input: String,
     pub to: Option<String>,
-    #[serde(rename = "blockNumber", default, deserialize_with = "deserialize_biguint_from_hex_str")]
-    pub block_number: BigUint,
+    #[serde(deserialize_with = "deserialize_u64_from_str_or_int")]
+    pub nonce: u64,
+    // 交易未打包时为 None
+    #[serde(default, deserialize_with = "deserialize_biguint_from_option_hex_str")]
+    pub block_number: Option<BigUint>,
     #[serde(deserialize_with = "deserialize_biguint_from_hex_str")]
     pub value: BigUint,
     // #[serde(rename = "type")]
@@ -1,16 +1,36 @@
 use crate::types::{ERROR_INTERNAL_ERROR, JsonRpcError, JsonRpcRequest, JsonRpcRequestConvert, JsonRpcResult, JsonRpcResults};
-use core_client::{Client, ClientError};
+use core_client::{Client, ClientError, DEFAULT_RETRY_DELAY, retry_with_delay};
+use primitives::Chain;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 #[cfg(feature = "reqwest")]
 use std::error::Error;
-use std::time::SystemTime;
+use std::{
+    collections::{BTreeSet, HashMap},
+    sync::{
+        Arc, LazyLock, Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::{Duration, Instant, SystemTime},
+};
+use tracing::{Instrument, Span, field};
 
 pub type CallTuple = (String, Value);
 
+/// 批量请求的最小分片大小
+pub const MIN_BATCH_SIZE: usize = 1;
+
+// 按节点 host 记录的可用批量大小，同一 host 的所有客户端共享
+static HOST_BATCH_SIZES: LazyLock<Mutex<HashMap<String, Arc<AtomicUsize>>>> = LazyLock::new(Default::default);
+
 #[derive(Clone, Debug)]
 pub struct JsonRpcClient<C: Client + Clone> {
     client: C,
+    // 节点可接受的批量大小，被拒绝后减半；在克隆之间共享，设置 host 后在同一 host 的客户端之间共享
+    batch_size: Arc<AtomicUsize>,
+    max_retries: u32,
+    retry_delay: Duration,
+    chain: Option<Chain>,
 }
 
 impl From<ClientError> for JsonRpcError {
@@ -24,27 +44,60 @@ impl From<ClientError> for JsonRpcError {
 
 impl<C: Client + Clone> JsonRpcClient<C> {
     pub fn new(client: C) -> Self {
-        Self { client }
+        Self {
+            client,
+            batch_size: Arc::new(AtomicUsize::new(usize::MAX)),
+            max_retries: 0,
+            retry_delay: DEFAULT_RETRY_DELAY,
+            chain: None,
+        }
+    }
+
+    /// 设置所属链，仅用于 tracing span 的 `chain` 字段
+    pub fn with_chain(mut self, chain: Chain) -> Self {
+        self.chain = Some(chain);
+        self
+    }
+
+    /// 设置节点 host，学习到的批量大小在同一 host 的所有客户端之间共享；空 host 不共享
+    pub fn with_host(mut self, host: &str) -> Self {
+        if host.is_empty() {
+            return self;
+        }
+        let mut batch_sizes = HOST_BATCH_SIZES.lock().unwrap();
+        self.batch_size = batch_sizes.entry(host.to_string()).or_insert_with(|| Arc::new(AtomicUsize::new(usize::MAX))).clone();
+        self
+    }
+
+    /// 设置 `call` / `request` 遇到可重试错误（见 `JsonRpcError::is_retryable`）时的最大重试次数
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// 设置重试的退避基数，第 n 次重试等待 `retry_delay * 2^n`，默认 `DEFAULT_RETRY_DELAY`
+    pub fn with_retry_delay(mut self, retry_delay: Duration) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// 当前记录的可用批量大小，`usize::MAX` 表示尚未遇到限制
+    pub fn batch_size(&self) -> usize {
+        self.batch_size.load(Ordering::Relaxed)
     }
 
     pub async fn request<T: JsonRpcRequestConvert, U: DeserializeOwned>(&self, request: T) -> Result<U, JsonRpcError> {
         let timestamp = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap().as_secs();
         let req = request.to_req(timestamp);
-        let result = self._request(req, None).await?;
-        match result {
-            JsonRpcResult::Value(value) => Ok(value.result),
-            JsonRpcResult::Error(error) => Err(error.error),
-        }
+        self.request_with_retry(req).await
     }
 
     pub async fn call<T: DeserializeOwned>(&self, method: &str, params: impl Into<Value>) -> Result<T, JsonRpcError> {
         let timestamp = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap().as_secs();
         let req = JsonRpcRequest::new(timestamp, method, params.into());
-        let result = self._request(req, None).await?;
-        match result {
-            JsonRpcResult::Value(value) => Ok(value.result),
-            JsonRpcResult::Error(error) => Err(error.error),
-        }
+        // span 为 debug 级别，默认的 info 过滤下不会被启用
+        let span = tracing::debug_span!("jsonrpc.call", method, chain = self.chain_name(), duration_ms = field::Empty, success = field::Empty);
+        traced(span, self.request_with_retry(req)).await
     }
 
     pub async fn call_with_cache<T: JsonRpcRequestConvert, U: DeserializeOwned>(&self, call: &T, ttl: Option<u64>) -> Result<JsonRpcResult<U>, JsonRpcError> {
@@ -84,7 +137,10 @@ impl<C: Client + Clone> JsonRpcClient<C> {
             .map(|(index, (method, params))| JsonRpcRequest::new(index as u64 + 1, method, params.clone()))
             .collect();
 
-        self.batch_request(requests).await
+        let methods = calls.iter().map(|(method, _)| method.as_str()).collect::<BTreeSet<_>>();
+        let method = methods.into_iter().collect::<Vec<_>>().join(",");
+        let span = tracing::debug_span!("jsonrpc.batch_call", method, chain = self.chain_name(), duration_ms = field::Empty, success = field::Empty);
+        traced(span, self.batch_request(requests)).await
     }
 
     pub async fn batch_call_requests<T: JsonRpcRequestConvert, U: DeserializeOwned>(&self, calls: Vec<T>) -> Result<JsonRpcResults<U>, JsonRpcError> {
@@ -97,7 +153,16 @@ impl<C: Client + Clone> JsonRpcClient<C> {
             return Ok(Default::default());
         }
 
-        let results: Vec<JsonRpcResult<T>> = self.client.post("", &requests, None).await?;
+        let mut chunk_size = self.batch_size().min(requests.len());
+        let results = loop {
+            match self.batch_request_chunks(&requests, chunk_size).await {
+                Err(error) if chunk_size > MIN_BATCH_SIZE && is_batch_too_large(&error) => {
+                    chunk_size = (chunk_size / 2).max(MIN_BATCH_SIZE);
+                    self.batch_size.fetch_min(chunk_size, Ordering::Relaxed);
+                }
+                result => break result?,
+            }
+        };
         if results.len() != requests.len() {
             return Err(JsonRpcError {
                 message: "Batch call response length mismatch".into(),
@@ -108,6 +173,25 @@ impl<C: Client + Clone> JsonRpcClient<C> {
         Ok(JsonRpcResults(results))
     }
 
+    async fn batch_request_chunks<T: DeserializeOwned>(&self, requests: &[JsonRpcRequest], chunk_size: usize) -> Result<Vec<JsonRpcResult<T>>, ClientError> {
+        let mut results = Vec::with_capacity(requests.len());
+        for chunk in requests.chunks(chunk_size) {
+            let chunk_results: Vec<JsonRpcResult<T>> = self.client.post("", &chunk, None).await?;
+            results.extend(chunk_results);
+        }
+        Ok(results)
+    }
+
+    async fn request_with_retry<T: DeserializeOwned>(&self, req: JsonRpcRequest) -> Result<T, JsonRpcError> {
+        retry_with_delay(
+            || async { self._request(req.clone(), None).await?.take() },
+            self.max_retries,
+            Some(JsonRpcError::is_retryable),
+            self.retry_delay,
+        )
+        .await
+    }
+
     async fn _request<T: DeserializeOwned>(&self, req: JsonRpcRequest, ttl: Option<u64>) -> Result<JsonRpcResult<T>, JsonRpcError> {
         // Build cache headers if TTL is provided
         let headers = ttl.map(|ttl_seconds| {
@@ -119,6 +203,26 @@ impl<C: Client + Clone> JsonRpcClient<C> {
         let result: JsonRpcResult<T> = self.client.post("", &req, headers).await?;
         Ok(result)
     }
+
+    fn chain_name(&self) -> Option<&str> {
+        self.chain.as_ref().map(AsRef::as_ref)
+    }
+}
+
+async fn traced<T>(span: Span, future: impl Future<Output = Result<T, JsonRpcError>>) -> Result<T, JsonRpcError> {
+    let start = Instant::now();
+    let result = future.instrument(span.clone()).await;
+    span.record("duration_ms", start.elapsed().as_millis() as u64);
+    span.record("success", result.is_ok());
+    result
+}
+
+fn is_batch_too_large(error: &ClientError) -> bool {
+    match error {
+        ClientError::Http { status, .. } => *status == 413,
+        ClientError::Network(message) | ClientError::Serialization(message) => message.to_lowercase().contains("batch too large"),
+        ClientError::Timeout | ClientError::ResponseTooLarge { .. } => false,
+    }
 }
 
 #[cfg(feature = "reqwest")]
@@ -126,8 +230,7 @@ impl JsonRpcClient<core_client::ReqwestClient> {
     pub fn new_reqwest(url: String) -> Self {
         use core_client::ReqwestClient;
         let reqwest_client = core_client::builder().build().expect("Failed to build reqwest client");
-        let client = ReqwestClient::new(url, reqwest_client);
-        Self { client }
+        Self::new(ReqwestClient::new(url, reqwest_client))
     }
 }
 
@@ -144,3 +247,167 @@ impl JsonRpcClient<core_client::ReqwestClient> {
 pub fn new_client(url: String) -> Result<JsonRpcClient<core_client::ReqwestClient>, Box<dyn Error + Send + Sync>> {
     Ok(JsonRpcClient::new_reqwest(url))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testkit::MockClient;
+    use serde_json::json;
+
+    fn batch_limited_client(limit: usize) -> MockClient {
+        MockClient::new(move |request| {
+            let requests = request.body.as_ref().and_then(|body| body.as_array()).cloned().unwrap_or_default();
+            if requests.len() > limit {
+                return Err(ClientError::Http { status: 413, len: 0 });
+            }
+            Ok(Value::Array(requests.iter().map(|request| json!({ "id": request["id"], "result": request["id"] })).collect()))
+        })
+    }
+
+    fn calls(count: usize) -> Vec<CallTuple> {
+        (0..count).map(|_| ("eth_blockNumber".to_string(), json!([]))).collect()
+    }
+
+    #[tokio::test]
+    async fn test_batch_call_reduces_chunk_size_on_413() {
+        let mock = batch_limited_client(2);
+        let client = JsonRpcClient::new(mock.clone());
+
+        let results: JsonRpcResults<u64> = client.batch_call(calls(5)).await.unwrap();
+        let values: Vec<u64> = results
+            .0
+            .into_iter()
+            .map(|result| match result {
+                JsonRpcResult::Value(value) => value.result,
+                JsonRpcResult::Error(error) => panic!("unexpected error: {}", error.error),
+            })
+            .collect();
+
+        assert_eq!(values, vec![1, 2, 3, 4, 5]);
+        assert_eq!(client.batch_size(), 2);
+
+        let sizes: Vec<usize> = mock.requests().iter().map(|request| request.body.as_ref().unwrap().as_array().unwrap().len()).collect();
+        assert_eq!(sizes, vec![5, 2, 2, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_batch_call_remembers_chunk_size() {
+        let mock = batch_limited_client(1);
+        let client = JsonRpcClient::new(mock.clone());
+
+        let _: JsonRpcResults<u64> = client.batch_call(calls(4)).await.unwrap();
+        assert_eq!(client.batch_size(), MIN_BATCH_SIZE);
+
+        let requests_before = mock.requests().len();
+        let _: JsonRpcResults<u64> = client.clone().batch_call(calls(3)).await.unwrap();
+        assert_eq!(mock.requests().len() - requests_before, 3);
+    }
+
+    #[tokio::test]
+    async fn test_batch_size_shared_per_host() {
+        let client = JsonRpcClient::new(batch_limited_client(2)).with_host("limited.example.com");
+        let _: JsonRpcResults<u64> = client.batch_call(calls(4)).await.unwrap();
+        assert_eq!(client.batch_size(), 2);
+
+        let same_host = JsonRpcClient::new(batch_limited_client(2)).with_host("limited.example.com");
+        assert_eq!(same_host.batch_size(), 2);
+
+        let other_host = JsonRpcClient::new(batch_limited_client(2)).with_host("other.example.com");
+        assert_eq!(other_host.batch_size(), usize::MAX);
+    }
+
+    fn rate_limited_client(failures: usize) -> MockClient {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        MockClient::new(move |request| {
+            let id = request.body.as_ref().unwrap()["id"].clone();
+            if attempts.fetch_add(1, Ordering::SeqCst) < failures {
+                return Ok(json!({ "id": id, "error": { "code": -32005, "message": "limit exceeded" } }));
+            }
+            Ok(json!({ "id": id, "result": "0x1" }))
+        })
+    }
+
+    #[tokio::test]
+    async fn test_call_retries_retryable_error() {
+        let mock = rate_limited_client(1);
+        let client = JsonRpcClient::new(mock.clone()).with_max_retries(1).with_retry_delay(Duration::ZERO);
+
+        let result: String = client.call("eth_blockNumber", json!([])).await.unwrap();
+        assert_eq!(result, "0x1");
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_call_retries_reuse_request_id() {
+        let mock = rate_limited_client(1);
+        let client = JsonRpcClient::new(mock.clone()).with_max_retries(1).with_retry_delay(Duration::ZERO);
+
+        let _: String = client.call("eth_sendRawTransaction", json!(["0x01"])).await.unwrap();
+
+        let ids: Vec<Value> = mock.requests().iter().map(|request| request.body.as_ref().unwrap()["id"].clone()).collect();
+        assert_eq!(ids.len(), 2);
+        assert_eq!(ids[0], ids[1]);
+    }
+
+    #[tokio::test]
+    async fn test_call_does_not_retry_without_retries() {
+        let mock = rate_limited_client(1);
+        let client = JsonRpcClient::new(mock.clone());
+
+        let error = client.call::<String>("eth_blockNumber", json!([])).await.unwrap_err();
+        assert_eq!(error.code, -32005);
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_batch_call_propagates_other_errors() {
+        let client = JsonRpcClient::new(MockClient::new(|_| Err(ClientError::Http { status: 500, len: 0 })));
+
+        let result: Result<JsonRpcResults<u64>, JsonRpcError> = client.batch_call(calls(4)).await;
+        assert!(result.is_err());
+        assert_eq!(client.batch_size(), usize::MAX);
+    }
+
+    #[derive(Clone, Default)]
+    struct SpanRecorder(Arc<std::sync::Mutex<Vec<(String, String)>>>);
+
+    impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for SpanRecorder {
+        fn on_new_span(&self, attrs: &tracing::span::Attributes<'_>, _id: &tracing::span::Id, _ctx: tracing_subscriber::layer::Context<'_, S>) {
+            struct MethodVisitor(Option<String>);
+            impl tracing::field::Visit for MethodVisitor {
+                fn record_str(&mut self, field: &field::Field, value: &str) {
+                    if field.name() == "method" {
+                        self.0 = Some(value.to_string());
+                    }
+                }
+                fn record_debug(&mut self, _field: &field::Field, _value: &dyn std::fmt::Debug) {}
+            }
+            let mut visitor = MethodVisitor(None);
+            attrs.record(&mut visitor);
+            if let Some(method) = visitor.0 {
+                self.0.lock().unwrap().push((attrs.metadata().name().to_string(), method));
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_call_emits_span_with_method() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let recorder = SpanRecorder::default();
+        let _guard = tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+        let client = JsonRpcClient::new(rate_limited_client(0)).with_chain(Chain::Ethereum);
+
+        let _: String = client.call("eth_blockNumber", json!([])).await.unwrap();
+        let _: JsonRpcResults<u64> = client.batch_call(calls(2)).await.unwrap_or_default();
+
+        let spans = recorder.0.lock().unwrap().clone();
+        assert_eq!(
+            spans,
+            vec![
+                ("jsonrpc.call".to_string(), "eth_blockNumber".to_string()),
+                ("jsonrpc.batch_call".to_string(), "eth_blockNumber".to_string()),
+            ]
+        );
+    }
+}
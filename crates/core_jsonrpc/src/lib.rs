@@ -5,3 +5,6 @@ pub use client::*;
 
 pub mod rpc;
 pub use rpc::{HttpMethod, RpcClient, RpcClientError, RpcProvider, RpcResponse, Target};
+
+#[cfg(any(test, feature = "testkit"))]
+pub mod testkit;
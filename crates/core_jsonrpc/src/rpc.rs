@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use core_client::{Client, ClientError, ContentType};
+use core_client::{Client, ClientError, ContentType, IDEMPOTENCY_KEY, cbor};
 use primitives::Chain;
 use serde::{Serialize, de::DeserializeOwned};
 use serde_json;
@@ -9,6 +9,7 @@ use std::{
     fmt::{Debug, Display},
     str::FromStr,
     sync::Arc,
+    time::Duration,
 };
 
 pub const X_CACHE_TTL: &str = "x-cache-ttl";
@@ -31,25 +32,61 @@ pub struct Target {
     pub method: HttpMethod,
     pub headers: Option<HashMap<String, String>>,
     pub body: Option<Vec<u8>>,
+    /// 本次请求的超时时间，为 `None` 时使用客户端默认值
+    pub timeout: Option<Duration>,
 }
 
 impl Target {
-    pub fn get(url: &str) -> Self {
+    pub fn new(method: HttpMethod, url: &str) -> Self {
         Self {
             url: url.into(),
-            method: HttpMethod::Get,
+            method,
             headers: None,
             body: None,
+            timeout: None,
         }
     }
 
+    pub fn get(url: &str) -> Self {
+        Self::new(HttpMethod::Get, url)
+    }
+
     pub fn post_json(url: &str, body: serde_json::Value) -> Self {
-        Self {
-            url: url.into(),
-            method: HttpMethod::Post,
-            headers: Some(HashMap::from([("Content-Type".into(), "application/json".into())])),
-            body: Some(serde_json::to_vec(&body).expect("Failed to serialize JSON body")),
+        Self::new(HttpMethod::Post, url).with_json_body(body)
+    }
+
+    pub fn with_body(mut self, body: Vec<u8>) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    pub fn with_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.get_or_insert_with(HashMap::new).insert(key.into(), value.into());
+        self
+    }
+
+    /// 序列化为 JSON 请求体并设置 `Content-Type: application/json`
+    pub fn with_json_body(self, body: serde_json::Value) -> Self {
+        self.with_header("Content-Type", ContentType::ApplicationJson.as_str())
+            .with_body(serde_json::to_vec(&body).expect("Failed to serialize JSON body"))
+    }
+
+    /// 设置 `Idempotency-Key`，同一逻辑请求的重试应复用同一个键（见 `core_client::new_idempotency_key`）
+    pub fn with_idempotency_key(self, key: &str) -> Self {
+        self.with_header(IDEMPOTENCY_KEY, key)
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// 把 URL 中的 `from` 前缀替换为 `to`，用于在多个节点之间切换请求
+    pub fn rebase(mut self, from: &str, to: &str) -> Self {
+        if let Some(path) = self.url.strip_prefix(from) {
+            self.url = format!("{}{}", to, path);
         }
+        self
     }
 
     pub fn set_cache_ttl(mut self, ttl: u64) -> Self {
@@ -97,12 +134,22 @@ pub trait RpcProvider: Send + Sync + Debug {
     fn get_endpoint(&self, chain: Chain) -> Result<String, Self::Error>;
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct RpcClient<E> {
     base_url: String,
     provider: Arc<dyn RpcProvider<Error = E>>,
 }
 
+// 手动实现，避免 derive 要求错误类型 `E: Clone`
+impl<E> Clone for RpcClient<E> {
+    fn clone(&self) -> Self {
+        Self {
+            base_url: self.base_url.clone(),
+            provider: self.provider.clone(),
+        }
+    }
+}
+
 impl<E> RpcClient<E>
 where
     E: RpcClientError,
@@ -114,24 +161,8 @@ where
     fn build_url(&self, path: &str) -> String {
         format!("{}{}", self.base_url.trim_end_matches('/'), path)
     }
-}
-
-#[async_trait]
-impl<E> Client for RpcClient<E>
-where
-    E: RpcClientError,
-{
-    async fn get<R>(&self, path: &str) -> Result<R, ClientError>
-    where
-        R: DeserializeOwned,
-    {
-        self.get_with_headers(path, None).await
-    }
 
-    async fn get_with_headers<R>(&self, path: &str, headers: Option<HashMap<String, String>>) -> Result<R, ClientError>
-    where
-        R: DeserializeOwned,
-    {
+    async fn get_data(&self, path: &str, headers: Option<HashMap<String, String>>) -> Result<Vec<u8>, ClientError> {
         let url = self.build_url(path);
         let target = if let Some(headers) = headers {
             Target {
@@ -139,20 +170,19 @@ where
                 method: HttpMethod::Get,
                 headers: Some(headers),
                 body: None,
+                timeout: None,
             }
         } else {
             Target::get(&url)
         };
 
         let response = self.provider.request(target).await.map_err(|e| e.into_client_error())?;
-
-        serde_json::from_slice(&response.data).map_err(|e| ClientError::Serialization(format!("Failed to deserialize response: {e}")))
+        response_data(response)
     }
 
-    async fn post<T, R>(&self, path: &str, body: &T, headers: Option<HashMap<String, String>>) -> Result<R, ClientError>
+    async fn post_data<T>(&self, path: &str, body: &T, headers: Option<HashMap<String, String>>) -> Result<Vec<u8>, ClientError>
     where
         T: Serialize + Send + Sync,
-        R: DeserializeOwned,
     {
         let url = self.build_url(path);
 
@@ -179,6 +209,7 @@ where
                     _ => return Err(ClientError::Serialization("Expected hex string body for binary content-type".to_string())),
                 }
             }
+            Some(ContentType::ApplicationCbor) => cbor::to_vec(body)?,
             _ => serde_json::to_vec(body)?,
         };
 
@@ -187,14 +218,72 @@ where
             method: HttpMethod::Post,
             headers: Some(request_headers),
             body: Some(data),
+            timeout: None,
         };
 
         let response = self.provider.request(target).await.map_err(|e| e.into_client_error())?;
+        response_data(response)
+    }
+}
 
-        serde_json::from_slice(&response.data).map_err(|e| ClientError::Serialization(format!("Failed to deserialize response: {e}")))
+#[async_trait]
+impl<E> Client for RpcClient<E>
+where
+    E: RpcClientError,
+{
+    async fn get<R>(&self, path: &str) -> Result<R, ClientError>
+    where
+        R: DeserializeOwned,
+    {
+        self.get_with_headers(path, None).await
+    }
+
+    async fn get_with_headers<R>(&self, path: &str, headers: Option<HashMap<String, String>>) -> Result<R, ClientError>
+    where
+        R: DeserializeOwned,
+    {
+        let data = self.get_data(path, headers).await?;
+        deserialize_response(&data)
+    }
+
+    async fn get_raw(&self, path: &str) -> Result<Vec<u8>, ClientError> {
+        self.get_data(path, None).await
+    }
+
+    async fn post<T, R>(&self, path: &str, body: &T, headers: Option<HashMap<String, String>>) -> Result<R, ClientError>
+    where
+        T: Serialize + Send + Sync,
+        R: DeserializeOwned,
+    {
+        // CBOR 请求的响应同样按 CBOR 解码
+        let is_cbor = headers
+            .as_ref()
+            .and_then(|headers| headers.get("Content-Type"))
+            .is_some_and(|content_type| ContentType::from_str(content_type) == Ok(ContentType::ApplicationCbor));
+        let data = self.post_data(path, body, headers).await?;
+        if is_cbor { cbor::from_slice(&data) } else { deserialize_response(&data) }
+    }
+
+    async fn post_raw<T>(&self, path: &str, body: &T) -> Result<Vec<u8>, ClientError>
+    where
+        T: Serialize + Send + Sync,
+    {
+        self.post_data(path, body, None).await
     }
 }
 
+// 与 `ReqwestClient` 一致，非 2xx 响应返回 `ClientError::Http`
+fn response_data(response: RpcResponse) -> Result<Vec<u8>, ClientError> {
+    match response.status {
+        Some(status) if !(200..300).contains(&status) => Err(ClientError::Http { status, len: response.data.len() }),
+        _ => Ok(response.data),
+    }
+}
+
+fn deserialize_response<R: DeserializeOwned>(data: &[u8]) -> Result<R, ClientError> {
+    serde_json::from_slice(data).map_err(|e| ClientError::Serialization(format!("Failed to deserialize response: {e}")))
+}
+
 #[async_trait]
 impl<E> RpcProvider for RpcClient<E>
 where
@@ -211,6 +300,43 @@ where
     }
 }
 
+impl RpcClientError for ClientError {
+    fn into_client_error(self) -> ClientError {
+        self
+    }
+}
+
+#[cfg(feature = "reqwest")]
+impl From<HttpMethod> for reqwest::Method {
+    fn from(value: HttpMethod) -> Self {
+        match value {
+            HttpMethod::Get => reqwest::Method::GET,
+            HttpMethod::Post => reqwest::Method::POST,
+            HttpMethod::Put => reqwest::Method::PUT,
+            HttpMethod::Delete => reqwest::Method::DELETE,
+            HttpMethod::Head => reqwest::Method::HEAD,
+            HttpMethod::Options => reqwest::Method::OPTIONS,
+            HttpMethod::Patch => reqwest::Method::PATCH,
+        }
+    }
+}
+
+// Target 的超时覆盖 reqwest 客户端的默认超时
+#[cfg(feature = "reqwest")]
+#[async_trait]
+impl RpcProvider for core_client::ReqwestClient {
+    type Error = ClientError;
+
+    async fn request(&self, target: Target) -> Result<RpcResponse, Self::Error> {
+        let (status, data) = self.execute(target.method.into(), &target.url, target.headers, target.body, target.timeout).await?;
+        Ok(RpcResponse { status: Some(status), data })
+    }
+
+    fn get_endpoint(&self, _chain: Chain) -> Result<String, Self::Error> {
+        Ok(self.base_url().to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -254,6 +380,48 @@ mod tests {
         assert_eq!(headers.get(X_CACHE_TTL).unwrap(), "300");
     }
 
+    #[test]
+    fn test_target_patch_with_json_body_and_header() {
+        let body = serde_json::json!({ "name": "wallet" });
+        let target = Target::new(HttpMethod::Patch, "https://api.example.com/v1/items/1")
+            .with_json_body(body.clone())
+            .with_header("Authorization", "Bearer token");
+
+        assert_eq!(target.method, HttpMethod::Patch);
+        assert_eq!(target.url, "https://api.example.com/v1/items/1");
+        assert_eq!(serde_json::from_slice::<serde_json::Value>(target.body.as_ref().unwrap()).unwrap(), body);
+
+        let headers = target.headers.unwrap();
+        assert_eq!(headers.get("Content-Type").unwrap(), "application/json");
+        assert_eq!(headers.get("Authorization").unwrap(), "Bearer token");
+    }
+
+    #[test]
+    fn test_target_delete_with_body() {
+        let target = Target::new(HttpMethod::Delete, "https://api.example.com/v1/items").with_body(b"1,2".to_vec());
+
+        assert_eq!(target.method, HttpMethod::Delete);
+        assert_eq!(target.body, Some(b"1,2".to_vec()));
+        assert!(target.headers.is_none());
+    }
+
+    #[test]
+    fn test_target_with_timeout() {
+        assert!(Target::get("https://example.com").timeout.is_none());
+
+        let target = Target::get("https://example.com").with_timeout(Duration::from_secs(2));
+        assert_eq!(target.timeout, Some(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn test_target_rebase() {
+        let target = Target::get("https://primary.example.com/v1/status").rebase("https://primary.example.com", "https://backup.example.com");
+        assert_eq!(target.url, "https://backup.example.com/v1/status");
+
+        let target = Target::get("https://other.example.com").rebase("https://primary.example.com", "https://backup.example.com");
+        assert_eq!(target.url, "https://other.example.com");
+    }
+
     #[test]
     fn test_http_method_to_string() {
         assert_eq!(String::from(HttpMethod::Get), "GET");
@@ -288,6 +456,8 @@ mod tests {
 
     impl std::error::Error for MockError {}
 
+    impl RpcClientError for MockError {}
+
     impl MockProvider {
         fn new() -> Self {
             let mut endpoints = HashMap::new();
@@ -352,6 +522,75 @@ mod tests {
         assert_eq!(response.data, b"{\"result\":\"0x1234\"}");
     }
 
+    #[tokio::test]
+    async fn test_rpc_client_raw_bytes() {
+        let client = RpcClient::new("https://example.com".to_string(), Arc::new(MockProvider::new()));
+
+        assert_eq!(client.get_raw("/status").await.unwrap(), b"{\"result\":\"0x1234\"}");
+        assert_eq!(client.post_raw("/", &serde_json::json!({ "id": 1 })).await.unwrap(), b"{\"result\":\"0x1234\"}");
+    }
+
+    #[derive(Debug)]
+    struct StatusProvider(u16);
+
+    #[async_trait]
+    impl RpcProvider for StatusProvider {
+        type Error = ClientError;
+
+        async fn request(&self, _target: Target) -> Result<RpcResponse, Self::Error> {
+            Ok(RpcResponse { status: Some(self.0), data: b"unavailable".to_vec() })
+        }
+
+        fn get_endpoint(&self, _chain: Chain) -> Result<String, Self::Error> {
+            Ok("https://example.com".to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rpc_client_error_status() {
+        let client = RpcClient::new("https://example.com".to_string(), Arc::new(StatusProvider(503)));
+
+        let error = client.post_raw("/", &serde_json::json!({ "id": 1 })).await.unwrap_err();
+        assert!(matches!(error, ClientError::Http { status: 503, len: 11 }));
+        assert!(matches!(client.get_raw("/status").await, Err(ClientError::Http { status: 503, .. })));
+    }
+
+    // 原样返回请求体的 provider
+    #[cfg(feature = "cbor")]
+    #[derive(Debug)]
+    struct EchoProvider;
+
+    #[cfg(feature = "cbor")]
+    #[async_trait]
+    impl RpcProvider for EchoProvider {
+        type Error = MockError;
+
+        async fn request(&self, target: Target) -> Result<RpcResponse, Self::Error> {
+            Ok(RpcResponse {
+                status: Some(200),
+                data: target.body.unwrap_or_default(),
+            })
+        }
+
+        fn get_endpoint(&self, _chain: Chain) -> Result<String, Self::Error> {
+            Ok("https://example.com".to_string())
+        }
+    }
+
+    #[cfg(feature = "cbor")]
+    #[tokio::test]
+    async fn test_rpc_client_post_cbor() {
+        let client = RpcClient::new("https://example.com".to_string(), Arc::new(EchoProvider));
+        let body = serde_json::json!({ "method": "getSlot", "params": [1] });
+        let headers = HashMap::from([("Content-Type".to_string(), ContentType::ApplicationCbor.as_str().to_string())]);
+
+        let raw = client.post_raw("/", &body).await.unwrap();
+        assert_eq!(serde_json::from_slice::<serde_json::Value>(&raw).unwrap(), body);
+
+        let echoed: serde_json::Value = client.post("/", &body, Some(headers)).await.unwrap();
+        assert_eq!(echoed, body);
+    }
+
     #[test]
     fn test_rpc_response_creation() {
         let response = RpcResponse {
@@ -0,0 +1,36 @@
+//! JSON-RPC 测试辅助工具
+//!
+//! 在 `MockClient` 之上按 JSON-RPC 协议分发单个请求和批量请求。
+
+use crate::types::{JSONRPC_VERSION, JsonRpcError};
+use core_client::ClientError;
+pub use core_client::testkit::{MockClient, MockRequest};
+use serde_json::{Value, json};
+
+/// 创建一个按方法名响应 JSON-RPC 请求的模拟客户端
+///
+/// # 参数
+/// - `handler` - 接收方法名和参数，返回调用结果或 JSON-RPC 错误
+pub fn mock_client<F>(handler: F) -> MockClient
+where
+    F: Fn(&str, &Value) -> Result<Value, JsonRpcError> + Send + Sync + 'static,
+{
+    MockClient::new(move |request| {
+        let body = request.body.as_ref().ok_or_else(|| ClientError::Network("JSON-RPC request without body".to_string()))?;
+        match body {
+            Value::Array(requests) => Ok(Value::Array(requests.iter().map(|request| respond(&handler, request)).collect())),
+            request => Ok(respond(&handler, request)),
+        }
+    })
+}
+
+fn respond<F>(handler: &F, request: &Value) -> Value
+where
+    F: Fn(&str, &Value) -> Result<Value, JsonRpcError>,
+{
+    let id = request["id"].clone();
+    match handler(request["method"].as_str().unwrap_or_default(), &request["params"]) {
+        Ok(result) => json!({ "jsonrpc": JSONRPC_VERSION, "id": id, "result": result }),
+        Err(error) => json!({ "jsonrpc": JSONRPC_VERSION, "id": id, "error": error }),
+    }
+}
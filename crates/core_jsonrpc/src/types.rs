@@ -4,6 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
 use std::fmt::{Debug, Display};
 
 /// JSON-RPC 协议版本号
@@ -21,6 +22,12 @@ pub const ERROR_INVALID_PARAMS: i32 = -32602;
 /// 错误码：内部错误
 pub const ERROR_INTERNAL_ERROR: i32 = -32603;
 
+/// 错误码：通用服务端错误（部分节点用于限流）
+pub const ERROR_SERVER_ERROR: i32 = -32000;
+
+/// 错误码：请求超出限额（部分节点的限流错误）
+pub const ERROR_LIMIT_EXCEEDED: i32 = -32005;
+
 /// JSON-RPC 请求结构
 ///
 /// 符合 JSON-RPC 2.0 规范的请求格式。
@@ -101,6 +108,34 @@ pub struct JsonRpcError {
     pub message: String,
 }
 
+impl JsonRpcError {
+    /// 判断错误是否为临时性错误，可以重试
+    ///
+    /// # 会触发重试的错误
+    /// - `-32005` - 超出请求限额
+    /// - `-32000` 且消息包含 "rate" - 节点限流
+    /// - `-32603` 且消息为 429/502/503/504 或超时 - 由传输层错误转换而来
+    ///
+    /// # 示例
+    /// ```
+    /// use core_jsonrpc::types::{ERROR_LIMIT_EXCEEDED, JsonRpcError};
+    ///
+    /// let error = JsonRpcError { code: ERROR_LIMIT_EXCEEDED, message: "limit exceeded".into() };
+    /// assert!(error.is_retryable());
+    /// ```
+    pub fn is_retryable(&self) -> bool {
+        let message = self.message.to_lowercase();
+        match self.code {
+            ERROR_LIMIT_EXCEEDED => true,
+            ERROR_SERVER_ERROR => message.contains("rate"),
+            ERROR_INTERNAL_ERROR => ["status 429", "status 502", "status 503", "status 504", "timeout"]
+                .iter()
+                .any(|pattern| message.contains(pattern)),
+            _ => false,
+        }
+    }
+}
+
 impl Display for JsonRpcError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{} ({})", self.message, self.code)
@@ -211,6 +246,55 @@ impl<T> JsonRpcResults<T> {
         }
         extracted
     }
+
+    /// 按响应 `id` 建立结果映射
+    ///
+    /// 部分节点会打乱批量响应的顺序，通过 `id` 可以把结果对应回请求。没有 `id` 的响应会被忽略。
+    ///
+    /// # 返回值
+    /// 以响应 `id` 为键的结果映射
+    pub fn into_map(self) -> HashMap<u64, Result<T, JsonRpcError>> {
+        self.0
+            .into_iter()
+            .filter_map(|result| match result {
+                JsonRpcResult::Value(response) => response.id.map(|id| (id, Ok(response.result))),
+                JsonRpcResult::Error(error) => error.id.map(|id| (id, Err(error.error))),
+            })
+            .collect()
+    }
+
+    /// 按调用方给定的请求 `id` 顺序返回结果
+    ///
+    /// # 参数
+    /// - `ids` - 期望的请求 `id` 顺序
+    ///
+    /// # 返回值
+    /// 与 `ids` 一一对应的结果，缺失的响应返回内部错误
+    pub fn extract_ordered(&self, ids: &[u64]) -> Vec<Result<T, JsonRpcError>>
+    where
+        T: Clone,
+    {
+        let mut results: HashMap<u64, &JsonRpcResult<T>> = HashMap::new();
+        for result in &self.0 {
+            let id = match result {
+                JsonRpcResult::Value(response) => response.id,
+                JsonRpcResult::Error(error) => error.id,
+            };
+            if let Some(id) = id {
+                results.insert(id, result);
+            }
+        }
+
+        ids.iter()
+            .map(|id| match results.get(id) {
+                Some(result) => (*result).clone().take(),
+                None => Err(JsonRpcError {
+                    code: ERROR_INTERNAL_ERROR,
+                    message: format!("Missing response for request id {id}"),
+                }),
+            })
+            .collect()
+    }
 }
 
 impl<T> Default for JsonRpcResults<T> {
@@ -276,6 +360,21 @@ mod tests {
         assert_eq!(ERROR_INTERNAL_ERROR, -32603);
     }
 
+    #[test]
+    fn test_jsonrpc_error_is_retryable() {
+        let error = |code: i32, message: &str| JsonRpcError {
+            code,
+            message: message.to_string(),
+        };
+
+        assert!(error(ERROR_LIMIT_EXCEEDED, "request limit exceeded").is_retryable());
+        assert!(error(ERROR_SERVER_ERROR, "Rate limited, try again later").is_retryable());
+        assert!(error(ERROR_INTERNAL_ERROR, "HTTP error: status 429, body len: 0").is_retryable());
+        assert!(!error(ERROR_METHOD_NOT_FOUND, "Method not found").is_retryable());
+        assert!(!error(ERROR_SERVER_ERROR, "execution reverted").is_retryable());
+        assert!(!error(ERROR_INTERNAL_ERROR, "HTTP error: status 400, body len: 0").is_retryable());
+    }
+
     #[test]
     fn test_jsonrpc_result_take_success() {
         let response = JsonRpcResponse {
@@ -352,6 +451,42 @@ mod tests {
         assert_eq!(extracted[1], 20);
     }
 
+    fn out_of_order_results() -> JsonRpcResults<u64> {
+        JsonRpcResults(vec![
+            JsonRpcResult::Value(JsonRpcResponse { id: Some(3), result: 30 }),
+            JsonRpcResult::Error(JsonRpcErrorResponse {
+                id: Some(2),
+                error: JsonRpcError {
+                    code: ERROR_INVALID_PARAMS,
+                    message: "Invalid params".to_string(),
+                },
+            }),
+            JsonRpcResult::Value(JsonRpcResponse { id: Some(1), result: 10 }),
+        ])
+    }
+
+    #[test]
+    fn test_jsonrpc_results_into_map() {
+        let map = out_of_order_results().into_map();
+
+        assert_eq!(map.len(), 3);
+        assert_eq!(map[&1].as_ref().unwrap(), &10);
+        assert_eq!(map[&3].as_ref().unwrap(), &30);
+        assert_eq!(map[&2].as_ref().unwrap_err().code, ERROR_INVALID_PARAMS);
+    }
+
+    #[test]
+    fn test_jsonrpc_results_extract_ordered() {
+        let results = out_of_order_results();
+        let ordered = results.extract_ordered(&[1, 2, 3, 4]);
+
+        assert_eq!(ordered.len(), 4);
+        assert_eq!(ordered[0].as_ref().unwrap(), &10);
+        assert_eq!(ordered[1].as_ref().unwrap_err().code, ERROR_INVALID_PARAMS);
+        assert_eq!(ordered[2].as_ref().unwrap(), &30);
+        assert_eq!(ordered[3].as_ref().unwrap_err().code, ERROR_INTERNAL_ERROR);
+    }
+
     #[test]
     fn test_jsonrpc_results_default() {
         let results: JsonRpcResults<u64> = JsonRpcResults::default();
@@ -0,0 +1,77 @@
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use sha2::{Digest, Sha256};
+use std::error::Error;
+
+use crate::{ASSOCIATED_TOKEN_ACCOUNT_PROGRAM, TOKEN_PROGRAM};
+
+const PUBKEY_LENGTH: usize = 32;
+const PDA_MARKER: &[u8] = b"ProgramDerivedAddress";
+
+pub fn decode_pubkey(address: &str) -> Result<[u8; PUBKEY_LENGTH], Box<dyn Error + Send + Sync>> {
+    let bytes = bs58::decode(address).into_vec()?;
+    bytes.try_into().map_err(|_| format!("Invalid Solana address: {address}").into())
+}
+
+/// 按 `find_program_address` 规则从 bump 255 开始向下查找第一个不在 ed25519 曲线上的地址
+pub fn find_program_address(seeds: &[&[u8]], program_id: &str) -> Result<(String, u8), Box<dyn Error + Send + Sync>> {
+    let program_id = decode_pubkey(program_id)?;
+    for bump in (0..=u8::MAX).rev() {
+        let mut hasher = Sha256::new();
+        for seed in seeds {
+            hasher.update(seed);
+        }
+        hasher.update([bump]);
+        hasher.update(program_id);
+        hasher.update(PDA_MARKER);
+        let hash: [u8; PUBKEY_LENGTH] = hasher.finalize().into();
+
+        if CompressedEdwardsY(hash).decompress().is_none() {
+            return Ok((bs58::encode(hash).into_string(), bump));
+        }
+    }
+    Err("Unable to find a viable program address bump seed".into())
+}
+
+/// 计算关联代币账户（ATA）地址，`token_program` 为空时使用 SPL Token 程序，Token-2022 需显式传入
+pub fn derive_associated_token_address(owner: &str, mint: &str, token_program: Option<&str>) -> Result<String, Box<dyn Error + Send + Sync>> {
+    let owner = decode_pubkey(owner)?;
+    let mint = decode_pubkey(mint)?;
+    let token_program = decode_pubkey(token_program.unwrap_or(TOKEN_PROGRAM))?;
+
+    let (address, _) = find_program_address(&[&owner, &token_program, &mint], ASSOCIATED_TOKEN_ACCOUNT_PROGRAM)?;
+    Ok(address)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PYUSD_TOKEN_MINT, TOKEN_PROGRAM_2022, USDC_TOKEN_MINT, USDT_TOKEN_MINT, WSOL_TOKEN_ADDRESS};
+
+    #[test]
+    fn test_derive_associated_token_address() {
+        // 取自 testdata 中真实交易的 token 余额
+        let owner = "GGztQqQ6pCPaJQnNpXBgELr5cs3WwDakRbh1iEMzjgSJ";
+        assert_eq!(derive_associated_token_address(owner, WSOL_TOKEN_ADDRESS, None).unwrap(), "g7dD1FHSemkUQrX1Eak37wzvDjscgBW2pFCENwjLdMX");
+        assert_eq!(derive_associated_token_address(owner, USDC_TOKEN_MINT, None).unwrap(), "DVCeozFGbe6ew3eWTnZByjHeYqTq1cvbrB7JJhkLxaRJ");
+        assert_eq!(derive_associated_token_address(owner, USDT_TOKEN_MINT, None).unwrap(), "HkphEpUqnFBxBuCPEq5j1HA9L8EwmsmRT6UcFKziptM1");
+        assert_eq!(
+            derive_associated_token_address("37BenMAXFJMo3GaXKb2XLsNQXmd6VbbdShZWnwDj9D6k", USDC_TOKEN_MINT, Some(TOKEN_PROGRAM)).unwrap(),
+            "6ocrDGfR4vwdQD8HtVCpQvbpADWFSHkbrJUm2X6s7wQT"
+        );
+    }
+
+    #[test]
+    fn test_derive_associated_token_address_token_2022() {
+        let owner = "GGztQqQ6pCPaJQnNpXBgELr5cs3WwDakRbh1iEMzjgSJ";
+        let address = derive_associated_token_address(owner, PYUSD_TOKEN_MINT, Some(TOKEN_PROGRAM_2022)).unwrap();
+
+        assert_ne!(address, derive_associated_token_address(owner, PYUSD_TOKEN_MINT, None).unwrap());
+        assert_eq!(decode_pubkey(&address).unwrap().len(), 32);
+    }
+
+    #[test]
+    fn test_derive_associated_token_address_invalid() {
+        assert!(derive_associated_token_address("invalid", USDC_TOKEN_MINT, None).is_err());
+        assert!(derive_associated_token_address("GGztQqQ6pCPaJQnNpXBgELr5cs3WwDakRbh1iEMzjgSJ", "1111", None).is_err());
+    }
+}
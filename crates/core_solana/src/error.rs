@@ -0,0 +1,98 @@
+use core_jsonrpc::types::JsonRpcError;
+use std::fmt;
+
+use crate::rpc::constants::SEND_TRANSACTION_PREFLIGHT_FAILURE_ERROR;
+
+/// Solana 客户端错误
+#[derive(Debug, Clone)]
+pub enum SolanaError {
+    /// 节点返回的 JSON-RPC 错误或传输层错误
+    Rpc(JsonRpcError),
+    /// 返回数据无法解码
+    Decode(String),
+    /// 账户不存在
+    AccountNotFound,
+    /// 交易预检（模拟执行）失败，包含节点返回的错误信息
+    SimulationFailed(String),
+}
+
+impl fmt::Display for SolanaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rpc(error) => write!(f, "RPC error: {}", error),
+            Self::Decode(msg) => write!(f, "Decode error: {}", msg),
+            Self::AccountNotFound => write!(f, "Account not found"),
+            Self::SimulationFailed(msg) => write!(f, "Simulation failed: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for SolanaError {
+    /// `Rpc` 暴露内部的 `JsonRpcError`，便于上层按错误码判断是否可重试
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Rpc(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<JsonRpcError> for SolanaError {
+    /// 预检失败（-32002）转换为 `SimulationFailed`，其余保留为 `Rpc`
+    fn from(error: JsonRpcError) -> Self {
+        if error.code == SEND_TRANSACTION_PREFLIGHT_FAILURE_ERROR {
+            Self::SimulationFailed(error.message)
+        } else {
+            Self::Rpc(error)
+        }
+    }
+}
+
+impl From<serde_json::Error> for SolanaError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Decode(error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core_jsonrpc::types::{ERROR_INTERNAL_ERROR, ERROR_LIMIT_EXCEEDED};
+    use std::error::Error;
+
+    #[test]
+    fn test_from_preflight_failure() {
+        let error = SolanaError::from(JsonRpcError {
+            code: SEND_TRANSACTION_PREFLIGHT_FAILURE_ERROR,
+            message: "Transaction simulation failed: Error processing Instruction 0: custom program error: 0x1".to_string(),
+        });
+
+        assert!(matches!(error, SolanaError::SimulationFailed(ref msg) if msg.ends_with("custom program error: 0x1")));
+        assert_eq!(
+            error.to_string(),
+            "Simulation failed: Transaction simulation failed: Error processing Instruction 0: custom program error: 0x1"
+        );
+    }
+
+    #[test]
+    fn test_from_transport_error() {
+        let error = SolanaError::from(JsonRpcError {
+            code: ERROR_INTERNAL_ERROR,
+            message: "HTTP error: status 503, body len: 0".to_string(),
+        });
+
+        assert!(matches!(error, SolanaError::Rpc(error) if error.is_retryable()));
+    }
+
+    #[test]
+    fn test_source_exposes_jsonrpc_error() {
+        let error = SolanaError::from(JsonRpcError {
+            code: ERROR_LIMIT_EXCEEDED,
+            message: "request limit exceeded".to_string(),
+        });
+        let source = error.source().and_then(|source| source.downcast_ref::<JsonRpcError>()).unwrap();
+        assert_eq!(source.code, ERROR_LIMIT_EXCEEDED);
+
+        assert!(SolanaError::AccountNotFound.source().is_none());
+    }
+}
@@ -1,8 +1,13 @@
+pub mod address;
+pub mod error;
 pub mod jsonrpc;
 pub mod models;
 pub mod rpc;
 pub mod provider;
+pub mod stablecoin;
+pub mod wsol;
 
+pub use error::SolanaError;
 
 // Constants
 pub const WSOL_TOKEN_ADDRESS: &str = "So11111111111111111111111111111111111111112";
@@ -20,3 +25,6 @@ pub const SYSTEM_PROGRAM_ID: &str = "11111111111111111111111111111111";
 pub const COMPUTE_BUDGET_PROGRAM_ID: &str = "ComputeBudget111111111111111111111111111111";
 pub const JUPITER_PROGRAM_ID: &str = "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4";
 pub const COMMITMENT_CONFIRMED: &str = "confirmed";
+
+// Account sizes
+pub const TOKEN_ACCOUNT_DATA_SIZE: usize = 165;
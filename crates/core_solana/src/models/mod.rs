@@ -1,12 +1,13 @@
 pub mod rpc;
 pub mod balances;
 pub mod token;
+pub mod transaction;
 
 
 
 pub use rpc::*;
 pub use token::*;
+pub use transaction::*;
 
 
 type UInt64 = u64;
-type Int = i64;
\ No newline at end of file
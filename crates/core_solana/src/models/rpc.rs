@@ -5,6 +5,33 @@ use crate::COMMITMENT_CONFIRMED;
 pub const ENCODING_BASE64: &str = "base64";
 pub const ENCODING_BASE58: &str = "base58";
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Commitment {
+    Processed,
+    #[default]
+    Confirmed,
+    Finalized,
+}
+
+impl Commitment {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Processed => "processed",
+            Self::Confirmed => COMMITMENT_CONFIRMED,
+            Self::Finalized => "finalized",
+        }
+    }
+
+    /// 不接受 `processed` 的方法（如 `getSignaturesForAddress`、`getTransaction`）使用的级别，`Processed` 提升为 `Confirmed`
+    pub fn at_least_confirmed(self) -> Self {
+        match self {
+            Self::Processed => Self::Confirmed,
+            commitment => commitment,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Configuration {
     pub commitment: &'static str,
@@ -68,6 +95,15 @@ pub struct Info<T> {
 
 pub type AccountData = ValueData<Vec<String>>;
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpochInfo {
+    pub epoch: u64,
+    pub slot_index: u64,
+    pub slots_in_epoch: u64,
+    pub absolute_slot: u64,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Status {
@@ -97,6 +97,40 @@ pub struct TokenInfo {
     pub extensions: Option<Vec<Extension>>,
 }
 
+// mint 账户解析后的信息
+pub type TokenMint = TokenInfo;
+
+impl TokenInfo {
+    /// Token-2022 转账手续费（万分比），没有 transferFeeConfig 扩展时为 `None`
+    ///
+    /// 返回 `newerTransferFee`：它在 `epoch` 生效，此前仍按 `olderTransferFee` 收取
+    pub fn transfer_fee_basis_points(&self) -> Option<u16> {
+        self.transfer_fee_config().map(|config| config.newer_transfer_fee.transfer_fee_basis_points)
+    }
+
+    pub fn transfer_fee_config(&self) -> Option<&TransferFeeConfig> {
+        self.extensions.as_ref()?.iter().find_map(|extension| match extension {
+            Extension::TransferFeeConfig(extension) => Some(&extension.state),
+            _ => None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferFeeConfig {
+    pub newer_transfer_fee: TransferFee,
+    pub older_transfer_fee: TransferFee,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferFee {
+    pub epoch: u64,
+    pub maximum_fee: u64,
+    pub transfer_fee_basis_points: u16,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TokenMetadata {
@@ -115,6 +149,7 @@ pub struct ExtensionBase<T> {
 #[serde(untagged)]
 pub enum Extension {
     TokenMetadata(ExtensionBase<TokenMetadata>),
+    TransferFeeConfig(ExtensionBase<TransferFeeConfig>),
     Other(ExtensionBase<serde_json::Value>),
 }
 
@@ -125,3 +160,48 @@ impl ResultTokenInfo {
         self.value.data.parsed.info.clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use primitives::JsonRpcResult;
+    use serde_json::json;
+
+    fn mint(file: &str) -> TokenMint {
+        let result: JsonRpcResult<ResultTokenInfo> = serde_json::from_str(file).unwrap();
+        result.result.info()
+    }
+
+    #[test]
+    fn test_transfer_fee_basis_points() {
+        let pyusd = mint(include_str!("../../testdata/pyusd_mint.json"));
+        assert_eq!(pyusd.transfer_fee_basis_points(), Some(0));
+        assert!(pyusd.extensions.unwrap().iter().any(|extension| matches!(extension, Extension::TokenMetadata(_))));
+
+        assert_eq!(mint(include_str!("../../testdata/usdc_mint.json")).transfer_fee_basis_points(), None);
+    }
+
+    #[test]
+    fn test_transfer_fee_basis_points_non_zero() {
+        let mint: TokenMint = serde_json::from_value(json!({
+            "decimals": 9,
+            "supply": "1000000",
+            "extensions": [{
+                "extension": "transferFeeConfig",
+                "state": {
+                    "newerTransferFee": { "epoch": 700, "maximumFee": 5000000000u64, "transferFeeBasisPoints": 150 },
+                    "olderTransferFee": { "epoch": 600, "maximumFee": 0, "transferFeeBasisPoints": 0 },
+                    "transferFeeConfigAuthority": null,
+                    "withdrawWithheldAuthority": null,
+                    "withheldAmount": 0
+                }
+            }]
+        }))
+        .unwrap();
+
+        assert_eq!(mint.transfer_fee_basis_points(), Some(150));
+        let config = mint.transfer_fee_config().unwrap();
+        assert_eq!(config.newer_transfer_fee.maximum_fee, 5_000_000_000);
+        assert_eq!(config.older_transfer_fee.epoch, 600);
+    }
+}
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignatureInfo {
+    pub signature: String,
+    pub slot: u64,
+    // 交易执行失败时的错误详情，成功为 null
+    pub err: Option<serde_json::Value>,
+    pub block_time: Option<i64>,
+}
+
+// `getTransaction` 使用 jsonParsed 编码时的返回结构
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParsedTransaction {
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    // legacy 交易为 "legacy"，版本化交易为版本号，旧节点可能不返回
+    #[serde(default)]
+    pub version: Option<TransactionVersion>,
+    pub meta: Option<TransactionMeta>,
+    pub transaction: TransactionData,
+}
+
+impl ParsedTransaction {
+    pub fn instructions(&self) -> &[ParsedInstruction] {
+        &self.transaction.message.instructions
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TransactionVersion {
+    Number(u8),
+    Legacy(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionMeta {
+    pub err: Option<serde_json::Value>,
+    pub fee: u64,
+    pub pre_balances: Vec<u64>,
+    pub post_balances: Vec<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionData {
+    pub signatures: Vec<String>,
+    pub message: TransactionMessage,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionMessage {
+    pub account_keys: Vec<AccountKey>,
+    pub instructions: Vec<ParsedInstruction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountKey {
+    pub pubkey: String,
+    pub signer: bool,
+    pub writable: bool,
+}
+
+// 节点能解析的程序返回 `program`/`parsed`，其余返回原始的 `accounts`/`data`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParsedInstruction {
+    pub program_id: String,
+    pub program: Option<String>,
+    pub parsed: Option<serde_json::Value>,
+    pub accounts: Option<Vec<String>>,
+    pub data: Option<String>,
+}
@@ -2,10 +2,10 @@ use async_trait::async_trait;
 use core_chain_traits::ChainBalances;
 use std::error::Error;
 
-use crate::provider::balances_mapper::{map_balance_staking, map_coin_balance, map_token_accounts};
+use crate::provider::balances_mapper::{map_assets_balances, map_balance_staking, map_coin_balance, map_token_accounts};
 use crate::rpc::client::SolanaClient;
 use core_client::Client;
-use primitives::{AssetBalance,AssetId};
+use primitives::AssetBalance;
 
 #[async_trait]
 impl<C: Client + Clone> ChainBalances for SolanaClient<C> {
@@ -31,25 +31,54 @@ impl<C: Client + Clone> ChainBalances for SolanaClient<C> {
     }
 
     async fn get_balance_assets(&self, address: String) -> Result<Vec<AssetBalance>, Box<dyn Error + Send + Sync>> {
-        let token_accounts_result = self.get_token_accounts_by_owner(&address, crate::TOKEN_PROGRAM).await?;
-        let balances: Vec<AssetBalance> = token_accounts_result
-            .value
-            .into_iter()
-            .filter_map(|account| {
-                let token_info = &account.account.data.parsed.info;
-                if let (Some(token_amount), Some(mint)) = (&token_info.token_amount, &token_info.mint)
-                    && token_amount.amount > num_bigint::BigUint::from(0u32)
-                {
-                    let asset_id = AssetId {
-                        chain: primitives::Chain::Solana,
-                        token_id: Some(mint.clone()),
-                    };
-                    return Some(primitives::AssetBalance::new(asset_id, token_amount.amount.clone()));
-                }
-                None
-            })
-            .collect();
+        let mut accounts = Vec::new();
+        for program_id in [crate::TOKEN_PROGRAM, crate::TOKEN_PROGRAM_2022] {
+            accounts.extend(self.get_token_accounts_by_owner(&address, program_id).await?.value);
+        }
+        Ok(map_assets_balances(accounts))
+    }
+}
 
-        Ok(balances)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core_jsonrpc::{client::JsonRpcClient, testkit::mock_client};
+    use num_bigint::BigUint;
+    use primitives::{AssetId, Chain};
+    use serde_json::{Value, json};
+
+    fn token_account(mint: &str, amount: &str) -> Value {
+        json!({
+            "pubkey": format!("{mint}-account"),
+            "account": {
+                "lamports": 2039280,
+                "owner": "owner",
+                "data": { "parsed": { "info": { "mint": mint, "tokenAmount": { "amount": amount } } } }
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_get_balance_assets_merges_token_programs() {
+        let mock = mock_client(|method, params| {
+            assert_eq!(method, "getTokenAccountsByOwner");
+            match params[1]["programId"].as_str().unwrap() {
+                crate::TOKEN_PROGRAM => Ok(json!({ "value": [token_account("mintA", "100"), token_account("mintB", "0"), token_account("mintA", "50")] })),
+                crate::TOKEN_PROGRAM_2022 => Ok(json!({ "value": [token_account("mint2022", "7")] })),
+                program => panic!("unexpected program {program}"),
+            }
+        });
+        let client = SolanaClient::new(JsonRpcClient::new(mock.clone()));
+
+        let balances = client.get_balance_assets("owner".to_string()).await.unwrap();
+        let balances: Vec<_> = balances.into_iter().map(|balance| (balance.asset_id, balance.balance.available)).collect();
+        assert_eq!(
+            balances,
+            vec![
+                (AssetId::from_token(Chain::Solana, "mintA"), BigUint::from(150u32)),
+                (AssetId::from_token(Chain::Solana, "mint2022"), BigUint::from(7u32)),
+            ]
+        );
+        assert_eq!(mock.requests().len(), 2);
     }
 }
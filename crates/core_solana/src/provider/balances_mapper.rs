@@ -50,6 +50,27 @@ pub fn map_token_accounts(accounts: &ValueResult<Vec<TokenAccountInfo>>, token_i
     }
 }
 
+/// 按 mint 合并多个 token 账户（可能来自 Token 和 Token-2022 程序）的余额，忽略零余额，保持首次出现的顺序
+pub fn map_assets_balances(accounts: Vec<TokenAccountInfo>) -> Vec<AssetBalance> {
+    let mut balances: Vec<(String, BigUint)> = Vec::new();
+    for account in accounts {
+        let info = account.account.data.parsed.info;
+        let (Some(token_amount), Some(mint)) = (info.token_amount, info.mint) else {
+            continue;
+        };
+        match balances.iter_mut().find(|(existing, _)| *existing == mint) {
+            Some((_, amount)) => *amount += token_amount.amount,
+            None => balances.push((mint, token_amount.amount)),
+        }
+    }
+
+    balances
+        .into_iter()
+        .filter(|(_, amount)| *amount > BigUint::from(0u32))
+        .map(|(mint, amount)| AssetBalance::new(AssetId::from_token(Chain::Solana, &mint), amount))
+        .collect()
+}
+
 pub fn map_balance_staking(stake_accounts: Vec<TokenAccountInfo>) -> Option<AssetBalance> {
     let total_staked: u64 = stake_accounts.iter().map(|x| x.account.lamports).sum();
 
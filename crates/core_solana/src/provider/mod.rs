@@ -1,5 +1,8 @@
 pub mod balances;
 pub mod balances_mapper;
 
+pub mod staking;
+pub mod staking_mapper;
+
 pub mod state;
 pub mod state_mapper;
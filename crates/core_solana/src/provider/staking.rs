@@ -0,0 +1,14 @@
+use core_client::Client;
+use primitives::DelegationBase;
+
+use crate::provider::staking_mapper::map_delegations;
+use crate::SolanaError;
+use crate::rpc::client::SolanaClient;
+
+impl<C: Client + Clone> SolanaClient<C> {
+    pub async fn get_delegations(&self, address: &str) -> Result<Vec<DelegationBase>, SolanaError> {
+        let stake_accounts = self.get_staking_balance(address).await?;
+        let current_epoch = self.get_current_epoch().await?;
+        Ok(map_delegations(stake_accounts, current_epoch))
+    }
+}
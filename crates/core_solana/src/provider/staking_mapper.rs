@@ -0,0 +1,85 @@
+use num_bigint::BigUint;
+use primitives::{AssetId, Chain, DelegationBase, DelegationState};
+use std::str::FromStr;
+
+use crate::models::{StakeDelegation, TokenAccountInfo};
+
+/// 未发起解除质押时 `deactivationEpoch` 的取值
+pub const STAKE_DEACTIVATION_EPOCH_NONE: u64 = u64::MAX;
+
+/// 根据当前 epoch 判断质押状态：激活 epoch 之后的下一个 epoch 才完全生效，
+/// 解除质押同理，冷却结束后进入待提取状态
+pub fn map_delegation_state(delegation: &StakeDelegation, current_epoch: u64) -> DelegationState {
+    if delegation.deactivation_epoch == STAKE_DEACTIVATION_EPOCH_NONE {
+        if delegation.activation_epoch >= current_epoch {
+            DelegationState::Activating
+        } else {
+            DelegationState::Active
+        }
+    } else if delegation.deactivation_epoch >= current_epoch {
+        DelegationState::Deactivating
+    } else {
+        DelegationState::AwaitingWithdrawal
+    }
+}
+
+pub fn map_delegations(stake_accounts: Vec<TokenAccountInfo>, current_epoch: u64) -> Vec<DelegationBase> {
+    stake_accounts
+        .into_iter()
+        .filter_map(|account| {
+            let delegation = account.account.data.parsed.info.stake?.delegation;
+            Some(DelegationBase {
+                asset_id: AssetId::native(Chain::Solana),
+                state: map_delegation_state(&delegation, current_epoch),
+                balance: BigUint::from_str(&delegation.stake).unwrap_or_default(),
+                shares: BigUint::from(0u32),
+                rewards: BigUint::from(0u32),
+                completion_date: None,
+                delegation_id: account.pubkey,
+                validator_id: delegation.voter,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use primitives::JsonRpcResult;
+
+    fn stake_accounts() -> Vec<TokenAccountInfo> {
+        let result: JsonRpcResult<Vec<TokenAccountInfo>> = serde_json::from_str(include_str!("../../testdata/balance_staking.json")).unwrap();
+        result.result
+    }
+
+    #[test]
+    fn test_map_delegations() {
+        let delegations = map_delegations(stake_accounts(), 831);
+
+        assert_eq!(delegations.len(), 7);
+        let delegation = &delegations[0];
+        assert_eq!(delegation.asset_id, AssetId::native(Chain::Solana));
+        assert_eq!(delegation.delegation_id, "DYPqEPfyCFJ7LxENB1SkKvEczmgybjoWyqTR5aZDo98W");
+        assert_eq!(delegation.validator_id, "9QU2QSxhb24FUX3Tu2FpczXjpK3VYrvRudywSZaM29mF");
+        assert_eq!(delegation.balance, BigUint::from(97847072u64));
+        assert_eq!(delegation.state, DelegationState::Activating);
+
+        assert_eq!(delegations[1].state, DelegationState::AwaitingWithdrawal);
+        assert_eq!(delegations[2].state, DelegationState::Active);
+    }
+
+    #[test]
+    fn test_map_delegation_state() {
+        let delegation = |activation_epoch, deactivation_epoch| StakeDelegation {
+            activation_epoch,
+            deactivation_epoch,
+            stake: "1".to_string(),
+            voter: "voter".to_string(),
+        };
+
+        assert_eq!(map_delegation_state(&delegation(100, STAKE_DEACTIVATION_EPOCH_NONE), 100), DelegationState::Activating);
+        assert_eq!(map_delegation_state(&delegation(100, STAKE_DEACTIVATION_EPOCH_NONE), 101), DelegationState::Active);
+        assert_eq!(map_delegation_state(&delegation(100, 120), 120), DelegationState::Deactivating);
+        assert_eq!(map_delegation_state(&delegation(100, 120), 121), DelegationState::AwaitingWithdrawal);
+    }
+}
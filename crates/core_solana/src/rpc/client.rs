@@ -1,44 +1,65 @@
-use std::error::Error;
 use core_chain_traits::ChainProvider;
 use core_chain_traits::{ChainAccount, ChainAddressStatus, ChainPerpetual, ChainTraits};
 use core_client::Client;
-use core_jsonrpc::{client::JsonRpcClient as GenericJsonRpcClient, types::JsonRpcError};
+use core_jsonrpc::client::JsonRpcClient as GenericJsonRpcClient;
 use primitives::Chain;
-use crate::models::{TokenAccountInfo, ValueResult};
+use crate::models::{Commitment, EpochInfo, ParsedTransaction, SignatureInfo, TokenAccountInfo, ValueResult};
 use crate::models::balances::SolanaBalance;
+use crate::rpc::constants::MISSING_BLOCKS_ERRORS;
+use crate::SolanaError;
 
 pub struct SolanaClient<C: Client + Clone> {
     client: GenericJsonRpcClient<C>,
     pub chain: Chain,
+    commitment: Commitment,
 }
 
-pub fn token_accounts_by_owner_params(owner: &str, program_id: &str) -> serde_json::Value {
+pub fn token_accounts_by_owner_params(owner: &str, program_id: &str, commitment: Commitment) -> serde_json::Value {
     serde_json::json!([
         owner,
         {
             "programId": program_id
         },
         {
-            "encoding": "jsonParsed"
+            "encoding": "jsonParsed",
+            "commitment": commitment
         }
     ])
 }
 
-pub fn token_accounts_by_mint_params(owner: &str, mint: &str) -> serde_json::Value {
+pub fn token_accounts_by_mint_params(owner: &str, mint: &str, commitment: Commitment) -> serde_json::Value {
     serde_json::json!([
         owner,
         {
             "mint": mint
         },
         {
-            "encoding": "jsonParsed"
+            "encoding": "jsonParsed",
+            "commitment": commitment
         }
     ])
 }
 
 impl<C: Client + Clone> SolanaClient<C> {
     pub fn new(client: GenericJsonRpcClient<C>) -> Self {
-        Self { client, chain: Chain::Solana }
+        Self {
+            client,
+            chain: Chain::Solana,
+            commitment: Commitment::default(),
+        }
+    }
+
+    pub fn with_commitment(mut self, commitment: Commitment) -> Self {
+        self.commitment = commitment;
+        self
+    }
+
+    pub fn get_commitment(&self) -> Commitment {
+        self.commitment
+    }
+
+    fn commitment_config(&self) -> serde_json::Value {
+        serde_json::json!({ "commitment": self.commitment })
     }
 
     pub fn get_client(&self) -> &GenericJsonRpcClient<C> {
@@ -49,24 +70,25 @@ impl<C: Client + Clone> SolanaClient<C> {
         self.chain
     }
 
-    pub async fn rpc_call<T>(&self, method: &str, params: serde_json::Value) -> Result<T, JsonRpcError>
+    pub async fn rpc_call<T>(&self, method: &str, params: serde_json::Value) -> Result<T, SolanaError>
     where
         T: serde::de::DeserializeOwned,
     {
-        self.client.call(method, params).await
+        Ok(self.client.call(method, params).await?)
     }
 
-    pub async fn get_balance(&self, address: &str) -> Result<SolanaBalance, JsonRpcError> {
-        self.rpc_call("getBalance", serde_json::json!([address])).await
+    pub async fn get_balance(&self, address: &str) -> Result<SolanaBalance, SolanaError> {
+        self.rpc_call("getBalance", serde_json::json!([address, self.commitment_config()])).await
     }
 
 
-    pub async fn get_staking_balance(&self, address: &str) -> Result<Vec<TokenAccountInfo>, JsonRpcError> {
+    pub async fn get_staking_balance(&self, address: &str) -> Result<Vec<TokenAccountInfo>, SolanaError> {
         let stake_program_id = "Stake11111111111111111111111111111111111111";
         let params = serde_json::json!([
             stake_program_id,
             {
                 "encoding": "jsonParsed",
+                "commitment": self.commitment,
                 "filters": [
                     {
                         "memcmp": {
@@ -81,16 +103,62 @@ impl<C: Client + Clone> SolanaClient<C> {
         self.rpc_call("getProgramAccounts", params).await
     }
 
-    pub async fn get_genesis_hash(&self) -> Result<String, JsonRpcError> {
+    pub async fn get_minimum_balance_for_rent_exemption(&self, data_len: usize) -> Result<u64, SolanaError> {
+        self.rpc_call("getMinimumBalanceForRentExemption", serde_json::json!([data_len, self.commitment_config()])).await
+    }
+
+    pub async fn get_genesis_hash(&self) -> Result<String, SolanaError> {
         self.rpc_call("getGenesisHash", serde_json::json!([])).await
     }
 
-    pub async fn get_slot(&self) -> Result<u64, JsonRpcError> {
-        self.rpc_call("getSlot", serde_json::json!([])).await
+    pub async fn get_epoch_info(&self) -> Result<EpochInfo, SolanaError> {
+        self.rpc_call("getEpochInfo", serde_json::json!([self.commitment_config()])).await
+    }
+
+    pub async fn get_current_epoch(&self) -> Result<u64, SolanaError> {
+        Ok(self.get_epoch_info().await?.epoch)
+    }
+
+    pub async fn get_slot(&self) -> Result<u64, SolanaError> {
+        self.rpc_call("getSlot", serde_json::json!([self.commitment_config()])).await
+    }
+
+    /// 区块的 Unix 时间戳，节点返回 null 或区块已跳过/不可用时为 `None`
+    pub async fn get_block_time(&self, slot: u64) -> Result<Option<i64>, SolanaError> {
+        match self.rpc_call("getBlockTime", serde_json::json!([slot])).await {
+            Err(SolanaError::Rpc(error)) if MISSING_BLOCKS_ERRORS.contains(&error.code) => Ok(None),
+            result => result,
+        }
+    }
+
+    /// 按时间倒序列出地址相关的交易签名，`before` 为上一页最后一个签名
+    ///
+    /// 该方法不支持 `processed`，此时按 `confirmed` 查询
+    pub async fn get_signatures_for_address(&self, address: &str, limit: u32, before: Option<String>) -> Result<Vec<SignatureInfo>, SolanaError> {
+        let mut config = serde_json::json!({ "limit": limit, "commitment": self.commitment.at_least_confirmed() });
+        if let Some(before) = before {
+            config["before"] = serde_json::json!(before);
+        }
+        self.rpc_call("getSignaturesForAddress", serde_json::json!([address, config])).await
+    }
+
+    /// 获取解析后的交易，交易不存在或尚未确认时为 `None`
+    ///
+    /// 该方法不支持 `processed`，此时按 `confirmed` 查询
+    pub async fn get_transaction(&self, signature: &str) -> Result<Option<ParsedTransaction>, SolanaError> {
+        let params = serde_json::json!([
+            signature,
+            {
+                "encoding": "jsonParsed",
+                "maxSupportedTransactionVersion": 0,
+                "commitment": self.commitment.at_least_confirmed()
+            }
+        ]);
+        self.rpc_call("getTransaction", params).await
     }
 
-    pub async fn get_token_accounts_by_owner(&self, owner: &str, program_id: &str) -> Result<ValueResult<Vec<TokenAccountInfo>>, JsonRpcError> {
-        let params = token_accounts_by_owner_params(owner, program_id);
+    pub async fn get_token_accounts_by_owner(&self, owner: &str, program_id: &str) -> Result<ValueResult<Vec<TokenAccountInfo>>, SolanaError> {
+        let params = token_accounts_by_owner_params(owner, program_id, self.commitment);
         self.rpc_call("getTokenAccountsByOwner", params).await
     }
 
@@ -98,10 +166,10 @@ impl<C: Client + Clone> SolanaClient<C> {
         &self,
         address: &str,
         token_mints: &[String],
-    ) -> Result<Vec<ValueResult<Vec<TokenAccountInfo>>>, Box<dyn Error + Send + Sync>> {
+    ) -> Result<Vec<ValueResult<Vec<TokenAccountInfo>>>, SolanaError> {
         let calls: Vec<(String, serde_json::Value)> = token_mints
             .iter()
-            .map(|mint| ("getTokenAccountsByOwner".to_string(), token_accounts_by_mint_params(address, mint)))
+            .map(|mint| ("getTokenAccountsByOwner".to_string(), token_accounts_by_mint_params(address, mint, self.commitment)))
             .collect();
 
         let results = self.get_client().batch_call(calls).await?.extract();
@@ -126,3 +194,298 @@ impl<C: Client + Clone> ChainProvider for SolanaClient<C> {
         Chain::Solana
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TOKEN_ACCOUNT_DATA_SIZE;
+    use crate::rpc::constants::SEND_TRANSACTION_PREFLIGHT_FAILURE_ERROR;
+    use core_jsonrpc::{testkit::{MockClient, mock_client}, types::JsonRpcError};
+    use serde_json::json;
+
+    fn client(mock: MockClient) -> SolanaClient<MockClient> {
+        SolanaClient::new(GenericJsonRpcClient::new(mock))
+    }
+
+    #[tokio::test]
+    async fn test_get_minimum_balance_for_rent_exemption() {
+        let mock = mock_client(|method, params| {
+            assert_eq!(method, "getMinimumBalanceForRentExemption");
+            assert_eq!(params, &json!([165, { "commitment": "confirmed" }]));
+            Ok(json!(2039280))
+        });
+
+        let balance = client(mock).get_minimum_balance_for_rent_exemption(TOKEN_ACCOUNT_DATA_SIZE).await.unwrap();
+        assert_eq!(balance, 2039280);
+    }
+
+    #[tokio::test]
+    async fn test_default_commitment_in_params() {
+        let mock = mock_client(|method, params| {
+            assert_eq!(method, "getSlot");
+            assert_eq!(params, &json!([{ "commitment": "confirmed" }]));
+            Ok(json!(100))
+        });
+
+        assert_eq!(client(mock).get_slot().await.unwrap(), 100);
+    }
+
+    #[tokio::test]
+    async fn test_with_commitment_in_params() {
+        let mock = mock_client(|method, params| match method {
+            "getBalance" => {
+                assert_eq!(params[1], json!({ "commitment": "finalized" }));
+                Ok(json!({ "value": 1 }))
+            }
+            "getProgramAccounts" => {
+                assert_eq!(params[1]["commitment"], json!("finalized"));
+                Ok(json!([]))
+            }
+            _ => panic!("unexpected method {method}"),
+        });
+        let client = client(mock).with_commitment(Commitment::Finalized);
+
+        assert_eq!(client.get_balance("address").await.unwrap().value, 1);
+        assert!(client.get_staking_balance("address").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_epoch_info() {
+        let mock = mock_client(|method, params| {
+            assert_eq!(method, "getEpochInfo");
+            assert_eq!(params, &json!([{ "commitment": "confirmed" }]));
+            Ok(json!({
+                "absoluteSlot": 166598,
+                "blockHeight": 166500,
+                "epoch": 27,
+                "slotIndex": 2790,
+                "slotsInEpoch": 8192,
+                "transactionCount": 22661093
+            }))
+        });
+
+        let epoch_info = client(mock).get_epoch_info().await.unwrap();
+        assert_eq!(
+            epoch_info,
+            EpochInfo {
+                epoch: 27,
+                slot_index: 2790,
+                slots_in_epoch: 8192,
+                absolute_slot: 166598,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_delegations() {
+        let mock = mock_client(|method, _params| match method {
+            "getProgramAccounts" => Ok(json!([{
+                "pubkey": "DYPqEPfyCFJ7LxENB1SkKvEczmgybjoWyqTR5aZDo98W",
+                "account": {
+                    "lamports": 100129952,
+                    "owner": "Stake11111111111111111111111111111111111111",
+                    "data": {
+                        "parsed": {
+                            "info": {
+                                "stake": {
+                                    "delegation": {
+                                        "activationEpoch": "831",
+                                        "deactivationEpoch": "18446744073709551615",
+                                        "stake": "97847072",
+                                        "voter": "9QU2QSxhb24FUX3Tu2FpczXjpK3VYrvRudywSZaM29mF"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }])),
+            "getEpochInfo" => Ok(json!({ "epoch": 840, "slotIndex": 1, "slotsInEpoch": 432000, "absoluteSlot": 362880001 })),
+            _ => panic!("unexpected method {method}"),
+        });
+
+        let delegations = client(mock).get_delegations("8wytzyCBXco7yqgrLDiecpEt452MSuNWRe7xsLgAAX1H").await.unwrap();
+        assert_eq!(delegations.len(), 1);
+        assert_eq!(delegations[0].state, primitives::DelegationState::Active);
+        assert_eq!(delegations[0].validator_id, "9QU2QSxhb24FUX3Tu2FpczXjpK3VYrvRudywSZaM29mF");
+        assert_eq!(delegations[0].balance, num_bigint::BigUint::from(97847072u64));
+    }
+
+    #[tokio::test]
+    async fn test_token_accounts_commitment_in_params() {
+        let mock = mock_client(|method, params| {
+            assert_eq!(method, "getTokenAccountsByOwner");
+            assert_eq!(params[2]["commitment"], json!("processed"));
+            Ok(json!({ "value": [] }))
+        });
+        let client = client(mock).with_commitment(Commitment::Processed);
+
+        let accounts = client.get_token_accounts_by_owner("owner", crate::TOKEN_PROGRAM).await.unwrap();
+        assert!(accounts.value.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_block_time() {
+        let mock = mock_client(|method, params| {
+            assert_eq!(method, "getBlockTime");
+            match params[0].as_u64().unwrap() {
+                1 => Ok(json!(1_700_000_000)),
+                2 => Ok(json!(null)),
+                _ => Err(JsonRpcError {
+                    code: crate::rpc::constants::MISSING_OR_SKIPPED_SLOT_ERROR,
+                    message: "Slot 3 was skipped, or missing in long-term storage".to_string(),
+                }),
+            }
+        });
+        let client = client(mock);
+
+        assert_eq!(client.get_block_time(1).await.unwrap(), Some(1_700_000_000));
+        assert_eq!(client.get_block_time(2).await.unwrap(), None);
+        assert_eq!(client.get_block_time(3).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_rpc_call_preflight_failure() {
+        let mock = mock_client(|_method, _params| {
+            Err(JsonRpcError {
+                code: SEND_TRANSACTION_PREFLIGHT_FAILURE_ERROR,
+                message: "Transaction simulation failed: Blockhash not found".to_string(),
+            })
+        });
+
+        let error = client(mock).rpc_call::<String>("sendTransaction", json!(["tx"])).await.unwrap_err();
+        assert!(matches!(error, SolanaError::SimulationFailed(msg) if msg == "Transaction simulation failed: Blockhash not found"));
+    }
+
+    #[tokio::test]
+    async fn test_get_signatures_for_address() {
+        let mock = mock_client(|method, params| {
+            assert_eq!(method, "getSignaturesForAddress");
+            assert_eq!(params[0], json!("address"));
+            assert_eq!(params[1]["limit"], json!(2));
+            match params[1].get("before") {
+                None => Ok(json!([
+                    { "signature": "sig1", "slot": 102, "err": null, "memo": null, "blockTime": 1_700_000_002 },
+                    { "signature": "sig2", "slot": 101, "err": { "InstructionError": [0, { "Custom": 1 }] }, "memo": null, "blockTime": 1_700_000_001 }
+                ])),
+                Some(before) => {
+                    assert_eq!(before, &json!("sig2"));
+                    Ok(json!([{ "signature": "sig3", "slot": 100, "err": null, "memo": null, "blockTime": null }]))
+                }
+            }
+        });
+        let client = client(mock);
+
+        let page = client.get_signatures_for_address("address", 2, None).await.unwrap();
+        assert_eq!(page.len(), 2);
+        assert_eq!(page[0].signature, "sig1");
+        assert_eq!(page[0].block_time, Some(1_700_000_002));
+        assert!(page[0].err.is_none());
+        assert_eq!(page[1].err, Some(json!({ "InstructionError": [0, { "Custom": 1 }] })));
+
+        let next = client.get_signatures_for_address("address", 2, Some(page[1].signature.clone())).await.unwrap();
+        assert_eq!(
+            next,
+            vec![SignatureInfo {
+                signature: "sig3".to_string(),
+                slot: 100,
+                err: None,
+                block_time: None,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_signatures_for_address_clamps_processed() {
+        let mock = mock_client(|_, params| {
+            assert_eq!(params[1]["commitment"], json!("confirmed"));
+            Ok(json!([]))
+        });
+        let client = client(mock).with_commitment(Commitment::Processed);
+
+        assert!(client.get_signatures_for_address("address", 10, None).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction_clamps_processed() {
+        let mock = mock_client(|_, params| {
+            assert_eq!(params[1]["commitment"], json!("confirmed"));
+            Ok(json!(null))
+        });
+        assert!(client(mock).with_commitment(Commitment::Processed).get_transaction("sig").await.unwrap().is_none());
+
+        let mock = mock_client(|_, params| {
+            assert_eq!(params[1]["commitment"], json!("finalized"));
+            Ok(json!(null))
+        });
+        assert!(client(mock).with_commitment(Commitment::Finalized).get_transaction("sig").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction_sol_transfer() {
+        let mock = mock_client(|method, params| {
+            assert_eq!(method, "getTransaction");
+            assert_eq!(params[1]["encoding"], json!("jsonParsed"));
+            assert_eq!(params[1]["maxSupportedTransactionVersion"], json!(0));
+            if params[0] == json!("missing") {
+                return Ok(json!(null));
+            }
+            Ok(json!({
+                "slot": 250_000_000,
+                "blockTime": 1_700_000_000,
+                "version": 0,
+                "meta": {
+                    "err": null,
+                    "fee": 5000,
+                    "preBalances": [1_000_000_000, 0, 1],
+                    "postBalances": [899_995_000, 100_000_000, 1],
+                    "logMessages": []
+                },
+                "transaction": {
+                    "signatures": ["sig"],
+                    "message": {
+                        "accountKeys": [
+                            { "pubkey": "sender", "signer": true, "writable": true, "source": "transaction" },
+                            { "pubkey": "recipient", "signer": false, "writable": true, "source": "transaction" },
+                            { "pubkey": "11111111111111111111111111111111", "signer": false, "writable": false, "source": "transaction" }
+                        ],
+                        "instructions": [{
+                            "programId": "11111111111111111111111111111111",
+                            "program": "system",
+                            "parsed": {
+                                "type": "transfer",
+                                "info": { "source": "sender", "destination": "recipient", "lamports": 100_000_000 }
+                            },
+                            "stackHeight": null
+                        }],
+                        "recentBlockhash": "blockhash",
+                        "addressTableLookups": []
+                    }
+                }
+            }))
+        });
+        let client = client(mock);
+
+        let transaction = client.get_transaction("sig").await.unwrap().unwrap();
+        assert_eq!(transaction.version, Some(crate::models::TransactionVersion::Number(0)));
+        assert_eq!(transaction.block_time, Some(1_700_000_000));
+
+        let meta = transaction.meta.as_ref().unwrap();
+        assert_eq!(meta.fee, 5000);
+        assert_eq!(meta.pre_balances[1], 0);
+        assert_eq!(meta.post_balances[1], 100_000_000);
+        assert!(transaction.transaction.message.account_keys[0].signer);
+
+        let instruction = &transaction.instructions()[0];
+        assert_eq!(instruction.program.as_deref(), Some("system"));
+        let parsed = instruction.parsed.as_ref().unwrap();
+        assert_eq!(parsed["type"], json!("transfer"));
+        assert_eq!(parsed["info"]["lamports"], json!(100_000_000));
+
+        assert!(client.get_transaction("missing").await.unwrap().is_none());
+        assert_eq!(
+            serde_json::from_value::<crate::models::TransactionVersion>(json!("legacy")).unwrap(),
+            crate::models::TransactionVersion::Legacy("legacy".to_string())
+        );
+    }
+}
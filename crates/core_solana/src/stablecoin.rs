@@ -0,0 +1,62 @@
+use crate::{PYUSD_TOKEN_MINT, USDC_TOKEN_MINT, USDS_TOKEN_MINT, USDT_TOKEN_MINT};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StablecoinKind {
+    Usdc,
+    Usdt,
+    Usds,
+    Pyusd,
+}
+
+impl StablecoinKind {
+    pub fn all() -> [Self; 4] {
+        [Self::Usdc, Self::Usdt, Self::Usds, Self::Pyusd]
+    }
+
+    pub fn from_mint(mint: &str) -> Option<Self> {
+        Self::all().into_iter().find(|kind| kind.mint() == mint)
+    }
+
+    pub fn mint(&self) -> &'static str {
+        match self {
+            Self::Usdc => USDC_TOKEN_MINT,
+            Self::Usdt => USDT_TOKEN_MINT,
+            Self::Usds => USDS_TOKEN_MINT,
+            Self::Pyusd => PYUSD_TOKEN_MINT,
+        }
+    }
+}
+
+pub fn is_stablecoin(mint: &str) -> bool {
+    StablecoinKind::from_mint(mint).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WSOL_TOKEN_ADDRESS;
+    use primitives::{AssetId, Chain, stablecoins};
+
+    #[test]
+    fn test_from_mint() {
+        assert_eq!(StablecoinKind::from_mint(USDC_TOKEN_MINT), Some(StablecoinKind::Usdc));
+        assert_eq!(StablecoinKind::from_mint(USDT_TOKEN_MINT), Some(StablecoinKind::Usdt));
+        assert_eq!(StablecoinKind::from_mint(USDS_TOKEN_MINT), Some(StablecoinKind::Usds));
+        assert_eq!(StablecoinKind::from_mint(PYUSD_TOKEN_MINT), Some(StablecoinKind::Pyusd));
+        assert_eq!(StablecoinKind::from_mint(WSOL_TOKEN_ADDRESS), None);
+    }
+
+    #[test]
+    fn test_is_stablecoin() {
+        for kind in StablecoinKind::all() {
+            assert!(is_stablecoin(kind.mint()));
+        }
+        assert!(!is_stablecoin(WSOL_TOKEN_ADDRESS));
+    }
+
+    #[test]
+    fn test_matches_primitives_registry() {
+        let mints: Vec<AssetId> = StablecoinKind::all().iter().map(|kind| AssetId::from_token(Chain::Solana, kind.mint())).collect();
+        assert_eq!(stablecoins::stablecoins(Chain::Solana), mints);
+    }
+}
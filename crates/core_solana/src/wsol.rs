@@ -0,0 +1,32 @@
+use crate::WSOL_TOKEN_ADDRESS;
+
+pub fn is_wsol(mint: &str) -> bool {
+    mint == WSOL_TOKEN_ADDRESS
+}
+
+/// 包装 `sol` lamports 所需的总 lamports，包含临时 token 账户的免租金额 `rent`
+///
+/// `rent` 通常来自 `get_minimum_balance_for_rent_exemption(TOKEN_ACCOUNT_DATA_SIZE)`，解包关闭账户时会退回
+pub fn wrap_amount_lamports(sol: u64, rent: u64) -> u64 {
+    sol.saturating_add(rent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::USDC_TOKEN_MINT;
+
+    #[test]
+    fn test_is_wsol() {
+        assert!(is_wsol(WSOL_TOKEN_ADDRESS));
+        assert!(!is_wsol(USDC_TOKEN_MINT));
+        assert!(!is_wsol("11111111111111111111111111111111"));
+    }
+
+    #[test]
+    fn test_wrap_amount_lamports() {
+        assert_eq!(wrap_amount_lamports(1_000_000_000, 2_039_280), 1_002_039_280);
+        assert_eq!(wrap_amount_lamports(0, 2_039_280), 2_039_280);
+        assert_eq!(wrap_amount_lamports(u64::MAX, 2_039_280), u64::MAX);
+    }
+}
@@ -1,8 +1,40 @@
+mod sampling;
+pub use sampling::SamplingFilter;
+
+use opentelemetry::trace::TracerProvider;
+use opentelemetry_otlp::{ExporterBuildError, SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{Resource, trace::SdkTracerProvider};
 use settings::{Settings, TracingConfig};
-use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
+use std::error::Error;
+use tracing::{Level, Subscriber};
+use tracing_subscriber::{EnvFilter, Layer, Registry, fmt, layer::Layered, layer::SubscriberExt, reload, util::SubscriberInitExt};
+
+type ReloadFilter = reload::Layer<EnvFilter, Registry>;
+type BoxedLayer = Box<dyn Layer<Layered<ReloadFilter, Registry>> + Send + Sync>;
+
+/// 运行时调整日志过滤器的句柄，可在线程间克隆共享
+#[derive(Clone, Debug)]
+pub struct ReloadHandle(reload::Handle<EnvFilter, Registry>);
+
+impl ReloadHandle {
+    /// 将全局日志级别替换为 `level`，原有的自定义过滤规则会被覆盖
+    pub fn set_level(&self, level: Level) -> Result<(), Box<dyn Error + Send + Sync>> {
+        Ok(self.0.reload(EnvFilter::default().add_directive(level.into()))?)
+    }
+
+    /// 当前生效的过滤规则
+    pub fn current_filter(&self) -> Option<String> {
+        self.0.with_current(|filter| filter.to_string()).ok()
+    }
+}
 
 /// Core Tracing 包装器
-pub struct CoreTracing;
+///
+/// 配置了 OTLP 导出时需要持有该值，drop 时会把未导出的 span 刷新到 collector
+pub struct CoreTracing {
+    reload_handle: ReloadHandle,
+    tracer_provider: Option<SdkTracerProvider>,
+}
 
 impl CoreTracing {
     /// 使用 Settings 初始化 tracing
@@ -24,13 +56,42 @@ impl CoreTracing {
 
         tracing::info!("Initializing tracing for service: {}", service_name);
 
-        Self::init_with_config(config, service_name);
-
-        Self
+        Self::init_with_config(config, service_name)
     }
 
     /// 使用 TracingConfig 初始化
-    fn init_with_config(config: &TracingConfig, service_name: &str) {
+    fn init_with_config(config: &TracingConfig, service_name: &str) -> Self {
+        let (subscriber, reload_handle, tracer_provider) = Self::subscriber(config, service_name);
+        subscriber.init();
+
+        // 导出器构建失败时不影响本地日志，安装 subscriber 后再记录
+        let tracer_provider = tracer_provider.unwrap_or_else(|err| {
+            tracing::warn!(otlp_endpoint = config.otlp_endpoint.as_deref(), "Failed to build OTLP exporter: {}", err);
+            None
+        });
+
+        tracing::info!(
+            service = service_name,
+            level = ?config.get_level(),
+            otlp_endpoint = config.otlp_endpoint.as_deref(),
+            "Tracing initialized successfully"
+        );
+
+        Self { reload_handle, tracer_provider }
+    }
+
+    /// 运行时调整日志级别的句柄
+    pub fn reload_handle(&self) -> ReloadHandle {
+        self.reload_handle.clone()
+    }
+
+    /// 根据配置构建 subscriber 及其过滤器的 reload 句柄，配置了 `otlp_endpoint` 时一并返回对应的 TracerProvider
+    ///
+    /// 导出器构建失败时 subscriber 不包含 OTLP 层，错误随第三个返回值交给调用方
+    fn subscriber(
+        config: &TracingConfig,
+        service_name: &str,
+    ) -> (impl Subscriber + Send + Sync, ReloadHandle, Result<Option<SdkTracerProvider>, ExporterBuildError>) {
         let level = config.get_level();
 
         // 构建环境过滤器
@@ -43,21 +104,36 @@ impl CoreTracing {
                 .add_directive(level.into())
         };
 
-        // 根据配置选择 JSON 或普通格式
+        // 采样只作用于日志输出，导出到 OTLP 的 span 不受影响
+        let fmt_layer = match config.sample_rate {
+            Some(rate) => Self::fmt_layer(config).with_filter(SamplingFilter::new(rate)).boxed(),
+            None => Self::fmt_layer(config),
+        };
+        let mut layers = vec![fmt_layer];
+
+        let tracer_provider = config.otlp_endpoint.as_deref().map(|endpoint| Self::tracer_provider(endpoint, service_name)).transpose();
+        if let Ok(Some(ref provider)) = tracer_provider {
+            let tracer = provider.tracer(service_name.to_string());
+            layers.push(tracing_opentelemetry::layer().with_tracer(tracer).boxed());
+        }
+
+        let (env_filter, reload_handle) = reload::Layer::new(env_filter);
+
+        (tracing_subscriber::registry().with(env_filter).with(layers), ReloadHandle(reload_handle), tracer_provider)
+    }
+
+    /// 根据配置选择 JSON 或普通格式
+    fn fmt_layer(config: &TracingConfig) -> BoxedLayer {
         if config.json {
-            let fmt_layer = fmt::layer()
+            fmt::layer()
                 .json()
                 .with_target(config.with_target)
                 .with_thread_ids(config.with_thread_ids)
                 .with_thread_names(config.with_thread_names)
                 .with_file(config.with_file)
                 .with_line_number(config.with_line_number)
-                .with_ansi(config.with_ansi);
-
-            tracing_subscriber::registry()
-                .with(env_filter)
-                .with(fmt_layer)
-                .init();
+                .with_ansi(config.with_ansi)
+                .boxed()
         } else {
             let fmt_layer = fmt::layer()
                 .with_target(config.with_target)
@@ -68,22 +144,74 @@ impl CoreTracing {
                 .with_ansi(config.with_ansi);
 
             if config.pretty {
-                tracing_subscriber::registry()
-                    .with(env_filter)
-                    .with(fmt_layer.pretty())
-                    .init();
+                fmt_layer.pretty().boxed()
             } else {
-                tracing_subscriber::registry()
-                    .with(env_filter)
-                    .with(fmt_layer)
-                    .init();
+                fmt_layer.boxed()
             }
         }
+    }
 
-        tracing::info!(
-            service = service_name,
-            level = ?level,
-            "Tracing initialized successfully"
-        );
+    /// 创建批量导出到 `endpoint` 的 TracerProvider，不会在创建时连接 collector
+    fn tracer_provider(endpoint: &str, service_name: &str) -> Result<SdkTracerProvider, ExporterBuildError> {
+        let exporter = SpanExporter::builder().with_http().with_endpoint(endpoint).build()?;
+        let resource = Resource::builder().with_service_name(service_name.to_string()).build();
+
+        Ok(SdkTracerProvider::builder().with_batch_exporter(exporter).with_resource(resource).build())
+    }
+}
+
+impl Drop for CoreTracing {
+    fn drop(&mut self) {
+        if let Some(provider) = self.tracer_provider.take()
+            && let Err(err) = provider.shutdown()
+        {
+            tracing::error!("Failed to shutdown tracer provider: {}", err);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 安装全局 subscriber，同一测试进程内只能有一个测试调用 init
+    #[test]
+    fn test_init_with_otlp_endpoint() {
+        let config = TracingConfig {
+            otlp_endpoint: Some("http://127.0.0.1:4318/v1/traces".to_string()),
+            ..Default::default()
+        };
+
+        let core_tracing = CoreTracing::init_with_config(&config, "test");
+        assert!(core_tracing.tracer_provider.is_some());
+
+        tracing::info_span!("otlp_test").in_scope(|| tracing::info!("exported without a live collector"));
+
+        // 没有 collector 时导出失败，但不应 panic 或阻塞
+        drop(core_tracing);
+    }
+
+    #[test]
+    fn test_subscriber_without_otlp_endpoint() {
+        let (_, _, tracer_provider) = CoreTracing::subscriber(&TracingConfig::default(), "test");
+        assert!(tracer_provider.unwrap().is_none());
+    }
+
+    #[test]
+    fn test_reload_handle_updates_level() {
+        let config = TracingConfig {
+            filter: Some("info".to_string()),
+            ..Default::default()
+        };
+        let (subscriber, reload_handle, _) = CoreTracing::subscriber(&config, "test");
+
+        tracing::subscriber::with_default(subscriber, || {
+            assert!(!tracing::enabled!(Level::DEBUG));
+
+            reload_handle.set_level(Level::DEBUG).unwrap();
+
+            assert!(tracing::enabled!(Level::DEBUG));
+            assert_eq!(reload_handle.current_filter(), Some("debug".to_string()));
+        });
     }
-}
\ No newline at end of file
+}
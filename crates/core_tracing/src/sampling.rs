@@ -0,0 +1,79 @@
+use tracing::{Level, Metadata, subscriber::Interest};
+use tracing_subscriber::layer::{Context, Filter};
+
+/// 按比例随机丢弃 WARN 以下的日志事件，WARN/ERROR 事件和 span 始终放行
+#[derive(Debug, Clone, Copy)]
+pub struct SamplingFilter {
+    rate: f64,
+}
+
+impl SamplingFilter {
+    /// `rate` 为保留比例，超出 0.0 ~ 1.0 的值会被截断
+    pub fn new(rate: f64) -> Self {
+        Self { rate: rate.clamp(0.0, 1.0) }
+    }
+
+    fn is_sampled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.is_event() && *metadata.level() > Level::WARN && self.rate < 1.0
+    }
+}
+
+impl<S> Filter<S> for SamplingFilter {
+    fn enabled(&self, metadata: &Metadata<'_>, _ctx: &Context<'_, S>) -> bool {
+        !self.is_sampled(metadata) || rand::random::<f64>() < self.rate
+    }
+
+    // 被采样的 callsite 每次都要重新判断，不能缓存结果
+    fn callsite_enabled(&self, metadata: &'static Metadata<'static>) -> Interest {
+        if self.is_sampled(metadata) { Interest::sometimes() } else { Interest::always() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+    use tracing_subscriber::{Layer, layer::SubscriberExt};
+
+    #[derive(Clone, Default)]
+    struct EventCounter {
+        info: Arc<AtomicUsize>,
+        error: Arc<AtomicUsize>,
+    }
+
+    impl<S: tracing::Subscriber> Layer<S> for EventCounter {
+        fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+            match *event.metadata().level() {
+                Level::ERROR => self.error.fetch_add(1, Ordering::SeqCst),
+                _ => self.info.fetch_add(1, Ordering::SeqCst),
+            };
+        }
+    }
+
+    fn count_events(rate: f64) -> (usize, usize) {
+        let counter = EventCounter::default();
+        let subscriber = tracing_subscriber::registry().with(counter.clone().with_filter(SamplingFilter::new(rate)));
+
+        tracing::subscriber::with_default(subscriber, || {
+            for _ in 0..10 {
+                tracing::info!("sampled");
+                tracing::error!("always logged");
+            }
+        });
+
+        (counter.info.load(Ordering::SeqCst), counter.error.load(Ordering::SeqCst))
+    }
+
+    #[test]
+    fn test_zero_rate_drops_info_keeps_error() {
+        assert_eq!(count_events(0.0), (0, 10));
+    }
+
+    #[test]
+    fn test_full_rate_keeps_all() {
+        assert_eq!(count_events(1.0), (10, 10));
+    }
+}
@@ -1,5 +1,6 @@
 use std::{collections::HashSet, error::Error};
 
+use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
 use typeshare::typeshare;
 
@@ -56,6 +57,21 @@ impl Asset {
         format!("{} ({})", self.name, self.symbol)
     }
 
+    /// 将最小单位的原始数量格式化为可读金额，保留全部有效小数位并去掉末尾的 0，例如 `"1.5 ETH"`
+    pub fn format_amount(&self, raw: &BigUint) -> String {
+        let decimals = self.decimals.max(0) as usize;
+        let digits = raw.to_string();
+        let digits = format!("{:0>width$}", digits, width = decimals + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - decimals);
+        let fraction = fraction.trim_end_matches('0');
+
+        if fraction.is_empty() {
+            format!("{} {}", integer, self.symbol)
+        } else {
+            format!("{}.{} {}", integer, fraction, self.symbol)
+        }
+    }
+
     pub fn as_basic_primitive(&self) -> AssetBasic {
         AssetBasic::new(self.clone(), AssetProperties::default(self.id.clone()), AssetScore::default())
     }
@@ -96,13 +112,25 @@ impl AssetVecExt for Vec<Asset> {
     }
 }
 
+pub trait AssetBasicVecExt {
+    fn sorted_by_score(self) -> Vec<AssetBasic>;
+}
+
+impl AssetBasicVecExt for Vec<AssetBasic> {
+    /// 按 `AssetScore::combined_rank` 从高到低排序，同分保持原有顺序
+    fn sorted_by_score(mut self) -> Vec<AssetBasic> {
+        self.sort_by_key(|asset| std::cmp::Reverse(asset.score.combined_rank(asset.asset.chain())));
+        self
+    }
+}
+
 pub trait AssetHashSetExt {
     fn ids(&self) -> Vec<String>;
 }
 
 impl AssetHashSetExt for HashSet<AssetId> {
     fn ids(&self) -> Vec<String> {
-        self.iter().cloned().map(|x| x.to_string()).collect()
+        self.iter().map(|x| x.to_string()).collect()
     }
 }
 
@@ -116,4 +144,41 @@ mod tests {
 
         assert_eq!(asset.symbol, "ETH");
     }
+
+    #[test]
+    fn test_format_amount() {
+        let eth = Asset::from_chain(Chain::Ethereum);
+        assert_eq!(eth.format_amount(&BigUint::from(1_500_000_000_000_000_000u64)), "1.5 ETH");
+        assert_eq!(eth.format_amount(&BigUint::from(2_000_000_000_000_000_000u64)), "2 ETH");
+        assert_eq!(eth.format_amount(&BigUint::from(1u32)), "0.000000000000000001 ETH");
+        assert_eq!(eth.format_amount(&BigUint::from(0u32)), "0 ETH");
+
+        let sol = Asset::from_chain(Chain::Solana);
+        assert_eq!(sol.format_amount(&BigUint::from(12_345_678_901u64)), "12.345678901 SOL");
+    }
+
+    #[test]
+    fn test_sorted_by_score() {
+        let token = |chain: Chain, symbol: &str, rank: i32| {
+            let asset = Asset::new(AssetId::from_token(chain, symbol), symbol.to_string(), symbol.to_string(), 18, AssetType::ERC20);
+            AssetBasic::new(asset, AssetProperties::default(AssetId::from_token(chain, symbol)), AssetScore::new(rank))
+        };
+        let assets = vec![
+            token(Chain::Ethereum, "LOW", 15),
+            token(Chain::Arbitrum, "HIGH", 100),
+            token(Chain::Polygon, "MEDIUM", 50),
+            token(Chain::Ethereum, "MEDIUM", 50),
+        ];
+
+        let ordered: Vec<_> = assets.sorted_by_score().into_iter().map(|asset| (asset.asset.chain(), asset.asset.symbol)).collect();
+        assert_eq!(
+            ordered,
+            vec![
+                (Chain::Arbitrum, "HIGH".to_string()),
+                (Chain::Ethereum, "MEDIUM".to_string()),
+                (Chain::Polygon, "MEDIUM".to_string()),
+                (Chain::Ethereum, "LOW".to_string()),
+            ]
+        );
+    }
 }
@@ -1,5 +1,6 @@
-use crate::AssetId;
+use crate::{AssetId, Price};
 use num_bigint::BigUint;
+use num_traits::{ToPrimitive, Zero};
 use serde::{Deserialize, Serialize};
 use typeshare::typeshare;
 
@@ -50,8 +51,20 @@ impl AssetBalance {
             is_active: true,
         }
     }
+
+    /// 资产总额：available + staked + pending + rewards + locked + frozen
+    pub fn total(&self) -> BigUint {
+        self.balance.total()
+    }
+
+    /// 资产总额按 `price` 折算的法币价值
+    pub fn fiat_value(&self, price: &Price, decimals: u32) -> f64 {
+        self.balance.fiat_value(price, decimals)
+    }
 }
 
+const FRACTION_PRECISION: u32 = 18;
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Balance {
@@ -85,6 +98,17 @@ pub struct BalanceMetadata {
     pub bandwidth_total: u32,
 }
 
+impl BalanceMetadata {
+    fn add(&self, other: &BalanceMetadata) -> BalanceMetadata {
+        BalanceMetadata {
+            votes: self.votes.saturating_add(other.votes),
+            energy_available: self.energy_available.saturating_add(other.energy_available),
+            energy_total: self.energy_total.saturating_add(other.energy_total),
+            bandwidth_available: self.bandwidth_available.saturating_add(other.bandwidth_available),
+            bandwidth_total: self.bandwidth_total.saturating_add(other.bandwidth_total),
+        }
+    }
+}
 
 impl Balance {
     pub fn coin_balance(available: BigUint) -> Self {
@@ -136,6 +160,59 @@ impl Balance {
             metadata,
         }
     }
+
+    /// 质押占比：staked / (available + staked)，范围 0–1；分母为 0 时返回 0
+    pub fn staked_fraction(&self) -> f64 {
+        let total = &self.available + &self.staked;
+        if total.is_zero() {
+            return 0.0;
+        }
+        // 先放大为整数再相除，避免超出 f64 精度的大数直接转换
+        let scale = BigUint::from(10u32).pow(FRACTION_PRECISION);
+        let scaled = (&self.staked * &scale) / total;
+        scaled.to_f64().unwrap_or(0.0) / scale.to_f64().unwrap_or(1.0)
+    }
+
+    /// 将 available 按 10^decimals 缩放为浮点数，例如 1_500_000 (6 位精度) -> 1.5
+    pub fn available_decimal(&self, decimals: u32) -> f64 {
+        to_decimal(&self.available, decimals)
+    }
+
+    /// 总额：available + staked + pending + rewards + locked + frozen
+    pub fn total(&self) -> BigUint {
+        &self.available + &self.staked + &self.pending + &self.rewards + &self.locked + &self.frozen
+    }
+
+    /// 总额按 `price` 折算的法币价值，`decimals` 为资产精度
+    pub fn fiat_value(&self, price: &Price, decimals: u32) -> f64 {
+        to_decimal(&self.total(), decimals) * price.price
+    }
+
+    /// 逐字段相加两个余额；任一方没有 metadata 时结果的 metadata 为 `None`
+    pub fn add(&self, other: &Balance) -> Balance {
+        Balance {
+            available: &self.available + &other.available,
+            frozen: &self.frozen + &other.frozen,
+            locked: &self.locked + &other.locked,
+            staked: &self.staked + &other.staked,
+            pending: &self.pending + &other.pending,
+            rewards: &self.rewards + &other.rewards,
+            reserved: &self.reserved + &other.reserved,
+            withdrawable: &self.withdrawable + &other.withdrawable,
+            metadata: match (&self.metadata, &other.metadata) {
+                (Some(lhs), Some(rhs)) => Some(lhs.add(rhs)),
+                _ => None,
+            },
+        }
+    }
+}
+
+// 整数部分和小数部分分别转换，避免大数整体转 f64 时丢失小数精度
+fn to_decimal(value: &BigUint, decimals: u32) -> f64 {
+    let scale = BigUint::from(10u32).pow(decimals);
+    let integer = value / &scale;
+    let fraction = value % &scale;
+    integer.to_f64().unwrap_or(f64::INFINITY) + fraction.to_f64().unwrap_or(0.0) / scale.to_f64().unwrap_or(f64::INFINITY)
 }
 
 #[cfg(test)]
@@ -199,4 +276,80 @@ mod tests {
         // 验证所有 BigUint 字段都序列化为字符串
         assert!(serialized.contains("\"available\": \"1000000\""));
     }
+
+    #[test]
+    fn test_balance_add() {
+        let metadata = BalanceMetadata {
+            votes: 1,
+            energy_available: 10,
+            energy_total: 20,
+            bandwidth_available: 30,
+            bandwidth_total: 40,
+        };
+        let lhs = Balance::stake_balance_with_metadata(BigUint::from(100u32), BigUint::from(5u32), Some(BigUint::from(1u32)), Some(metadata.clone()));
+        let mut rhs = Balance::coin_balance(BigUint::from(1_000u32));
+        rhs.metadata = Some(metadata);
+
+        let sum = lhs.add(&rhs);
+        assert_eq!(sum.available, BigUint::from(1_000u32));
+        assert_eq!(sum.staked, BigUint::from(100u32));
+        assert_eq!(sum.pending, BigUint::from(5u32));
+        assert_eq!(sum.rewards, BigUint::from(1u32));
+        assert_eq!(sum.metadata.as_ref().map(|m| (m.votes, m.bandwidth_total)), Some((2, 80)));
+
+        let sum = lhs.add(&Balance::coin_balance(BigUint::from(1u32)));
+        assert_eq!(sum.available, BigUint::from(1u32));
+        assert_eq!(sum.metadata, None);
+    }
+
+    #[test]
+    fn test_asset_balance_total() {
+        let mut asset_balance = AssetBalance::new_staking(
+            AssetId::new("solana").unwrap(),
+            BigUint::from(2_000_000_000u64),
+            BigUint::from(500_000_000u64),
+            BigUint::from(25_000_000u64),
+        );
+        asset_balance.balance.available = BigUint::from(1_000u32);
+        asset_balance.balance.reserved = BigUint::from(7u32);
+
+        assert_eq!(asset_balance.total(), BigUint::from(2_525_001_000u64));
+    }
+
+    #[test]
+    fn test_staked_fraction() {
+        let wei = BigUint::from(10u32).pow(18);
+        let mut balance = Balance::stake_balance(BigUint::from(3u32) * &wei * &wei, BigUint::zero(), None);
+        balance.available = &wei * &wei;
+
+        assert_eq!(balance.staked_fraction(), 0.75);
+        assert_eq!(Balance::coin_balance(BigUint::from(1u32)).staked_fraction(), 0.0);
+        assert_eq!(Balance::zero().staked_fraction(), 0.0);
+    }
+
+    #[test]
+    fn test_available_decimal() {
+        assert_eq!(Balance::coin_balance(BigUint::from(1_500_000u32)).available_decimal(6), 1.5);
+        assert_eq!(Balance::coin_balance(BigUint::from(10u32).pow(36)).available_decimal(18), 1e18);
+        assert_eq!(Balance::zero().available_decimal(9), 0.0);
+    }
+
+    #[test]
+    fn test_fiat_value_large_balance() {
+        let price = Price::new(2_000.0, 0.0, Default::default());
+        // 1e12 ETH 再加 0.5 ETH，整体转 f64 会丢掉小数部分
+        let mut balance = Balance::coin_balance(BigUint::from(10u32).pow(30) + BigUint::from(5u32) * BigUint::from(10u32).pow(17));
+        balance.staked = BigUint::from(10u32).pow(18);
+
+        assert_eq!(balance.fiat_value(&price, 18), (1e12 + 1.5) * 2_000.0);
+    }
+
+    #[test]
+    fn test_fiat_value_few_decimals() {
+        let price = Price::new(0.5, 0.0, Default::default());
+        let asset_balance = AssetBalance::new(AssetId::new("solana").unwrap(), BigUint::from(1_234_567u32));
+
+        assert_eq!(asset_balance.fiat_value(&price, 2), 12_345.67 * 0.5);
+        assert_eq!(AssetBalance::new_zero_balance(AssetId::new("solana").unwrap()).fiat_value(&price, 2), 0.0);
+    }
 }
@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
 use typeshare::typeshare;
+use url::Url;
 
-use crate::{Asset, AssetId, AssetMarket, AssetScore, LinkType, Price, perpetual::PerpetualBasic};
+use crate::{Asset, AssetId, AssetMarket, AssetScore, LinkType, Price, asset_score::AssetRank, perpetual::PerpetualBasic};
 
 #[typeshare(swift = "Sendable")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,6 +20,27 @@ pub struct AssetFull {
     pub market: Option<AssetMarket>,
 }
 
+impl AssetFull {
+    /// 由各组成部分组装完整资产信息，`properties` 覆盖 `basic` 中的属性
+    pub fn assemble(basic: AssetBasic, market_price: AssetMarketPrice, properties: AssetProperties, links: Vec<AssetLink>) -> Self {
+        Self {
+            asset: basic.asset,
+            properties,
+            score: basic.score,
+            tags: vec![],
+            links,
+            perpetuals: vec![],
+            price: market_price.price,
+            market: market_price.market,
+        }
+    }
+
+    /// 资产已启用且评分至少达到 `AssetRank::Low`
+    pub fn is_verified(&self) -> bool {
+        self.properties.is_enabled && self.score.rank >= AssetRank::Low.threshold()
+    }
+}
+
 #[typeshare(swift = "Sendable")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -48,6 +70,13 @@ pub struct AssetMarketPrice {
     pub market: Option<AssetMarket>,
 }
 
+impl AssetMarketPrice {
+    /// 价格距 `now`（Unix 秒）的年龄，没有价格时返回 `u64::MAX`
+    pub fn age_secs(&self, now: u64) -> u64 {
+        self.price.as_ref().map_or(u64::MAX, |price| price.age_secs(now))
+    }
+}
+
 #[typeshare(swift = "Sendable")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -90,4 +119,75 @@ impl AssetLink {
             url: url.to_string(),
         }
     }
+
+    /// 链接可解析且协议为 http/https，`javascript:`、`data:` 等协议视为无效
+    pub fn is_valid(&self) -> bool {
+        self.typed_url().is_some()
+    }
+
+    /// 解析后的链接，无效时返回 `None`
+    pub fn typed_url(&self) -> Option<Url> {
+        Url::parse(&self.url).ok().filter(|url| matches!(url.scheme(), "http" | "https") && url.has_host())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Chain;
+
+    fn assemble(rank: i32, is_enabled: bool) -> AssetFull {
+        let asset = Asset::from_chain(Chain::Ethereum);
+        let basic = AssetBasic::new(asset.clone(), AssetProperties::default(asset.id.clone()), AssetScore::new(rank));
+        let properties = AssetProperties {
+            is_enabled,
+            ..AssetProperties::default(asset.id.clone())
+        };
+        let market_price = AssetMarketPrice { price: None, market: None };
+        AssetFull::assemble(basic, market_price, properties, vec![AssetLink::new("https://ethereum.org", LinkType::Website)])
+    }
+
+    #[test]
+    fn test_assemble() {
+        let full = assemble(50, true);
+
+        assert_eq!(full.asset.symbol, "ETH");
+        assert_eq!(full.score.rank, 50);
+        assert_eq!(full.links, vec![AssetLink::new("https://ethereum.org", LinkType::Website)]);
+        assert!(full.properties.is_stakeable);
+        assert!(full.price.is_none());
+        assert!(full.is_verified());
+    }
+
+    #[test]
+    fn test_is_verified() {
+        assert!(!assemble(50, false).is_verified());
+        assert!(!assemble(AssetScore::default().rank, true).is_verified());
+        assert!(assemble(AssetRank::Low.threshold(), true).is_verified());
+    }
+
+    #[test]
+    fn test_market_price_age_secs() {
+        let price = Price::new(100.0, 0.0, chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap());
+        let fresh = AssetMarketPrice { price: Some(price), market: None };
+
+        assert_eq!(fresh.age_secs(1_700_000_042), 42);
+        assert_eq!(AssetMarketPrice { price: None, market: None }.age_secs(1_700_000_042), u64::MAX);
+    }
+
+    #[test]
+    fn test_asset_link_valid() {
+        let link = AssetLink::new("https://x.com/ethereum", LinkType::X);
+
+        assert!(link.is_valid());
+        assert_eq!(link.typed_url().unwrap().host_str(), Some("x.com"));
+    }
+
+    #[test]
+    fn test_asset_link_invalid() {
+        assert!(!AssetLink::new("javascript:alert(document.cookie)", LinkType::Website).is_valid());
+        assert!(!AssetLink::new("data:text/html,<script>alert(1)</script>", LinkType::Website).is_valid());
+        assert!(!AssetLink::new("ftp://example.com", LinkType::Website).is_valid());
+        assert!(!AssetLink::new("example.com", LinkType::Website).is_valid());
+    }
 }
@@ -1,4 +1,8 @@
-use std::{collections::HashSet, fmt};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    str::FromStr,
+};
 
 use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
 
@@ -6,6 +10,27 @@ use crate::{AssetSubtype, chain::Chain};
 
 pub const TOKEN_ID_SEPARATOR: &str = "::";
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssetIdError {
+    Empty,
+    UnknownChain(String),
+    EmptyTokenId(String),
+    InvalidEncoding(String),
+}
+
+impl fmt::Display for AssetIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssetIdError::Empty => write!(f, "Asset id is empty"),
+            AssetIdError::UnknownChain(chain) => write!(f, "Unknown chain in asset id: {}", chain),
+            AssetIdError::EmptyTokenId(asset_id) => write!(f, "Empty token id in asset id: {}", asset_id),
+            AssetIdError::InvalidEncoding(segment) => write!(f, "Invalid percent-encoding in asset id: {}", segment),
+        }
+    }
+}
+
+impl std::error::Error for AssetIdError {}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AssetId {
     pub chain: Chain,
@@ -37,6 +62,26 @@ impl From<&str> for AssetId {
     }
 }
 
+impl FromStr for AssetId {
+    type Err = AssetIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(AssetIdError::Empty);
+        }
+        let (chain, token_id) = match s.split_once('_') {
+            Some((chain, token_id)) => (chain, Some(token_id)),
+            None => (s, None),
+        };
+        let chain = chain.parse::<Chain>().map_err(|_| AssetIdError::UnknownChain(chain.to_string()))?;
+        match token_id {
+            Some("") => Err(AssetIdError::EmptyTokenId(s.to_string())),
+            Some(token_id) => Ok(AssetId::from_token(chain, token_id)),
+            None => Ok(AssetId::from_chain(chain)),
+        }
+    }
+}
+
 impl fmt::Display for AssetId {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let str = match &self.token_id {
@@ -57,20 +102,7 @@ impl From<AssetId> for String {
 
 impl AssetId {
     pub fn new(asset_id: &str) -> Option<Self> {
-        let split: Vec<&str> = asset_id.split('_').collect();
-        if split.len() == 1 {
-            if let Ok(chain) = asset_id.parse::<Chain>() {
-                return Some(AssetId { chain, token_id: None });
-            }
-        } else if split.len() >= 2
-            && let Ok(chain) = split[0].parse::<Chain>()
-        {
-            return Some(AssetId {
-                chain,
-                token_id: Some(split[1..split.len()].join("_")),
-            });
-        }
-        None
+        asset_id.parse().ok()
     }
 
     pub fn from(chain: Chain, token_id: Option<String>) -> AssetId {
@@ -88,6 +120,42 @@ impl AssetId {
         AssetId { chain, token_id: None }
     }
 
+    /// 链原生币的资产 ID
+    pub fn native(chain: Chain) -> AssetId {
+        Self::from_chain(chain)
+    }
+
+    /// 编码为可直接放入 URL 路径的片段，非 unreserved 字符（RFC 3986）按 `%XX` 转义
+    pub fn to_path_segment(&self) -> String {
+        self.to_string()
+            .bytes()
+            .map(|byte| match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (byte as char).to_string(),
+                _ => format!("%{:02X}", byte),
+            })
+            .collect()
+    }
+
+    /// 解析 `to_path_segment` 生成的路径片段
+    pub fn from_path_segment(segment: &str) -> Result<AssetId, AssetIdError> {
+        let invalid = || AssetIdError::InvalidEncoding(segment.to_string());
+        let bytes = segment.as_bytes();
+        let mut decoded = Vec::with_capacity(bytes.len());
+        let mut index = 0;
+        while index < bytes.len() {
+            if bytes[index] == b'%' {
+                let hex = bytes.get(index + 1..index + 3).filter(|hex| hex.iter().all(u8::is_ascii_hexdigit)).ok_or_else(invalid)?;
+                let hex = std::str::from_utf8(hex).map_err(|_| invalid())?;
+                decoded.push(u8::from_str_radix(hex, 16).map_err(|_| invalid())?);
+                index += 3;
+            } else {
+                decoded.push(bytes[index]);
+                index += 1;
+            }
+        }
+        String::from_utf8(decoded).map_err(|_| invalid())?.parse()
+    }
+
     pub fn sub_token_id(ids: &[String]) -> String {
         ids.join(TOKEN_ID_SEPARATOR)
     }
@@ -122,6 +190,11 @@ impl AssetId {
         self.token_id.is_some()
     }
 
+    /// 代币的合约地址 / mint 地址，原生币返回 `None`
+    pub fn token_address(&self) -> Option<&str> {
+        self.token_id.as_deref()
+    }
+
     pub fn token_subtype(&self) -> AssetSubtype {
         if self.is_native() { AssetSubtype::NATIVE } else { AssetSubtype::TOKEN }
     }
@@ -144,6 +217,8 @@ impl AssetId {
 pub trait AssetIdVecExt {
     fn ids(&self) -> Vec<String>;
     fn ids_set(&self) -> HashSet<AssetId>;
+    fn group_by_chain(&self) -> HashMap<Chain, Vec<AssetId>>;
+    fn chains(&self) -> HashSet<Chain>;
 }
 
 impl AssetIdVecExt for Vec<AssetId> {
@@ -154,6 +229,18 @@ impl AssetIdVecExt for Vec<AssetId> {
     fn ids_set(&self) -> HashSet<AssetId> {
         self.iter().cloned().collect()
     }
+
+    fn group_by_chain(&self) -> HashMap<Chain, Vec<AssetId>> {
+        let mut groups: HashMap<Chain, Vec<AssetId>> = HashMap::new();
+        for asset_id in self {
+            groups.entry(asset_id.chain).or_default().push(asset_id.clone());
+        }
+        groups
+    }
+
+    fn chains(&self) -> HashSet<Chain> {
+        self.iter().map(|x| x.chain).collect()
+    }
 }
 
 #[cfg(test)]
@@ -174,6 +261,86 @@ mod tests {
         assert_eq!(asset_id.token_id, Some("0x1234567890abcdef".to_owned()));
     }
 
+    #[test]
+    fn test_from_str() {
+        let native: AssetId = "solana".parse().unwrap();
+        assert_eq!(native, AssetId::from_chain(Chain::Solana));
+        assert_eq!(native.to_string(), "solana");
+
+        let token: AssetId = "ethereum_0xdAC17F958D2ee523a2206206994597C13D831ec7".parse().unwrap();
+        assert_eq!(token, AssetId::from_token(Chain::Ethereum, "0xdAC17F958D2ee523a2206206994597C13D831ec7"));
+        assert_eq!(token.to_string(), "ethereum_0xdAC17F958D2ee523a2206206994597C13D831ec7");
+
+        let nested: AssetId = "ethereum_USDC_0x1".parse().unwrap();
+        assert_eq!(nested.token_id.as_deref(), Some("USDC_0x1"));
+        assert_eq!(nested.to_string(), "ethereum_USDC_0x1");
+    }
+
+    #[test]
+    fn test_from_str_invalid() {
+        assert_eq!("foo_bar".parse::<AssetId>(), Err(AssetIdError::UnknownChain("foo".to_string())));
+        assert_eq!("".parse::<AssetId>(), Err(AssetIdError::Empty));
+        assert_eq!("ethereum_".parse::<AssetId>(), Err(AssetIdError::EmptyTokenId("ethereum_".to_string())));
+    }
+
+    #[test]
+    fn test_path_segment_roundtrip() {
+        let asset_id = AssetId::from_token(Chain::Ethereum, "0xdAC17F958D2ee523a2206206994597C13D831ec7");
+        assert_eq!(asset_id.to_path_segment(), "ethereum_0xdAC17F958D2ee523a2206206994597C13D831ec7");
+
+        let asset_id = AssetId::from_token(Chain::Solana, &AssetId::sub_token_id(&["USDC".to_string(), "a/b c".to_string()]));
+        let segment = asset_id.to_path_segment();
+        assert_eq!(segment, "solana_USDC%3A%3Aa%2Fb%20c");
+
+        let path = format!("/v1/assets/{}/prices", segment);
+        let parsed = path.split('/').nth(3).map(AssetId::from_path_segment).unwrap().unwrap();
+        assert_eq!(parsed, asset_id);
+
+        assert_eq!(AssetId::from_path_segment("smartchain").unwrap(), AssetId::native(Chain::SmartChain));
+    }
+
+    #[test]
+    fn test_from_path_segment_invalid() {
+        assert_eq!(AssetId::from_path_segment("ethereum_%2"), Err(AssetIdError::InvalidEncoding("ethereum_%2".to_string())));
+        assert_eq!(AssetId::from_path_segment("ethereum_%zz"), Err(AssetIdError::InvalidEncoding("ethereum_%zz".to_string())));
+        assert_eq!(AssetId::from_path_segment("ethereum_%+F"), Err(AssetIdError::InvalidEncoding("ethereum_%+F".to_string())));
+        assert_eq!(AssetId::from_path_segment("ethereum_%FF"), Err(AssetIdError::InvalidEncoding("ethereum_%FF".to_string())));
+        assert_eq!(AssetId::from_path_segment("bitcoin"), Err(AssetIdError::UnknownChain("bitcoin".to_string())));
+    }
+
+    #[test]
+    fn test_native_and_token_address() {
+        let native = AssetId::native(Chain::Ethereum);
+        assert!(native.is_native());
+        assert!(!native.is_token());
+        assert_eq!(native.token_address(), None);
+        assert_eq!(native, AssetId::new("ethereum").unwrap());
+
+        let token = AssetId::from_token(Chain::Solana, "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+        assert!(!token.is_native());
+        assert!(token.is_token());
+        assert_eq!(token.token_address(), Some("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"));
+    }
+
+    #[test]
+    fn test_group_by_chain() {
+        let asset_ids = vec![
+            AssetId::native(Chain::Ethereum),
+            AssetId::from_token(Chain::Solana, "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"),
+            AssetId::from_token(Chain::Ethereum, "0xdAC17F958D2ee523a2206206994597C13D831ec7"),
+            AssetId::native(Chain::SmartChain),
+        ];
+
+        let groups = asset_ids.group_by_chain();
+        assert_eq!(groups.len(), 3);
+        assert_eq!(
+            groups[&Chain::Ethereum],
+            vec![AssetId::native(Chain::Ethereum), AssetId::from_token(Chain::Ethereum, "0xdAC17F958D2ee523a2206206994597C13D831ec7")]
+        );
+        assert_eq!(groups[&Chain::Solana].len(), 1);
+        assert_eq!(groups[&Chain::SmartChain], vec![AssetId::native(Chain::SmartChain)]);
+        assert_eq!(asset_ids.chains(), HashSet::from([Chain::Ethereum, Chain::Solana, Chain::SmartChain]));
+    }
 
     #[test]
     fn test_decode_token_id() {
@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use typeshare::typeshare;
 
 use crate::{AssetId, Price};
@@ -23,6 +24,14 @@ impl AssetPrice {
             updated_at,
         }
     }
+
+    /// 相对 `previous` 的涨跌幅（百分比），`previous` 为 0 时返回 0
+    pub fn change_percent(&self, previous: f64) -> f64 {
+        if previous == 0.0 {
+            return 0.0;
+        }
+        (self.price - previous) / previous * 100.0
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -54,6 +63,28 @@ pub struct AssetPricesRequest {
     pub asset_ids: Vec<AssetId>,
 }
 
+impl AssetPricesRequest {
+    /// 去除重复的资产 id，保留首次出现的顺序
+    pub fn deduplicated(&self) -> AssetPricesRequest {
+        let mut seen = HashSet::new();
+        AssetPricesRequest {
+            currency: self.currency.clone(),
+            asset_ids: self.asset_ids.iter().filter(|asset_id| seen.insert(*asset_id)).cloned().collect(),
+        }
+    }
+
+    /// 按每个请求最多 `max` 个 id 拆分，`max` 为 0 时按 1 处理；不会去重
+    pub fn chunk(&self, max: usize) -> Vec<AssetPricesRequest> {
+        self.asset_ids
+            .chunks(max.max(1))
+            .map(|asset_ids| AssetPricesRequest {
+                currency: self.currency.clone(),
+                asset_ids: asset_ids.to_vec(),
+            })
+            .collect()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[typeshare(swift = "Sendable")]
 #[serde(rename_all = "camelCase")]
@@ -65,6 +96,69 @@ pub struct Charts {
     pub total_volumes: Vec<ChartValue>,
 }
 
+impl Charts {
+    /// 使用 Largest-Triangle-Three-Buckets 将每条序列降采样到不超过 `max_points` 个点，首尾点保持不变
+    pub fn downsample(&self, max_points: usize) -> Charts {
+        Charts {
+            price: self.price,
+            market: self.market.clone(),
+            prices: downsample_values(&self.prices, max_points),
+            market_caps: downsample_values(&self.market_caps, max_points),
+            total_volumes: downsample_values(&self.total_volumes, max_points),
+        }
+    }
+}
+
+fn downsample_values(values: &[ChartValue], max_points: usize) -> Vec<ChartValue> {
+    let len = values.len();
+    if len <= max_points {
+        return values.to_vec();
+    }
+    if max_points < 3 {
+        let endpoints = [values[0].clone(), values[len - 1].clone()];
+        return endpoints.into_iter().rev().take(max_points).rev().collect();
+    }
+
+    let bucket_size = (len - 2) as f64 / (max_points - 2) as f64;
+    let point = |value: &ChartValue| (value.timestamp as f64, value.value as f64);
+
+    let mut sampled = Vec::with_capacity(max_points);
+    sampled.push(values[0].clone());
+    let mut previous = 0;
+
+    for bucket in 0..max_points - 2 {
+        // 下一个桶的平均点作为三角形的第三个顶点
+        let next_start = ((bucket + 1) as f64 * bucket_size) as usize + 1;
+        let next_end = (((bucket + 2) as f64 * bucket_size) as usize + 1).min(len);
+        let next = &values[next_start..next_end];
+        let (avg_x, avg_y) = next
+            .iter()
+            .map(point)
+            .fold((0.0, 0.0), |(sum_x, sum_y), (x, y)| (sum_x + x, sum_y + y));
+        let (avg_x, avg_y) = (avg_x / next.len() as f64, avg_y / next.len() as f64);
+
+        let start = (bucket as f64 * bucket_size) as usize + 1;
+        let end = next_start;
+        let (prev_x, prev_y) = point(&values[previous]);
+
+        let selected = (start..end)
+            .max_by(|&a, &b| {
+                let area = |index: usize| {
+                    let (x, y) = point(&values[index]);
+                    ((prev_x - avg_x) * (y - prev_y) - (prev_x - x) * (avg_y - prev_y)).abs()
+                };
+                area(a).total_cmp(&area(b))
+            })
+            .unwrap_or(start);
+
+        sampled.push(values[selected].clone());
+        previous = selected;
+    }
+
+    sampled.push(values[len - 1].clone());
+    sampled
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[typeshare(swift = "Sendable")]
 #[serde(rename_all = "camelCase")]
@@ -115,3 +209,92 @@ impl ChartPeriod {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Chain;
+
+    fn asset_price(price: f64) -> AssetPrice {
+        AssetPrice::new(AssetId::from_chain(Chain::Ethereum), price, 0.0, DateTime::default())
+    }
+
+    fn charts(len: usize) -> Charts {
+        let values: Vec<ChartValue> = (0..len)
+            .map(|i| ChartValue {
+                timestamp: 1_700_000_000 + i as i32 * 60,
+                value: ((i as f32) / 10.0).sin() * 100.0 + 1_000.0,
+            })
+            .collect();
+        Charts {
+            price: None,
+            market: None,
+            prices: values.clone(),
+            market_caps: values,
+            total_volumes: vec![],
+        }
+    }
+
+    #[test]
+    fn test_charts_downsample() {
+        let charts = charts(5_000);
+        let downsampled = charts.downsample(200);
+
+        assert_eq!(downsampled.prices.len(), 200);
+        assert_eq!(downsampled.market_caps.len(), 200);
+        assert!(downsampled.total_volumes.is_empty());
+        assert_eq!(downsampled.prices.first(), charts.prices.first());
+        assert_eq!(downsampled.prices.last(), charts.prices.last());
+        assert!(downsampled.prices.windows(2).all(|pair| pair[0].timestamp < pair[1].timestamp));
+    }
+
+    #[test]
+    fn test_charts_downsample_small() {
+        let charts = charts(10);
+        assert_eq!(charts.downsample(100).prices, charts.prices);
+
+        let downsampled = charts.downsample(2);
+        assert_eq!(downsampled.prices, vec![charts.prices[0].clone(), charts.prices[9].clone()]);
+        assert_eq!(charts.downsample(1).prices, vec![charts.prices[9].clone()]);
+        assert!(charts.downsample(0).prices.is_empty());
+    }
+
+    #[test]
+    fn test_change_percent() {
+        assert_eq!(asset_price(110.0).change_percent(100.0), 10.0);
+        assert_eq!(asset_price(75.0).change_percent(100.0), -25.0);
+        assert_eq!(asset_price(100.0).change_percent(100.0), 0.0);
+        assert_eq!(asset_price(100.0).change_percent(0.0), 0.0);
+    }
+
+    fn prices_request(asset_ids: Vec<AssetId>) -> AssetPricesRequest {
+        AssetPricesRequest {
+            currency: Some("USD".to_string()),
+            asset_ids,
+        }
+    }
+
+    #[test]
+    fn test_prices_request_deduplicated() {
+        let eth = AssetId::from_chain(Chain::Ethereum);
+        let sol = AssetId::from_chain(Chain::Solana);
+        let request = prices_request(vec![eth.clone(), sol.clone(), eth.clone(), sol.clone()]);
+
+        let deduplicated = request.deduplicated();
+        assert_eq!(deduplicated.asset_ids, vec![eth, sol]);
+        assert_eq!(deduplicated.currency.as_deref(), Some("USD"));
+    }
+
+    #[test]
+    fn test_prices_request_chunk() {
+        let request = prices_request(vec![AssetId::from_chain(Chain::Ethereum); 5]);
+
+        let chunks = request.chunk(2);
+        assert_eq!(chunks.iter().map(|chunk| chunk.asset_ids.len()).collect::<Vec<_>>(), vec![2, 2, 1]);
+        assert!(chunks.iter().all(|chunk| chunk.currency.as_deref() == Some("USD")));
+
+        assert_eq!(request.chunk(5).len(), 1);
+        assert_eq!(request.chunk(0).len(), 5);
+        assert!(prices_request(vec![]).chunk(2).is_empty());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::Chain;
 use serde::{Deserialize, Serialize};
 use strum::EnumIter;
 use typeshare::typeshare;
@@ -29,6 +30,12 @@ impl AssetScore {
     pub fn rank_type(&self) -> AssetRank {
         AssetRank::from_rank(self.rank)
     }
+
+    /// 结合链排名的综合排序分，资产评分的权重是链排名的两倍，
+    /// 评分跨越一个档位（如 Low → Medium）足以抵消链之间的排名差距
+    pub fn combined_rank(&self, chain: Chain) -> i32 {
+        self.rank.saturating_mul(2).saturating_add(chain.rank())
+    }
 }
 
 impl Default for AssetScore {
@@ -78,3 +85,16 @@ impl AssetRank {
         AssetRank::iter().find(|variant| rank >= variant.threshold()).unwrap_or(AssetRank::Unknown)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_combined_rank() {
+        assert_eq!(AssetScore::new(100).combined_rank(Chain::Arbitrum), 230);
+        assert_eq!(AssetScore::new(15).combined_rank(Chain::Ethereum), 110);
+        // 同分时按链排名
+        assert!(AssetScore::new(50).combined_rank(Chain::Ethereum) > AssetScore::new(50).combined_rank(Chain::Polygon));
+    }
+}
@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 use strum::{AsRefStr, EnumIter, EnumString, IntoEnumIterator};
 use typeshare::typeshare;
 
+use crate::Chain;
+
 #[derive(Debug, Clone, Serialize, Deserialize, AsRefStr, EnumString, EnumIter, PartialEq)]
 #[typeshare(swift = "Equatable, CaseIterable, Sendable")]
 #[serde(rename_all = "UPPERCASE")]
@@ -26,6 +28,32 @@ impl AssetType {
     pub fn all() -> Vec<Self> {
         Self::iter().collect::<Vec<_>>()
     }
+
+    /// 链上代币类型（不含原生币、永续合约和现货）
+    pub fn is_token(&self) -> bool {
+        !matches!(self, Self::NATIVE | Self::PERPETUAL | Self::SPOT)
+    }
+
+    pub fn is_native(&self) -> bool {
+        matches!(self, Self::NATIVE)
+    }
+
+    /// 代币标准名称，如 `ERC20`、`SPL`；非代币或无统一标准时返回 `None`
+    pub fn standard(&self) -> Option<&str> {
+        match self {
+            Self::NATIVE | Self::PERPETUAL | Self::SPOT | Self::TOKEN => None,
+            _ => Some(self.as_ref()),
+        }
+    }
+
+    /// 指定链上代币的默认类型
+    pub fn for_chain_token(chain: Chain) -> AssetType {
+        match chain {
+            Chain::Ethereum | Chain::Arbitrum | Chain::Polygon => Self::ERC20,
+            Chain::SmartChain => Self::BEP20,
+            Chain::Solana => Self::SPL,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
@@ -35,3 +63,32 @@ pub enum AssetSubtype {
     NATIVE,
     TOKEN,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classification() {
+        assert!(AssetType::NATIVE.is_native());
+        assert!(!AssetType::NATIVE.is_token());
+        assert_eq!(AssetType::NATIVE.standard(), None);
+
+        assert!(AssetType::ERC20.is_token());
+        assert!(!AssetType::ERC20.is_native());
+        assert_eq!(AssetType::ERC20.standard(), Some("ERC20"));
+        assert_eq!(AssetType::SPL.standard(), Some("SPL"));
+
+        assert!(!AssetType::PERPETUAL.is_token());
+        assert_eq!(AssetType::TOKEN.standard(), None);
+    }
+
+    #[test]
+    fn test_for_chain_token() {
+        assert_eq!(AssetType::for_chain_token(Chain::Ethereum), AssetType::ERC20);
+        assert_eq!(AssetType::for_chain_token(Chain::Arbitrum), AssetType::ERC20);
+        assert_eq!(AssetType::for_chain_token(Chain::Polygon), AssetType::ERC20);
+        assert_eq!(AssetType::for_chain_token(Chain::SmartChain), AssetType::BEP20);
+        assert_eq!(AssetType::for_chain_token(Chain::Solana), AssetType::SPL);
+    }
+}
@@ -1,7 +1,7 @@
 //! 区块链网络枚举和实现
 //!
 //! 定义了支持的区块链网络类型及其相关属性（网络 ID、区块时间等）。
-use crate::{AssetId, StakeChain};
+use crate::{AssetId, EVMChain, StakeChain};
 use serde::{Deserialize, Serialize};
 use std::{fmt, str::FromStr};
 use strum::IntoEnumIterator;
@@ -124,6 +124,62 @@ impl Chain {
         }
     }
 
+    /// 构建 BIP-44 派生路径
+    ///
+    /// EVM 链为 `m/44'/{slip44}'/{account}'/0/{index}`；
+    /// Solana 按 SLIP-0010 只使用硬化路径 `m/44'/501'/{account}'/0'`，忽略 `index`。
+    ///
+    /// # 示例
+    /// ```
+    /// use primitives::Chain;
+    ///
+    /// assert_eq!(Chain::Ethereum.derivation_path(0, 0), "m/44'/60'/0'/0/0");
+    /// assert_eq!(Chain::Solana.derivation_path(0, 0), "m/44'/501'/0'/0'");
+    /// ```
+    pub fn derivation_path(&self, account: u32, index: u32) -> String {
+        let slip44 = self.as_slip44();
+        match self {
+            Self::Ethereum | Self::SmartChain | Self::Arbitrum | Self::Polygon => format!("m/44'/{slip44}'/{account}'/0/{index}"),
+            Self::Solana => format!("m/44'/{slip44}'/{account}'/0'"),
+        }
+    }
+
+    /// 获取链在 CoinGecko 中的平台 ID，用于按合约地址查询代币价格
+    ///
+    /// # 示例
+    /// ```
+    /// use primitives::Chain;
+    ///
+    /// assert_eq!(Chain::SmartChain.coingecko_id(), "binance-smart-chain");
+    /// ```
+    pub fn coingecko_id(&self) -> &str {
+        match self {
+            Self::Ethereum => "ethereum",
+            Self::SmartChain => "binance-smart-chain",
+            Self::Polygon => "polygon-pos",
+            Self::Arbitrum => "arbitrum-one",
+            Self::Solana => "solana",
+        }
+    }
+
+    /// 获取链原生币在 CoinGecko 中的币种 ID，Arbitrum 的原生币为 ETH
+    ///
+    /// # 示例
+    /// ```
+    /// use primitives::Chain;
+    ///
+    /// assert_eq!(Chain::Polygon.coingecko_native_id(), "matic-network");
+    /// assert_eq!(Chain::Arbitrum.coingecko_native_id(), "ethereum");
+    /// ```
+    pub fn coingecko_native_id(&self) -> &str {
+        match self {
+            Self::Ethereum | Self::Arbitrum => "ethereum",
+            Self::SmartChain => "binancecoin",
+            Self::Polygon => "matic-network",
+            Self::Solana => "solana",
+        }
+    }
+
     /// 获取链的平均区块时间（毫秒）
     ///
     /// # 返回值
@@ -134,7 +190,7 @@ impl Chain {
     /// use primitives::Chain;
     ///
     /// assert_eq!(Chain::Ethereum.block_time(), 12_000); // 12 秒
-    /// assert_eq!(Chain::SmartChain.block_time(), 3_000); // 3 秒
+    /// assert_eq!(Chain::SmartChain.block_time(), 1_000); // 1 秒
     /// ```
     pub fn block_time(&self) -> u32 {
         match self {
@@ -176,7 +232,7 @@ impl Chain {
     /// use primitives::Chain;
     ///
     /// let chains = Chain::all();
-    /// assert_eq!(chains.len(), 4);
+    /// assert_eq!(chains.len(), 5);
     /// ```
     pub fn all() -> Vec<Self> {
         Self::iter().collect::<Vec<_>>()
@@ -201,6 +257,40 @@ impl Chain {
             Self::Solana => false,
         }
     }
+
+    /// 是否为 Layer 2 链
+    ///
+    /// # 返回值
+    /// EVM 链按 `EVMChain::is_ethereum_layer2` 判断，Solana 始终返回 `false`
+    ///
+    /// # 示例
+    /// ```
+    /// use primitives::Chain;
+    ///
+    /// assert!(Chain::Arbitrum.is_layer2());
+    /// assert!(!Chain::Ethereum.is_layer2());
+    /// ```
+    pub fn is_layer2(&self) -> bool {
+        EVMChain::from_chain(*self).is_some_and(|chain| chain.is_ethereum_layer2())
+    }
+
+    /// 获取 Layer 2 链的结算链
+    ///
+    /// # 返回值
+    /// - `Some(Chain)` - Layer 2 链对应的结算链
+    /// - `None` - 不是 Layer 2 链
+    ///
+    /// # 示例
+    /// ```
+    /// use primitives::Chain;
+    ///
+    /// assert_eq!(Chain::Arbitrum.settlement_chain(), Some(Chain::Ethereum));
+    /// assert_eq!(Chain::Polygon.settlement_chain(), None);
+    /// ```
+    pub fn settlement_chain(&self) -> Option<Chain> {
+        // 目前支持的 Layer 2 都结算到以太坊主网
+        self.is_layer2().then_some(Self::Ethereum)
+    }
 }
 
 #[cfg(test)]
@@ -234,29 +324,58 @@ mod tests {
         assert_eq!(Chain::Polygon.as_slip44(), 60);
     }
 
+    #[test]
+    fn test_chain_derivation_path() {
+        assert_eq!(Chain::Ethereum.derivation_path(0, 0), "m/44'/60'/0'/0/0");
+        assert_eq!(Chain::Ethereum.derivation_path(1, 5), "m/44'/60'/1'/0/5");
+        assert_eq!(Chain::Polygon.derivation_path(0, 3), "m/44'/60'/0'/0/3");
+        assert_eq!(Chain::Solana.derivation_path(0, 0), "m/44'/501'/0'/0'");
+        assert_eq!(Chain::Solana.derivation_path(2, 7), "m/44'/501'/2'/0'");
+    }
+
+    #[test]
+    fn test_chain_coingecko_id() {
+        assert_eq!(Chain::Ethereum.coingecko_id(), "ethereum");
+        assert_eq!(Chain::SmartChain.coingecko_id(), "binance-smart-chain");
+        assert_eq!(Chain::Arbitrum.coingecko_id(), "arbitrum-one");
+        assert_eq!(Chain::Polygon.coingecko_id(), "polygon-pos");
+        assert_eq!(Chain::Solana.coingecko_id(), "solana");
+    }
+
+    #[test]
+    fn test_chain_coingecko_native_id() {
+        assert_eq!(Chain::Ethereum.coingecko_native_id(), "ethereum");
+        assert_eq!(Chain::SmartChain.coingecko_native_id(), "binancecoin");
+        assert_eq!(Chain::Arbitrum.coingecko_native_id(), "ethereum");
+        assert_eq!(Chain::Polygon.coingecko_native_id(), "matic-network");
+        assert_eq!(Chain::Solana.coingecko_native_id(), "solana");
+    }
+
     #[test]
     fn test_chain_block_time() {
         assert_eq!(Chain::Ethereum.block_time(), 12_000);
-        assert_eq!(Chain::SmartChain.block_time(), 3_000);
-        assert_eq!(Chain::Arbitrum.block_time(), 250);
-        assert_eq!(Chain::Polygon.block_time(), 2_000);
+        assert_eq!(Chain::SmartChain.block_time(), 1_000);
+        assert_eq!(Chain::Arbitrum.block_time(), 1_000);
+        assert_eq!(Chain::Polygon.block_time(), 3_000);
+        assert_eq!(Chain::Solana.block_time(), 500);
     }
 
     #[test]
     fn test_chain_rank() {
-        assert_eq!(Chain::Ethereum.rank(), 100);
-        assert_eq!(Chain::SmartChain.rank(), 80);
+        assert_eq!(Chain::Ethereum.rank(), 80);
+        assert_eq!(Chain::SmartChain.rank(), 70);
         assert!(Chain::Ethereum.rank() > Chain::Arbitrum.rank());
     }
 
     #[test]
     fn test_chain_all() {
         let chains = Chain::all();
-        assert_eq!(chains.len(), 4);
+        assert_eq!(chains.len(), 5);
         assert!(chains.contains(&Chain::Ethereum));
         assert!(chains.contains(&Chain::SmartChain));
         assert!(chains.contains(&Chain::Arbitrum));
         assert!(chains.contains(&Chain::Polygon));
+        assert!(chains.contains(&Chain::Solana));
     }
 
     #[test]
@@ -267,6 +386,24 @@ mod tests {
         assert!(Chain::Polygon.is_evm());
     }
 
+    #[test]
+    fn test_chain_is_layer2() {
+        assert!(Chain::Arbitrum.is_layer2());
+        assert!(!Chain::Ethereum.is_layer2());
+        assert!(!Chain::SmartChain.is_layer2());
+        assert!(!Chain::Polygon.is_layer2());
+        assert!(!Chain::Solana.is_layer2());
+    }
+
+    #[test]
+    fn test_chain_settlement_chain() {
+        assert_eq!(Chain::Arbitrum.settlement_chain(), Some(Chain::Ethereum));
+        assert_eq!(Chain::Ethereum.settlement_chain(), None);
+        assert_eq!(Chain::SmartChain.settlement_chain(), None);
+        assert_eq!(Chain::Polygon.settlement_chain(), None);
+        assert_eq!(Chain::Solana.settlement_chain(), None);
+    }
+
     #[test]
     fn test_chain_display() {
         assert_eq!(Chain::Ethereum.to_string(), "ethereum");
@@ -296,7 +433,7 @@ mod tests {
 
     #[test]
     fn test_chain_ordering() {
-        let mut chains = vec![
+        let mut chains = [
             Chain::Polygon,
             Chain::Ethereum,
             Chain::Arbitrum,
@@ -1,8 +1,12 @@
 use std::fmt;
 
 use serde::{Deserialize, Serialize};
+use tiny_keccak::{Hasher, Keccak};
 
-use crate::Chain;
+use crate::{Chain, SignerError};
+
+const EVM_ADDRESS_LENGTH: usize = 20;
+const SOLANA_ADDRESS_LENGTH: usize = 32;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ChainAddress {
@@ -14,6 +18,43 @@ impl ChainAddress {
     pub fn new(chain: Chain, address: String) -> Self {
         Self { chain, address }
     }
+
+    /// 校验地址格式：EVM 为 20 字节十六进制（大小写混合时校验 EIP-55），Solana 为 32 字节 base58
+    pub fn is_valid(&self) -> bool {
+        match self.chain {
+            Chain::Solana => is_valid_solana_address(&self.address),
+            chain if chain.is_evm() => is_valid_evm_address(&self.address),
+            _ => false,
+        }
+    }
+
+    /// 规范化地址：EVM 返回 EIP-55 校验和格式，无效地址原样返回
+    pub fn normalized(&self) -> String {
+        if !self.is_valid() {
+            return self.address.clone();
+        }
+        match self.chain {
+            chain if chain.is_evm() => evm_checksum(&self.address[2..]),
+            _ => self.address.clone(),
+        }
+    }
+
+    /// 比较地址是否相同：EVM 忽略大小写（校验和），Solana 精确比较
+    pub fn equals(&self, other: &str) -> bool {
+        if self.chain.is_evm() {
+            self.address.eq_ignore_ascii_case(other)
+        } else {
+            self.address == other
+        }
+    }
+
+    /// EIP-55 校验和格式的地址，Solana 地址原样返回；地址无效时返回错误
+    pub fn to_checksum(&self) -> Result<String, SignerError> {
+        if !self.is_valid() {
+            return Err(SignerError::InvalidInput(format!("Invalid {} address: {}", self.chain, self.address)));
+        }
+        Ok(self.normalized())
+    }
 }
 
 impl fmt::Display for ChainAddress {
@@ -21,3 +62,112 @@ impl fmt::Display for ChainAddress {
         write!(f, "{}:{}", self.chain, self.address)
     }
 }
+
+fn is_valid_evm_address(address: &str) -> bool {
+    let Some(hex_part) = address.strip_prefix("0x") else {
+        return false;
+    };
+    if hex_part.len() != EVM_ADDRESS_LENGTH * 2 || !hex_part.chars().all(|c| c.is_ascii_hexdigit()) {
+        return false;
+    }
+    let is_mixed_case = hex_part.chars().any(|c| c.is_ascii_lowercase()) && hex_part.chars().any(|c| c.is_ascii_uppercase());
+    !is_mixed_case || evm_checksum(hex_part) == address
+}
+
+fn is_valid_solana_address(address: &str) -> bool {
+    bs58::decode(address).into_vec().is_ok_and(|bytes| bytes.len() == SOLANA_ADDRESS_LENGTH)
+}
+
+fn evm_checksum(hex_part: &str) -> String {
+    let lowercase = hex_part.to_ascii_lowercase();
+    let mut hash = [0u8; 32];
+    let mut keccak = Keccak::v256();
+    keccak.update(lowercase.as_bytes());
+    keccak.finalize(&mut hash);
+    let hash = hex::encode(hash);
+
+    let checksummed: String = lowercase
+        .chars()
+        .zip(hash.chars())
+        .map(|(c, h)| if h.to_digit(16).unwrap_or(0) >= 8 { c.to_ascii_uppercase() } else { c })
+        .collect();
+    format!("0x{}", checksummed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_evm_lowercase() {
+        let address = ChainAddress::new(Chain::Ethereum, "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed".to_string());
+        assert!(address.is_valid());
+        assert_eq!(address.normalized(), "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+    }
+
+    #[test]
+    fn test_is_valid_evm_checksum() {
+        let valid = ChainAddress::new(Chain::SmartChain, "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string());
+        assert!(valid.is_valid());
+
+        let bad_checksum = ChainAddress::new(Chain::Ethereum, "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD".to_string());
+        assert!(!bad_checksum.is_valid());
+        assert_eq!(bad_checksum.normalized(), bad_checksum.address);
+
+        assert!(!ChainAddress::new(Chain::Ethereum, "0x1234".to_string()).is_valid());
+        assert!(!ChainAddress::new(Chain::Ethereum, "5aaeb6053f3e94c9b9a09f33669435e7ef1beaed".to_string()).is_valid());
+    }
+
+    #[test]
+    fn test_is_valid_solana() {
+        let address = ChainAddress::new(Chain::Solana, "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM".to_string());
+        assert!(address.is_valid());
+        assert_eq!(address.normalized(), address.address);
+
+        assert!(!ChainAddress::new(Chain::Solana, "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed".to_string()).is_valid());
+        assert!(!ChainAddress::new(Chain::Solana, "1111".to_string()).is_valid());
+    }
+
+    #[test]
+    fn test_to_checksum_evm() {
+        // EIP-55 规范中的测试向量
+        for expected in [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ] {
+            let address = ChainAddress::new(Chain::Ethereum, expected.to_lowercase());
+            assert_eq!(address.to_checksum().unwrap(), expected);
+        }
+
+        let upper = ChainAddress::new(Chain::Arbitrum, "0xDAC17F958D2EE523A2206206994597C13D831EC7".to_string());
+        assert_eq!(upper.to_checksum().unwrap(), "0xdAC17F958D2ee523a2206206994597C13D831ec7");
+    }
+
+    #[test]
+    fn test_to_checksum_solana_and_invalid() {
+        let solana = ChainAddress::new(Chain::Solana, "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM".to_string());
+        assert_eq!(solana.to_checksum().unwrap(), solana.address);
+
+        let error = ChainAddress::new(Chain::Ethereum, "0x1234".to_string()).to_checksum().unwrap_err();
+        assert_eq!(error.to_string(), "Invalid input: Invalid ethereum address: 0x1234");
+    }
+
+    #[test]
+    fn test_equals_evm_mixed_case() {
+        let address = ChainAddress::new(Chain::Ethereum, "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string());
+
+        assert!(address.equals("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"));
+        assert!(address.equals("0x5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED"));
+        assert!(!address.equals("0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359"));
+    }
+
+    #[test]
+    fn test_equals_solana_exact() {
+        let address = ChainAddress::new(Chain::Solana, "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM".to_string());
+
+        assert!(address.equals("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"));
+        assert!(!address.equals("9wzdxwbbmkg8ztbnmquxvqrayrzzdsgydlvl9zytawwm"));
+    }
+}
@@ -65,6 +65,26 @@ impl EVMChain {
         }
     }
 
+    // https://www.multicall3.com/deployments
+    pub fn multicall3_address(&self) -> Option<&str> {
+        match self {
+            Self::Ethereum | Self::SmartChain | Self::Polygon | Self::Arbitrum => Some("0xcA11bde05977b3631167028862bE2a173976CA11"),
+        }
+    }
+
+    pub fn chain_id(&self) -> u64 {
+        match self {
+            Self::Ethereum => 1,
+            Self::SmartChain => 56,
+            Self::Polygon => 137,
+            Self::Arbitrum => 42161,
+        }
+    }
+
+    pub fn from_chain_id(chain_id: u64) -> Option<Self> {
+        Self::iter().find(|chain| chain.chain_id() == chain_id)
+    }
+
     pub fn from_chain(chain: Chain) -> Option<Self> {
         EVMChain::from_str(chain.as_ref()).ok()
     }
@@ -83,4 +103,24 @@ mod tests {
         assert_eq!(EVMChain::from_chain(Chain::Ethereum), Some(EVMChain::Ethereum));
         assert_eq!(EVMChain::from_chain(Chain::Solana), None);
     }
+
+    #[test]
+    fn test_multicall3_address() {
+        for chain in EVMChain::all() {
+            assert_eq!(chain.multicall3_address(), Some("0xcA11bde05977b3631167028862bE2a173976CA11"));
+        }
+    }
+
+    #[test]
+    fn test_chain_id() {
+        for chain in EVMChain::all() {
+            assert_eq!(chain.chain_id().to_string(), chain.to_chain().network_id());
+            assert_eq!(EVMChain::from_chain_id(chain.chain_id()), Some(chain));
+        }
+        assert_eq!(EVMChain::Ethereum.chain_id(), 1);
+        assert_eq!(EVMChain::SmartChain.chain_id(), 56);
+        assert_eq!(EVMChain::Polygon.chain_id(), 137);
+        assert_eq!(EVMChain::Arbitrum.chain_id(), 42161);
+        assert_eq!(EVMChain::from_chain_id(10), None);
+    }
 }
@@ -1,6 +1,7 @@
 use std::str::FromStr;
 
 use crate::Chain;
+use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
 use strum::{AsRefStr, EnumIter, EnumString};
 use typeshare::typeshare;
@@ -29,6 +30,26 @@ impl StakeChain {
         Chain::from_str(self.as_ref()).unwrap()
     }
 
+    /// Get the stake chain for a chain, `None` if the chain has no staking
+    pub fn from_chain(chain: Chain) -> Option<Self> {
+        Self::from_str(chain.as_ref()).ok()
+    }
+
+    /// Check if the chain supports staking
+    pub fn supports(chain: Chain) -> bool {
+        Self::from_chain(chain).is_some()
+    }
+
+    /// Get the minimum stake amount for a chain
+    pub fn min_stake_amount(chain: Chain) -> Option<BigUint> {
+        Self::from_chain(chain).map(|x| BigUint::from(x.get_min_stake_amount()))
+    }
+
+    /// Get the unbonding period in seconds for a chain
+    pub fn unbonding_period_secs(chain: Chain) -> Option<u64> {
+        Self::from_chain(chain).map(|x| x.get_lock_time())
+    }
+
     /// Get the lock time in seconds
     pub fn get_lock_time(&self) -> u64 {
         match self {
@@ -106,3 +127,30 @@ impl StakeChain {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_staking_chain() {
+        assert!(StakeChain::supports(Chain::Ethereum));
+        assert_eq!(StakeChain::min_stake_amount(Chain::Ethereum), Some(BigUint::from(100_000_000_000_000_000u64)));
+        assert_eq!(StakeChain::unbonding_period_secs(Chain::Ethereum), Some(1_209_600));
+
+        assert!(StakeChain::supports(Chain::SmartChain));
+        assert_eq!(StakeChain::min_stake_amount(Chain::SmartChain), Some(BigUint::from(1_000_000_000_000_000_000u64)));
+        assert_eq!(StakeChain::unbonding_period_secs(Chain::SmartChain), Some(604800));
+
+        assert!(StakeChain::supports(Chain::Solana));
+        assert_eq!(StakeChain::min_stake_amount(Chain::Solana), Some(BigUint::from(10_000_000u64)));
+        assert_eq!(StakeChain::unbonding_period_secs(Chain::Solana), Some(259200));
+    }
+
+    #[test]
+    fn test_non_staking_chain() {
+        assert!(!StakeChain::supports(Chain::Polygon));
+        assert_eq!(StakeChain::min_stake_amount(Chain::Polygon), None);
+        assert_eq!(StakeChain::unbonding_period_secs(Chain::Arbitrum), None);
+    }
+}
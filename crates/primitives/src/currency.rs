@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+use strum::{AsRefStr, EnumIter, EnumString, IntoEnumIterator};
+use typeshare::typeshare;
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, EnumIter, AsRefStr, EnumString, PartialEq, Eq, Hash)]
+#[typeshare(swift = "Equatable, CaseIterable, Sendable")]
+pub enum Currency {
+    USD,
+    EUR,
+    GBP,
+    JPY,
+    CNY,
+    HKD,
+    KRW,
+    SGD,
+    AUD,
+    CAD,
+    CHF,
+    INR,
+    RUB,
+    BRL,
+    TRY,
+    UAH,
+}
+
+impl Currency {
+    pub fn all() -> Vec<Self> {
+        Self::iter().collect()
+    }
+}
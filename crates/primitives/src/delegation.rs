@@ -47,6 +47,32 @@ pub struct DelegationValidator {
     pub apr: f64,
 }
 
+pub trait DelegationValidatorVecExt {
+    fn active_only(&self) -> Vec<DelegationValidator>;
+    fn sorted_by_apr_desc(&self) -> Vec<DelegationValidator>;
+    fn best(&self) -> Option<&DelegationValidator>;
+}
+
+impl DelegationValidatorVecExt for Vec<DelegationValidator> {
+    fn active_only(&self) -> Vec<DelegationValidator> {
+        self.iter().filter(|x| x.is_active).cloned().collect()
+    }
+
+    /// 按 APR 从高到低排序，APR 相同时保持原有顺序
+    fn sorted_by_apr_desc(&self) -> Vec<DelegationValidator> {
+        let mut validators = self.clone();
+        validators.sort_by(|a, b| b.apr.total_cmp(&a.apr));
+        validators
+    }
+
+    /// 活跃验证者中 APR 最高的一个，APR 相同时取靠前的
+    fn best(&self) -> Option<&DelegationValidator> {
+        self.iter()
+            .filter(|x| x.is_active)
+            .reduce(|best, x| if x.apr > best.apr { x } else { best })
+    }
+}
+
 #[derive(Copy, Clone, Debug, Serialize, Deserialize, Display, AsRefStr, EnumString, PartialEq)]
 #[typeshare(swift = "Equatable, CaseIterable, Sendable")]
 #[serde(rename_all = "lowercase")]
@@ -59,3 +85,45 @@ pub enum DelegationState {
     Deactivating,
     AwaitingWithdrawal,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validator(id: &str, is_active: bool, apr: f64) -> DelegationValidator {
+        DelegationValidator {
+            chain: Chain::Solana,
+            id: id.to_string(),
+            name: id.to_string(),
+            is_active,
+            commission: 0.0,
+            apr,
+        }
+    }
+
+    fn ids(validators: &[DelegationValidator]) -> Vec<&str> {
+        validators.iter().map(|x| x.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_active_only() {
+        let validators = vec![validator("a", true, 5.0), validator("b", false, 9.0), validator("c", true, 7.0)];
+        assert_eq!(ids(&validators.active_only()), vec!["a", "c"]);
+    }
+
+    #[test]
+    fn test_sorted_by_apr_desc_with_ties() {
+        let validators = vec![validator("a", true, 5.0), validator("b", true, 7.0), validator("c", false, 7.0), validator("d", true, 6.0)];
+        assert_eq!(ids(&validators.sorted_by_apr_desc()), vec!["b", "c", "d", "a"]);
+    }
+
+    #[test]
+    fn test_best() {
+        let validators = vec![validator("a", true, 5.0), validator("b", false, 9.0), validator("c", true, 7.0), validator("d", true, 7.0)];
+        assert_eq!(validators.best().map(|x| x.id.as_str()), Some("c"));
+
+        let inactive = vec![validator("a", false, 5.0), validator("b", false, 9.0)];
+        assert!(inactive.best().is_none());
+        assert!(Vec::<DelegationValidator>::new().best().is_none());
+    }
+}
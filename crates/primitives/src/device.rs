@@ -1,7 +1,14 @@
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 use typeshare::typeshare;
 
-use crate::{PlatformStore, platform::Platform};
+use crate::{Currency, DEFAULT_FIAT_CURRENCY, PlatformStore, platform::Platform};
+
+/// APNs 设备 token 为十六进制字符串，当前为 32 字节（64 个字符），Apple 保留了变长的可能
+const APNS_TOKEN_LENGTH: std::ops::RangeInclusive<usize> = 64..=200;
+/// FCM 注册 token 长度范围
+const FCM_TOKEN_LENGTH: std::ops::RangeInclusive<usize> = 100..=4096;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[typeshare(swift = "Equatable, Sendable")]
@@ -23,10 +30,146 @@ pub struct Device {
 
 impl Device {
     pub fn can_receive_push_notification(&self) -> bool {
-        self.is_push_enabled && !self.token.is_empty()
+        self.is_push_enabled && !self.token.is_empty() && self.is_token_valid()
+    }
+
+    /// 按平台校验推送 token 格式：iOS 为 APNs 十六进制 token，Android 为 FCM token
+    pub fn is_token_valid(&self) -> bool {
+        let token = self.token.as_str();
+        match self.platform {
+            Platform::IOS => APNS_TOKEN_LENGTH.contains(&token.len()) && token.len().is_multiple_of(2) && token.chars().all(|c| c.is_ascii_hexdigit()),
+            Platform::Android => {
+                FCM_TOKEN_LENGTH.contains(&token.len()) && token.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, ':' | '_' | '-'))
+            }
+        }
     }
 
     pub fn can_receive_price_alerts(&self) -> bool {
         self.can_receive_push_notification() && self.is_price_alerts_enabled == Some(true)
     }
+
+    /// 设备的计价货币，不受支持时回退到 `DEFAULT_FIAT_CURRENCY`，等同于 `normalized_currency`
+    pub fn effective_currency(&self) -> String {
+        self.normalized_currency()
+    }
+
+    /// 忽略大小写和首尾空白后的计价货币（如 `usd` -> `USD`），不受支持时回退到 `DEFAULT_FIAT_CURRENCY`
+    pub fn normalized_currency(&self) -> String {
+        match Currency::from_str(&self.currency.trim().to_uppercase()) {
+            Ok(currency) => currency.as_ref().to_string(),
+            Err(_) => DEFAULT_FIAT_CURRENCY.to_string(),
+        }
+    }
+
+    /// BCP-47 格式的语言标签：`en_us` -> `en-US`，`zh_hant_tw` -> `zh-Hant-TW`
+    pub fn normalized_locale(&self) -> String {
+        self.locale
+            .trim()
+            .split(['_', '-'])
+            .filter(|x| !x.is_empty())
+            .enumerate()
+            .map(|(index, subtag)| match (index, subtag.len()) {
+                (0, _) => subtag.to_lowercase(),
+                (_, 2) => subtag.to_uppercase(),
+                (_, 4) => {
+                    let mut chars = subtag.chars();
+                    chars.next().map(|c| c.to_uppercase().collect::<String>()).unwrap_or_default() + &chars.as_str().to_lowercase()
+                }
+                _ => subtag.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn device(currency: &str) -> Device {
+        Device {
+            id: "device".to_string(),
+            platform: Platform::IOS,
+            os: None,
+            model: None,
+            platform_store: None,
+            token: String::new(),
+            locale: "en".to_string(),
+            version: "1.0.0".to_string(),
+            currency: currency.to_string(),
+            is_push_enabled: false,
+            is_price_alerts_enabled: None,
+            subscriptions_version: 0,
+        }
+    }
+
+    const APNS_TOKEN: &str = "740f4707bebcf74f9b7c25d48e3358945f6aa01da5ddb387462c7eaf61bb78ad";
+    const FCM_TOKEN: &str = "dQw4w9WgXcQ:APA91bHun4MxP5egoKMwt2KZFBaFUH-1RYqx_nmSG0dpaH3ZW6C1pNgQnHHmP9qsl_TRJZwjx9jvqVQy1VOrOOpO-PjXYNmEOzHMFfwo7n6_H-VV0lGkwV8j_iI7EeR5xWw2gKp3tRuN";
+
+    #[test]
+    fn test_is_token_valid_ios() {
+        let mut device = device("USD");
+        device.is_push_enabled = true;
+        device.token = APNS_TOKEN.to_string();
+        assert!(device.is_token_valid());
+        assert!(device.can_receive_push_notification());
+
+        device.token = String::new();
+        assert!(!device.is_token_valid());
+        assert!(!device.can_receive_push_notification());
+
+        device.token = "740f4707bebcf74f9b7c25d48e3358945f6aa01da5ddb387462c7eaf61bb78zz".to_string();
+        assert!(!device.is_token_valid());
+        device.token = APNS_TOKEN[..40].to_string();
+        assert!(!device.is_token_valid());
+        device.token = FCM_TOKEN.to_string();
+        assert!(!device.is_token_valid());
+    }
+
+    #[test]
+    fn test_is_token_valid_android() {
+        let mut device = device("USD");
+        device.platform = Platform::Android;
+        device.token = FCM_TOKEN.to_string();
+        assert!(device.is_token_valid());
+
+        device.token = APNS_TOKEN.to_string();
+        assert!(!device.is_token_valid());
+        device.token = format!("{} ", &FCM_TOKEN[..120]);
+        assert!(!device.is_token_valid());
+    }
+
+    #[test]
+    fn test_normalized_currency() {
+        assert_eq!(device("usd").normalized_currency(), "USD");
+        assert_eq!(device(" eur ").normalized_currency(), "EUR");
+        assert_eq!(device("xyz").normalized_currency(), DEFAULT_FIAT_CURRENCY);
+    }
+
+    #[test]
+    fn test_normalized_locale() {
+        let locale = |locale: &str| {
+            let mut device = device("USD");
+            device.locale = locale.to_string();
+            device.normalized_locale()
+        };
+        assert_eq!(locale("en_US"), "en-US");
+        assert_eq!(locale("en-us"), "en-US");
+        assert_eq!(locale("zh_hant_TW"), "zh-Hant-TW");
+        assert_eq!(locale("es_419"), "es-419");
+        assert_eq!(locale("EN"), "en");
+    }
+
+    #[test]
+    fn test_effective_currency_supported() {
+        assert_eq!(device("EUR").effective_currency(), "EUR");
+        assert_eq!(device("JPY").effective_currency(), "JPY");
+        assert_eq!(device("eur").effective_currency(), "EUR");
+    }
+
+    #[test]
+    fn test_effective_currency_unsupported() {
+        assert_eq!(device("XYZ").effective_currency(), DEFAULT_FIAT_CURRENCY);
+        assert_eq!(device("").effective_currency(), DEFAULT_FIAT_CURRENCY);
+    }
 }
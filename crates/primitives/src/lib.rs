@@ -6,13 +6,13 @@ mod chain;
 mod node_sync_status;
 
 pub use chain::Chain;
-pub use self::node_sync_status::{NodeStatusState, NodeSyncStatus};
+pub use self::node_sync_status::{NodeStatusState, NodeSyncStatus, STALE_BLOCK_TIME_MULTIPLIER};
 
 pub mod asset_balance;
 pub use self::asset_balance::{AssetBalance, Balance};
 
 pub mod asset_id;
-pub use self::asset_id::{AssetId, AssetIdVecExt};
+pub use self::asset_id::{AssetId, AssetIdError, AssetIdVecExt};
 
 pub mod asset_type;
 pub use self::asset_type::{AssetSubtype, AssetType};
@@ -36,7 +36,7 @@ pub mod transaction_state;
 pub use self::transaction_state::TransactionState;
 
 pub mod delegation;
-pub use self::delegation::{Delegation, DelegationBase, DelegationState, DelegationValidator};
+pub use self::delegation::{Delegation, DelegationBase, DelegationState, DelegationValidator, DelegationValidatorVecExt};
 
 pub mod price;
 pub use self::price::{Price, PriceFull};
@@ -45,7 +45,7 @@ pub mod validator;
 pub use self::validator::StakeValidator;
 
 pub mod scan;
-pub use self::scan::{AddressType, ScanAddress, ScanAddressTarget, ScanTransaction, ScanTransactionPayload};
+pub use self::scan::{AddressType, Denylist, RiskLevel, ScanAddress, ScanAddressTarget, ScanTransaction, ScanTransactionPayload};
 
 pub mod transaction_type;
 pub use self::transaction_type::TransactionType;
@@ -54,13 +54,16 @@ pub mod asset_price;
 pub use self::asset_price::{AssetMarket, AssetPrice, AssetPrices, AssetPricesRequest, ChartPeriod, ChartValue, Charts};
 
 pub mod price_alert;
-pub use self::price_alert::{DevicePriceAlert, PriceAlert, PriceAlertDirection, PriceAlertType, PriceAlerts};
+pub use self::price_alert::{DevicePriceAlert, PriceAlert, PriceAlertDirection, PriceAlertType, PriceAlerts, PriceAlertsExt};
 
 pub mod device;
 pub use self::device::Device;
 
+pub mod currency;
+pub use self::currency::Currency;
+
 pub mod asset;
-pub use self::asset::{Asset, AssetVecExt};
+pub use self::asset::{Asset, AssetBasicVecExt, AssetVecExt};
 
 pub mod asset_details;
 pub use self::asset_details::{AssetBasic, AssetFull, AssetLink, AssetMarketPrice, AssetPriceMetadata, AssetProperties};
@@ -74,7 +77,7 @@ pub use self::platform::{Platform, PlatformStore};
 pub mod perpetual;
 pub use self::perpetual::{
     AccountDataType, CancelOrderData, Perpetual, PerpetualBalance, PerpetualBasic, PerpetualConfirmData, PerpetualDirection, PerpetualModifyConfirmData,
-    PerpetualModifyPositionType, PerpetualPositionData, PerpetualPositionsSummary, PerpetualReduceData, PerpetualType, TPSLOrderData,
+    PerpetualError, PerpetualModifyPositionType, PerpetualPositionData, PerpetualPositionsSummary, PerpetualReduceData, PerpetualType, TPSLOrderData,
 };
 
 pub mod perpetual_provider;
@@ -89,6 +92,8 @@ pub use self::link_type::LinkType;
 pub mod chain_stake;
 pub use self::chain_stake::StakeChain;
 
+pub mod stablecoins;
+
 pub type UInt64 = u64;
 
 
@@ -27,6 +27,26 @@ impl LinkType {
     pub fn name(&self) -> String {
         self.as_ref().to_string()
     }
+
+    /// UI 使用的图标名称
+    pub fn icon_name(&self) -> &str {
+        match self {
+            Self::X => "x",
+            Self::Discord => "discord",
+            Self::Reddit => "reddit",
+            Self::Telegram => "telegram",
+            Self::GitHub => "github",
+            Self::YouTube => "youtube",
+            Self::Facebook => "facebook",
+            Self::Website => "globe",
+            Self::Coingecko => "coingecko",
+            Self::OpenSea => "opensea",
+            Self::Instagram => "instagram",
+            Self::MagicEden => "magiceden",
+            Self::CoinMarketCap => "coinmarketcap",
+            Self::TikTok => "tiktok",
+        }
+    }
 }
 
 impl LinkType {
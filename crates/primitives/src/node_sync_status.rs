@@ -1,6 +1,9 @@
-use crate::UInt64;
+use crate::{Chain, UInt64};
 use serde::{Deserialize, Serialize};
 
+/// 最新区块落后超过多少个出块间隔即视为节点停滞
+pub const STALE_BLOCK_TIME_MULTIPLIER: u64 = 5;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NodeSyncStatus {
@@ -21,6 +24,12 @@ impl NodeSyncStatus {
     pub fn in_sync() -> Self {
         Self::new(true, None, None)
     }
+
+    /// 判断节点是否停滞：距最新区块的时间超过 `STALE_BLOCK_TIME_MULTIPLIER` 个出块间隔
+    pub fn is_stale(&self, chain: Chain, now_ms: u64, last_block_ms: u64) -> bool {
+        let elapsed = now_ms.saturating_sub(last_block_ms);
+        elapsed > chain.block_time() as u64 * STALE_BLOCK_TIME_MULTIPLIER
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -60,3 +69,19 @@ impl NodeStatusState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_stale() {
+        let status = NodeSyncStatus::in_sync();
+        let now = 1_700_000_000_000;
+        let block_time = Chain::Ethereum.block_time() as u64;
+
+        assert!(status.is_stale(Chain::Ethereum, now, now - block_time * 10));
+        assert!(!status.is_stale(Chain::Ethereum, now, now - block_time));
+        assert!(!status.is_stale(Chain::Ethereum, now, now + block_time));
+    }
+}
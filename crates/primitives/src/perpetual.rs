@@ -1,5 +1,6 @@
 use crate::{Asset, AssetId, PerpetualPosition, PerpetualProvider, UInt64};
 use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt};
 use strum::{AsRefStr, EnumString};
 use typeshare::typeshare;
 
@@ -69,9 +70,55 @@ pub struct PerpetualData {
 pub struct PerpetualPositionsSummary {
     pub positions: Vec<PerpetualPosition>,
     pub balance: PerpetualBalance,
+    #[serde(default)]
+    pub total_notional: f64,
+    #[serde(default)]
+    pub unrealized_pnl: f64,
+    #[serde(default)]
+    pub margin_used: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl PerpetualPositionsSummary {
+    /// 按 `mark_prices`（以 `perpetual_id` 为键）汇总名义价值、未实现盈亏和已用保证金
+    ///
+    /// 缺少标记价格的仓位仍保留在 `positions` 中，但不计入汇总；`balance` 为零，由调用方填充。
+    pub fn from_positions(positions: &[PerpetualPosition], mark_prices: &HashMap<String, f64>) -> PerpetualPositionsSummary {
+        let mut summary = PerpetualPositionsSummary {
+            positions: positions.to_vec(),
+            balance: PerpetualBalance::default(),
+            total_notional: 0.0,
+            unrealized_pnl: 0.0,
+            margin_used: 0.0,
+        };
+
+        for position in positions {
+            let Some(&mark_price) = mark_prices.get(&position.perpetual_id) else {
+                continue;
+            };
+            let size = position.size.abs();
+            let notional = size * mark_price;
+            let unrealized_pnl = match (position.entry_price, &position.direction) {
+                (Some(entry_price), PerpetualDirection::Long) => (mark_price - entry_price) * size,
+                (Some(entry_price), PerpetualDirection::Short) => (entry_price - mark_price) * size,
+                (None, _) => position.pnl,
+            };
+            let margin = if position.margin_amount > 0.0 {
+                position.margin_amount
+            } else if position.leverage > 0 {
+                notional / position.leverage as f64
+            } else {
+                notional
+            };
+
+            summary.total_notional += notional;
+            summary.unrealized_pnl += unrealized_pnl;
+            summary.margin_used += margin;
+        }
+        summary
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[typeshare(swift = "Equatable, Sendable, Hashable")]
 pub struct PerpetualBalance {
     pub available: f64,
@@ -79,6 +126,21 @@ pub struct PerpetualBalance {
     pub withdrawable: f64,
 }
 
+impl PerpetualBalance {
+    /// 可用于开仓的余额：账户权益 `available` 减去已占用保证金 `reserved`，不小于 0
+    pub fn available_to_trade(&self) -> f64 {
+        (self.available - self.reserved).max(0.0)
+    }
+
+    /// 账户健康度，范围 0–1：1 表示未占用保证金，越接近 0 越接近强平；没有权益时为 0
+    pub fn health_ratio(&self) -> f64 {
+        if self.available <= 0.0 {
+            return 0.0;
+        }
+        (1.0 - self.reserved / self.available).clamp(0.0, 1.0)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[typeshare(swift = "Equatable, Sendable, Hashable")]
 #[serde(rename_all = "camelCase")]
@@ -104,6 +166,66 @@ pub struct PerpetualConfirmData {
     pub margin_amount: f64,
 }
 
+impl PerpetualConfirmData {
+    fn size_value(&self) -> f64 {
+        self.size.parse::<f64>().map(f64::abs).unwrap_or(0.0)
+    }
+
+    // 未指定保证金时按杠杆推算
+    fn margin(&self) -> f64 {
+        if self.margin_amount > 0.0 { self.margin_amount } else { self.required_margin() }
+    }
+
+    /// 开仓所需保证金：名义价值 / 杠杆
+    pub fn required_margin(&self) -> f64 {
+        if self.leverage == 0 {
+            return self.fiat_value;
+        }
+        self.fiat_value / self.leverage as f64
+    }
+
+    /// 校验杠杆是否在该合约支持的范围内，以及可用余额是否足够支付保证金
+    pub fn validate(&self, available_balance: f64, perpetual: &Perpetual) -> Result<(), PerpetualError> {
+        let min = perpetual.leverage.iter().copied().min().unwrap_or(1);
+        let max = perpetual.leverage.iter().copied().max().unwrap_or(1);
+        if self.leverage < min || self.leverage > max {
+            return Err(PerpetualError::InvalidLeverage {
+                leverage: self.leverage,
+                min,
+                max,
+            });
+        }
+
+        let required = self.required_margin();
+        if required > available_balance {
+            return Err(PerpetualError::InsufficientBalance {
+                required,
+                available: available_balance,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PerpetualError {
+    InvalidLeverage { leverage: u8, min: u8, max: u8 },
+    InsufficientBalance { required: f64, available: f64 },
+}
+
+impl fmt::Display for PerpetualError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PerpetualError::InvalidLeverage { leverage, min, max } => write!(f, "Leverage {}x is outside the allowed range {}x-{}x", leverage, min, max),
+            PerpetualError::InsufficientBalance { required, available } => {
+                write!(f, "Insufficient balance: required margin {} exceeds available {}", required, available)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PerpetualError {}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[typeshare(swift = "Equatable, Sendable, Hashable")]
 #[serde(rename_all = "camelCase")]
@@ -131,6 +253,40 @@ pub struct TPSLOrderData {
     pub size: String,
 }
 
+impl TPSLOrderData {
+    /// 校验止盈/止损价格是否位于开仓价的正确一侧：多单止盈高于开仓价、止损低于开仓价，空单相反
+    pub fn validate(&self, entry_price: f64, direction: PerpetualDirection) -> Result<(), String> {
+        let parse = |name: &str, value: &str| value.parse::<f64>().map_err(|_| format!("Invalid {} price: {}", name, value));
+
+        if let Some(take_profit) = &self.take_profit {
+            let price = parse("take profit", take_profit)?;
+            match direction {
+                PerpetualDirection::Long if price <= entry_price => {
+                    return Err(format!("Take profit {} must be above entry price {} for a long position", price, entry_price));
+                }
+                PerpetualDirection::Short if price >= entry_price => {
+                    return Err(format!("Take profit {} must be below entry price {} for a short position", price, entry_price));
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(stop_loss) = &self.stop_loss {
+            let price = parse("stop loss", stop_loss)?;
+            match direction {
+                PerpetualDirection::Long if price >= entry_price => {
+                    return Err(format!("Stop loss {} must be below entry price {} for a long position", price, entry_price));
+                }
+                PerpetualDirection::Short if price <= entry_price => {
+                    return Err(format!("Stop loss {} must be above entry price {} for a short position", price, entry_price));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[typeshare(swift = "Equatable, Sendable, Hashable")]
 #[serde(tag = "type", content = "content")]
@@ -170,3 +326,240 @@ pub enum PerpetualType {
     Increase(PerpetualConfirmData),
     Reduce(PerpetualReduceData),
 }
+
+impl PerpetualType {
+    /// 操作后仓位数量的变化，以基础资产计，增加为正、减少为负
+    pub fn size_delta(&self, current: &PerpetualPosition) -> f64 {
+        match self {
+            PerpetualType::Open(data) | PerpetualType::Increase(data) => data.size_value(),
+            PerpetualType::Reduce(reduce) => -reduced_size(&reduce.data, current),
+            PerpetualType::Close(_) => -current.size.abs(),
+            PerpetualType::Modify(_) => 0.0,
+        }
+    }
+
+    /// 操作后占用保证金的变化，减仓按减少的数量比例释放当前仓位保证金
+    pub fn margin_delta(&self, current: &PerpetualPosition) -> f64 {
+        match self {
+            PerpetualType::Open(data) | PerpetualType::Increase(data) => data.margin(),
+            PerpetualType::Reduce(reduce) => {
+                let size = current.size.abs();
+                if size == 0.0 {
+                    return 0.0;
+                }
+                -current.margin_amount * reduced_size(&reduce.data, current) / size
+            }
+            PerpetualType::Close(_) => -current.margin_amount,
+            PerpetualType::Modify(_) => 0.0,
+        }
+    }
+}
+
+// 减仓数量不超过当前仓位
+fn reduced_size(data: &PerpetualConfirmData, current: &PerpetualPosition) -> f64 {
+    data.size_value().min(current.size.abs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AssetType, Chain, PerpetualMarginType};
+
+    fn perpetual() -> Perpetual {
+        Perpetual {
+            id: "hypercore_ETH".to_string(),
+            name: "ETH".to_string(),
+            provider: PerpetualProvider::Hypercore,
+            asset_id: AssetId::from_chain(Chain::Ethereum),
+            identifier: "1".to_string(),
+            price: 2_000.0,
+            price_percent_change_24h: 0.0,
+            open_interest: 0.0,
+            volume_24h: 0.0,
+            funding: 0.0,
+            leverage: vec![1, 2, 5, 10, 25],
+        }
+    }
+
+    fn confirm_data(fiat_value: f64, leverage: u8) -> PerpetualConfirmData {
+        PerpetualConfirmData {
+            direction: PerpetualDirection::Long,
+            base_asset: Asset::new(AssetId::from_chain(Chain::Ethereum), "Ethereum".to_string(), "ETH".to_string(), 18, AssetType::NATIVE),
+            asset_index: 1,
+            price: "2000".to_string(),
+            fiat_value,
+            size: "0.5".to_string(),
+            slippage: 0.01,
+            leverage,
+            pnl: None,
+            entry_price: None,
+            market_price: 2_000.0,
+            margin_amount: 0.0,
+        }
+    }
+
+    fn tpsl(take_profit: Option<&str>, stop_loss: Option<&str>) -> TPSLOrderData {
+        TPSLOrderData {
+            direction: PerpetualDirection::Long,
+            take_profit: take_profit.map(str::to_string),
+            stop_loss: stop_loss.map(str::to_string),
+            size: "0.5".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_tpsl_validate_valid() {
+        assert_eq!(tpsl(Some("2200"), Some("1900")).validate(2_000.0, PerpetualDirection::Long), Ok(()));
+        assert_eq!(tpsl(Some("1800"), Some("2100")).validate(2_000.0, PerpetualDirection::Short), Ok(()));
+        assert_eq!(tpsl(None, None).validate(2_000.0, PerpetualDirection::Long), Ok(()));
+    }
+
+    #[test]
+    fn test_tpsl_validate_inverted() {
+        assert_eq!(
+            tpsl(Some("2200"), Some("2100")).validate(2_000.0, PerpetualDirection::Long),
+            Err("Stop loss 2100 must be below entry price 2000 for a long position".to_string())
+        );
+        assert_eq!(
+            tpsl(Some("2200"), None).validate(2_000.0, PerpetualDirection::Short),
+            Err("Take profit 2200 must be below entry price 2000 for a short position".to_string())
+        );
+        assert!(tpsl(Some("abc"), None).validate(2_000.0, PerpetualDirection::Long).is_err());
+    }
+
+    #[test]
+    fn test_validate_valid_order() {
+        let data = confirm_data(1_000.0, 10);
+        assert_eq!(data.required_margin(), 100.0);
+        assert_eq!(data.validate(100.0, &perpetual()), Ok(()));
+        assert_eq!(
+            data.validate(99.0, &perpetual()),
+            Err(PerpetualError::InsufficientBalance {
+                required: 100.0,
+                available: 99.0
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_over_leveraged() {
+        let data = confirm_data(1_000.0, 50);
+        assert_eq!(
+            data.validate(1_000.0, &perpetual()),
+            Err(PerpetualError::InvalidLeverage { leverage: 50, min: 1, max: 25 })
+        );
+    }
+
+    fn position(perpetual_id: &str, direction: PerpetualDirection, size: f64, entry_price: f64, margin_amount: f64) -> PerpetualPosition {
+        PerpetualPosition {
+            id: perpetual_id.to_string(),
+            perpetual_id: perpetual_id.to_string(),
+            asset_id: AssetId::from_chain(Chain::Ethereum),
+            size,
+            size_value: size * entry_price,
+            leverage: 10,
+            entry_price: Some(entry_price),
+            liquidation_price: None,
+            margin_type: PerpetualMarginType::Cross,
+            direction,
+            margin_amount,
+            take_profit: None,
+            stop_loss: None,
+            pnl: 0.0,
+            funding: None,
+        }
+    }
+
+    #[test]
+    fn test_positions_summary_from_positions() {
+        let positions = vec![
+            position("hypercore_ETH", PerpetualDirection::Long, 2.0, 2_000.0, 400.0),
+            position("hypercore_BTC", PerpetualDirection::Short, 0.1, 60_000.0, 0.0),
+            position("hypercore_SOL", PerpetualDirection::Long, 10.0, 150.0, 150.0),
+        ];
+        let mark_prices = HashMap::from([("hypercore_ETH".to_string(), 2_100.0), ("hypercore_BTC".to_string(), 61_000.0)]);
+
+        let summary = PerpetualPositionsSummary::from_positions(&positions, &mark_prices);
+
+        assert_eq!(summary.positions.len(), 3);
+        // 4200 + 6100，SOL 没有标记价格被跳过
+        assert!((summary.total_notional - 10_300.0).abs() < 1e-9);
+        // 多单 +200，空单 -100
+        assert!((summary.unrealized_pnl - 100.0).abs() < 1e-9);
+        // 400 + 6100 / 10
+        assert!((summary.margin_used - 1_010.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_increase_deltas() {
+        let current = position("hypercore_ETH", PerpetualDirection::Long, 2.0, 2_000.0, 400.0);
+        let increase = PerpetualType::Increase(confirm_data(1_000.0, 5));
+
+        assert_eq!(increase.size_delta(&current), 0.5);
+        assert_eq!(increase.margin_delta(&current), 200.0);
+    }
+
+    #[test]
+    fn test_reduce_deltas() {
+        let current = position("hypercore_ETH", PerpetualDirection::Long, 2.0, 2_000.0, 400.0);
+        let reduce = PerpetualType::Reduce(PerpetualReduceData {
+            data: confirm_data(1_000.0, 10),
+            position_direction: PerpetualDirection::Long,
+        });
+
+        assert_eq!(reduce.size_delta(&current), -0.5);
+        assert_eq!(reduce.margin_delta(&current), -100.0);
+
+        let mut over_reduce = confirm_data(10_000.0, 10);
+        over_reduce.size = "5".to_string();
+        let reduce = PerpetualType::Reduce(PerpetualReduceData {
+            data: over_reduce,
+            position_direction: PerpetualDirection::Long,
+        });
+        assert_eq!(reduce.size_delta(&current), -2.0);
+        assert_eq!(reduce.margin_delta(&current), -400.0);
+    }
+
+    #[test]
+    fn test_modify_deltas() {
+        let current = position("hypercore_ETH", PerpetualDirection::Long, 2.0, 2_000.0, 400.0);
+        let modify = PerpetualType::Modify(PerpetualModifyConfirmData {
+            base_asset: confirm_data(0.0, 1).base_asset,
+            asset_index: 1,
+            modify_types: vec![PerpetualModifyPositionType::Tpsl(tpsl(Some("2200"), None))],
+            take_profit_order_id: None,
+            stop_loss_order_id: None,
+        });
+
+        assert_eq!(modify.size_delta(&current), 0.0);
+        assert_eq!(modify.margin_delta(&current), 0.0);
+    }
+
+    fn balance(available: f64, reserved: f64) -> PerpetualBalance {
+        PerpetualBalance {
+            available,
+            reserved,
+            withdrawable: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_balance_healthy_account() {
+        let balance = balance(10_000.0, 2_000.0);
+
+        assert_eq!(balance.available_to_trade(), 8_000.0);
+        assert_eq!(balance.health_ratio(), 0.8);
+        assert_eq!(PerpetualBalance::default().health_ratio(), 0.0);
+    }
+
+    #[test]
+    fn test_balance_near_liquidation() {
+        let near = balance(1_000.0, 980.0);
+        assert_eq!(near.available_to_trade(), 20.0);
+        assert!((near.health_ratio() - 0.02).abs() < 1e-9);
+
+        let underwater = balance(900.0, 1_000.0);
+        assert_eq!(underwater.available_to_trade(), 0.0);
+        assert_eq!(underwater.health_ratio(), 0.0);
+    }
+}
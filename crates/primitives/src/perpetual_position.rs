@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use strum::{AsRefStr, EnumString};
 use typeshare::typeshare;
 
-use crate::{AssetId, PerpetualDirection};
+use crate::{AssetId, CancelOrderData, PerpetualDirection};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, AsRefStr, EnumString)]
 #[typeshare(swift = "Equatable, Sendable, Hashable")]
@@ -30,6 +30,15 @@ pub struct PerpetualTriggerOrder {
     pub order_id: String,
 }
 
+impl PerpetualTriggerOrder {
+    /// 撤单请求是否指向该订单，按订单 id 精确匹配
+    ///
+    /// 触发单不携带资产信息，调用方需确保只在同一合约（`asset_index`）的订单中匹配。
+    pub fn matches_cancel(&self, cancel: &CancelOrderData) -> bool {
+        self.order_id.trim().parse::<u64>().is_ok_and(|order_id| order_id == cancel.order_id)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[typeshare(swift = "Equatable, Sendable, Hashable")]
 #[serde(rename_all = "camelCase")]
@@ -50,3 +59,136 @@ pub struct PerpetualPosition {
     pub pnl: f64,
     pub funding: Option<f32>,
 }
+
+impl PerpetualPosition {
+    /// 估算强平价格
+    ///
+    /// 逐仓以仓位保证金（缺省时按 `entry_price / leverage` 推算）承担亏损；
+    /// 全仓以 `account_equity` 承担亏损，未提供时按逐仓计算。
+    /// 当权益降到 `maintenance_margin_rate * size * price` 时触发强平。
+    pub fn liquidation_price(&self, maintenance_margin_rate: f64, account_equity: Option<f64>) -> f64 {
+        let Some(entry_price) = self.entry_price else {
+            return 0.0;
+        };
+        let size = self.size.abs();
+        if size == 0.0 {
+            return 0.0;
+        }
+
+        let isolated_margin = if self.margin_amount > 0.0 {
+            self.margin_amount
+        } else if self.leverage > 0 {
+            size * entry_price / self.leverage as f64
+        } else {
+            0.0
+        };
+        let margin = match self.margin_type {
+            PerpetualMarginType::Isolated => isolated_margin,
+            PerpetualMarginType::Cross => account_equity.unwrap_or(isolated_margin),
+        };
+        let margin_per_unit = margin / size;
+
+        let price = match self.direction {
+            PerpetualDirection::Long => (entry_price - margin_per_unit) / (1.0 - maintenance_margin_rate),
+            PerpetualDirection::Short => (entry_price + margin_per_unit) / (1.0 + maintenance_margin_rate),
+        };
+        price.max(0.0)
+    }
+
+    /// 按每小时资金费率 `funding_rate` 估算持仓 `hours` 小时的资金费用
+    ///
+    /// 以仓位名义价值计算，正数表示支付、负数表示收取：
+    /// 费率为正时多单支付给空单，费率为负时相反。
+    pub fn estimated_funding_cost(&self, funding_rate: f64, hours: f64) -> f64 {
+        let cost = self.size_value.abs() * funding_rate * hours;
+        match self.direction {
+            PerpetualDirection::Long => cost,
+            PerpetualDirection::Short => -cost,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Chain;
+
+    fn position(direction: PerpetualDirection, margin_type: PerpetualMarginType, margin_amount: f64) -> PerpetualPosition {
+        PerpetualPosition {
+            id: "1".to_string(),
+            perpetual_id: "hypercore_ETH".to_string(),
+            asset_id: AssetId::from_chain(Chain::Ethereum),
+            size: 2.0,
+            size_value: 4_000.0,
+            leverage: 10,
+            entry_price: Some(2_000.0),
+            liquidation_price: None,
+            margin_type,
+            direction,
+            margin_amount,
+            take_profit: None,
+            stop_loss: None,
+            pnl: 0.0,
+            funding: None,
+        }
+    }
+
+    #[test]
+    fn test_liquidation_price_isolated_long() {
+        let position = position(PerpetualDirection::Long, PerpetualMarginType::Isolated, 400.0);
+        // (2000 - 400 / 2) / (1 - 0.05)
+        assert!((position.liquidation_price(0.05, None) - 1_894.736_842).abs() < 1e-6);
+        assert_eq!(position.liquidation_price(0.0, None), 1_800.0);
+    }
+
+    #[test]
+    fn test_liquidation_price_isolated_short() {
+        let position = position(PerpetualDirection::Short, PerpetualMarginType::Isolated, 0.0);
+        // 保证金按 10 倍杠杆推算：(2000 + 200) / (1 + 0.05)
+        assert!((position.liquidation_price(0.05, None) - 2_095.238_095).abs() < 1e-6);
+        assert_eq!(position.liquidation_price(0.0, Some(1_000_000.0)), 2_200.0);
+    }
+
+    #[test]
+    fn test_liquidation_price_cross() {
+        let position = position(PerpetualDirection::Long, PerpetualMarginType::Cross, 400.0);
+        assert_eq!(position.liquidation_price(0.0, Some(1_000.0)), 1_500.0);
+        assert_eq!(position.liquidation_price(0.0, Some(10_000.0)), 0.0);
+        assert_eq!(position.liquidation_price(0.0, None), 1_800.0);
+    }
+
+    #[test]
+    fn test_estimated_funding_cost_long_pays() {
+        let position = position(PerpetualDirection::Long, PerpetualMarginType::Cross, 400.0);
+        // 4000 * 0.0001 * 8
+        assert!((position.estimated_funding_cost(0.0001, 8.0) - 3.2).abs() < 1e-9);
+        assert!((position.estimated_funding_cost(-0.0001, 8.0) + 3.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_estimated_funding_cost_short_receives() {
+        let position = position(PerpetualDirection::Short, PerpetualMarginType::Cross, 400.0);
+        assert!((position.estimated_funding_cost(0.0001, 8.0) + 3.2).abs() < 1e-9);
+        assert_eq!(position.estimated_funding_cost(0.0001, 0.0), 0.0);
+    }
+
+    fn trigger_order(order_id: &str) -> PerpetualTriggerOrder {
+        PerpetualTriggerOrder {
+            price: 2_200.0,
+            order_type: PerpetualOrderType::Market,
+            order_id: order_id.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_matches_cancel() {
+        let cancel = CancelOrderData {
+            asset_index: 1,
+            order_id: 123_456,
+        };
+
+        assert!(trigger_order("123456").matches_cancel(&cancel));
+        assert!(!trigger_order("123457").matches_cancel(&cancel));
+        assert!(!trigger_order("0x1e240").matches_cancel(&cancel));
+    }
+}
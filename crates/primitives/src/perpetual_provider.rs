@@ -3,6 +3,8 @@ use std::fmt;
 use strum::{AsRefStr, EnumIter, EnumString};
 use typeshare::typeshare;
 
+use crate::{Perpetual, PerpetualDirection};
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, EnumIter, AsRefStr, EnumString)]
 #[typeshare(swift = "Equatable, Sendable")]
 #[serde(rename_all = "lowercase")]
@@ -18,3 +20,58 @@ impl fmt::Display for PerpetualProvider {
         }
     }
 }
+
+impl PerpetualProvider {
+    pub fn supported_directions(&self) -> Vec<PerpetualDirection> {
+        match self {
+            PerpetualProvider::Hypercore => vec![PerpetualDirection::Long, PerpetualDirection::Short],
+        }
+    }
+
+    /// 合约的最大杠杆，取自 `Perpetual::leverage`；合约不属于该 provider 或未提供杠杆档位时返回 `None`
+    pub fn max_leverage(&self, perpetual: &Perpetual) -> Option<u8> {
+        if &perpetual.provider != self {
+            return None;
+        }
+        perpetual.leverage.iter().copied().max()
+    }
+
+    /// 是否支持修改已有仓位（止盈/止损、撤单）
+    pub fn supports_modify(&self) -> bool {
+        match self {
+            PerpetualProvider::Hypercore => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AssetId, Chain};
+
+    fn perpetual(leverage: Vec<u8>) -> Perpetual {
+        Perpetual {
+            id: "hypercore_BTC".to_string(),
+            name: "BTC".to_string(),
+            provider: PerpetualProvider::Hypercore,
+            asset_id: AssetId::from_chain(Chain::Ethereum),
+            identifier: "0".to_string(),
+            price: 60_000.0,
+            price_percent_change_24h: 0.0,
+            open_interest: 0.0,
+            volume_24h: 0.0,
+            funding: 0.0,
+            leverage,
+        }
+    }
+
+    #[test]
+    fn test_hypercore_capabilities() {
+        let provider = PerpetualProvider::Hypercore;
+
+        assert_eq!(provider.supported_directions(), vec![PerpetualDirection::Long, PerpetualDirection::Short]);
+        assert_eq!(provider.max_leverage(&perpetual(vec![1, 5, 40, 10])), Some(40));
+        assert_eq!(provider.max_leverage(&perpetual(vec![])), None);
+        assert!(provider.supports_modify());
+    }
+}
@@ -30,6 +30,36 @@ impl PlatformStore {
     pub fn all() -> Vec<Self> {
         Self::iter().collect()
     }
+
+    /// 商店所属平台，`Local` 不属于任何商店平台
+    pub fn platform(&self) -> Option<Platform> {
+        match self {
+            Self::AppStore => Some(Platform::IOS),
+            Self::GooglePlay | Self::Fdroid | Self::Huawei | Self::SolanaStore | Self::SamsungStore | Self::ApkUniversal => Some(Platform::Android),
+            Self::Local => None,
+        }
+    }
+
+    /// 应用在该商店的下载链接
+    ///
+    /// App Store 使用数字 ID（可带 `id` 前缀），Android 商店使用包名。
+    /// `app_id` 与商店平台不匹配，或商店没有公开页面时返回空字符串。
+    pub fn app_url(&self, app_id: &str) -> String {
+        let app_id = app_id.trim();
+        let apple_id = app_id.strip_prefix("id").unwrap_or(app_id);
+        let is_apple_id = !apple_id.is_empty() && apple_id.chars().all(|c| c.is_ascii_digit());
+        let is_package_name = app_id.contains('.') && !is_apple_id;
+
+        match self {
+            Self::AppStore if is_apple_id => format!("https://apps.apple.com/app/id{}", apple_id),
+            Self::GooglePlay if is_package_name => format!("https://play.google.com/store/apps/details?id={}", app_id),
+            Self::Fdroid if is_package_name => format!("https://f-droid.org/packages/{}", app_id),
+            Self::Huawei if is_package_name => format!("https://appgallery.huawei.com/app/{}", app_id),
+            Self::SolanaStore if is_package_name => format!("https://dappstore.solanamobile.com/app/{}", app_id),
+            Self::SamsungStore if is_package_name => format!("https://galaxystore.samsung.com/detail/{}", app_id),
+            _ => String::new(),
+        }
+    }
 }
 
 impl Platform {
@@ -55,3 +85,28 @@ impl Platform {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_app_url() {
+        assert_eq!(PlatformStore::AppStore.app_url("1263956716"), "https://apps.apple.com/app/id1263956716");
+        assert_eq!(PlatformStore::AppStore.app_url("id1263956716"), "https://apps.apple.com/app/id1263956716");
+        assert_eq!(
+            PlatformStore::GooglePlay.app_url("com.example.wallet"),
+            "https://play.google.com/store/apps/details?id=com.example.wallet"
+        );
+        assert_eq!(PlatformStore::Fdroid.app_url("com.example.wallet"), "https://f-droid.org/packages/com.example.wallet");
+        assert_eq!(PlatformStore::Local.app_url("com.example.wallet"), "");
+    }
+
+    #[test]
+    fn test_app_url_platform_mismatch() {
+        assert_eq!(PlatformStore::GooglePlay.app_url("1263956716"), "");
+        assert_eq!(PlatformStore::AppStore.app_url("com.example.wallet"), "");
+        assert_eq!(PlatformStore::GooglePlay.platform(), Some(Platform::Android));
+        assert_eq!(PlatformStore::AppStore.platform(), Some(Platform::IOS));
+    }
+}
@@ -2,7 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use typeshare::typeshare;
 
-use crate::{Asset, AssetLink, AssetMarket, PriceAlert};
+use crate::{Asset, AssetLink, AssetMarket, PriceAlert, PriceAlertDirection};
 
 #[derive(Copy, Clone, Debug, Serialize, Deserialize)]
 #[typeshare(swift = "Equatable, Hashable, Sendable")]
@@ -32,6 +32,15 @@ impl Price {
             updated_at: self.updated_at,
         }
     }
+
+    /// 距 `now`（Unix 秒）的价格年龄，更新时间晚于 `now` 时为 0
+    pub fn age_secs(&self, now: u64) -> u64 {
+        age_secs(self.updated_at, now)
+    }
+}
+
+fn age_secs(updated_at: DateTime<Utc>, now: u64) -> u64 {
+    now.saturating_sub(updated_at.timestamp().max(0) as u64)
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,6 +50,26 @@ pub struct PriceFull {
     pub coin_id: String,
     pub price: f64,
     pub price_change_percentage_24h: f64,
+    #[serde(default)]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl PriceFull {
+    /// 价格年龄超过 `max_age_secs` 时视为过期，`now` 为 Unix 秒
+    pub fn is_stale(&self, now: u64, max_age_secs: u64) -> bool {
+        age_secs(self.updated_at, now) > max_age_secs
+    }
+
+    /// 24 小时价格方向，涨跌幅为 0 时返回 `None`（持平）
+    pub fn direction(&self) -> Option<PriceAlertDirection> {
+        if self.price_change_percentage_24h > 0.0 {
+            Some(PriceAlertDirection::Up)
+        } else if self.price_change_percentage_24h < 0.0 {
+            Some(PriceAlertDirection::Down)
+        } else {
+            None
+        }
+    }
 }
 
 #[allow(dead_code)]
@@ -59,6 +88,32 @@ struct PriceData {
 mod tests {
     use super::*;
 
+    fn price_full(price_change_percentage_24h: f64) -> PriceFull {
+        PriceFull {
+            asset_id: "ethereum".to_string(),
+            coin_id: "ethereum".to_string(),
+            price: 100.0,
+            price_change_percentage_24h,
+            updated_at: DateTime::from_timestamp(1_700_000_000, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_is_stale() {
+        let price = price_full(0.0);
+
+        assert!(!price.is_stale(1_700_000_060, 300));
+        assert!(!price.is_stale(1_699_999_000, 300));
+        assert!(price.is_stale(1_700_000_301, 300));
+    }
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(price_full(2.5).direction(), Some(PriceAlertDirection::Up));
+        assert_eq!(price_full(-0.1).direction(), Some(PriceAlertDirection::Down));
+        assert_eq!(price_full(0.0).direction(), None);
+    }
+
     #[test]
     fn test_new_with_rate() {
         let price = Price {
@@ -1,3 +1,5 @@
+use std::collections::{HashMap, HashSet};
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use strum::{AsRefStr, EnumString};
@@ -39,6 +41,39 @@ impl PriceAlert {
         .collect();
         parts.join("_")
     }
+
+    /// 判断价格从 `old_price` 变为 `new_price` 时是否触发提醒
+    ///
+    /// - 涨跌幅提醒：以提醒中记录的 `price` 为基准价（缺省时用 `old_price`），涨跌幅达到阈值时触发
+    /// - 目标价提醒：价格穿越 `price` 时触发
+    ///
+    /// 恰好达到阈值也算触发；只有本次变化跨过阈值才触发，避免重复通知。
+    pub fn is_triggered(&self, old_price: f64, new_price: f64) -> bool {
+        if let Some(percent) = self.price_percent_change {
+            let reference = self.price.unwrap_or(old_price);
+            if reference == 0.0 {
+                return false;
+            }
+            let change = |price: f64| (price - reference) / reference * 100.0;
+            let reached = |price: f64| match self.price_direction {
+                Some(PriceAlertDirection::Up) => change(price) >= percent,
+                Some(PriceAlertDirection::Down) => change(price) <= -percent,
+                None => change(price).abs() >= percent,
+            };
+            return reached(new_price) && !reached(old_price);
+        }
+
+        let Some(target) = self.price else {
+            return false;
+        };
+        let crossed_up = old_price < target && new_price >= target;
+        let crossed_down = old_price > target && new_price <= target;
+        match self.price_direction {
+            Some(PriceAlertDirection::Up) => crossed_up,
+            Some(PriceAlertDirection::Down) => crossed_down,
+            None => crossed_up || crossed_down,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -80,6 +115,25 @@ pub enum PriceAlertNotificationType {
 
 pub type PriceAlerts = Vec<PriceAlert>;
 
+pub trait PriceAlertsExt {
+    fn group_by_asset(&self) -> HashMap<AssetId, Vec<&PriceAlert>>;
+    fn assets(&self) -> HashSet<AssetId>;
+}
+
+impl PriceAlertsExt for PriceAlerts {
+    fn group_by_asset(&self) -> HashMap<AssetId, Vec<&PriceAlert>> {
+        let mut groups: HashMap<AssetId, Vec<&PriceAlert>> = HashMap::new();
+        for alert in self {
+            groups.entry(alert.asset_id.clone()).or_default().push(alert);
+        }
+        groups
+    }
+
+    fn assets(&self) -> HashSet<AssetId> {
+        self.iter().map(|x| x.asset_id.clone()).collect()
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DevicePriceAlert {
@@ -141,4 +195,79 @@ mod tests {
         };
         assert_eq!(price_alert.id(), "ethereum_USD_100_down");
     }
+
+    fn alert(price: Option<f64>, price_percent_change: Option<f64>, price_direction: Option<PriceAlertDirection>) -> PriceAlert {
+        PriceAlert {
+            asset_id: AssetId::from_chain(Chain::Ethereum),
+            currency: "USD".to_string(),
+            price,
+            price_percent_change,
+            price_direction,
+            last_notified_at: None,
+        }
+    }
+
+    #[test]
+    fn test_is_triggered_price_up() {
+        let alert = alert(Some(2_000.0), None, Some(PriceAlertDirection::Up));
+        assert!(alert.is_triggered(1_990.0, 2_010.0));
+        assert!(alert.is_triggered(1_990.0, 2_000.0));
+        assert!(!alert.is_triggered(2_000.0, 2_010.0));
+        assert!(!alert.is_triggered(2_010.0, 1_990.0));
+    }
+
+    #[test]
+    fn test_is_triggered_price_down() {
+        let alert = alert(Some(2_000.0), None, Some(PriceAlertDirection::Down));
+        assert!(alert.is_triggered(2_010.0, 1_990.0));
+        assert!(alert.is_triggered(2_010.0, 2_000.0));
+        assert!(!alert.is_triggered(1_990.0, 2_010.0));
+    }
+
+    #[test]
+    fn test_is_triggered_percent_change() {
+        let up = alert(Some(100.0), Some(10.0), Some(PriceAlertDirection::Up));
+        assert!(up.is_triggered(105.0, 110.0));
+        assert!(!up.is_triggered(105.0, 109.0));
+        assert!(!up.is_triggered(111.0, 112.0));
+
+        let down = alert(Some(100.0), Some(10.0), Some(PriceAlertDirection::Down));
+        assert!(down.is_triggered(95.0, 90.0));
+        assert!(!down.is_triggered(95.0, 110.0));
+    }
+
+    #[test]
+    fn test_is_not_triggered() {
+        assert!(!alert(None, None, None).is_triggered(1.0, 1_000.0));
+        assert!(!alert(Some(50.0), None, None).is_triggered(10.0, 20.0));
+        assert!(alert(Some(50.0), None, None).is_triggered(60.0, 40.0));
+    }
+
+    #[test]
+    fn test_group_by_asset() {
+        let usdc = AssetId::from_token(Chain::Solana, "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v");
+        let mut usdc_alert = alert(Some(1.0), None, None);
+        usdc_alert.asset_id = usdc.clone();
+        let mut sol_alert = alert(None, Some(5.0), None);
+        sol_alert.asset_id = AssetId::from_chain(Chain::Solana);
+
+        let alerts: PriceAlerts = vec![
+            alert(Some(2_000.0), None, Some(PriceAlertDirection::Up)),
+            usdc_alert,
+            alert(Some(1_500.0), None, Some(PriceAlertDirection::Down)),
+            sol_alert,
+        ];
+
+        let groups = alerts.group_by_asset();
+        assert_eq!(groups.len(), 3);
+        let ethereum: Vec<Option<f64>> = groups[&AssetId::from_chain(Chain::Ethereum)].iter().map(|x| x.price).collect();
+        assert_eq!(ethereum, vec![Some(2_000.0), Some(1_500.0)]);
+        assert_eq!(groups[&usdc].len(), 1);
+        assert_eq!(groups[&AssetId::from_chain(Chain::Solana)][0].price_percent_change, Some(5.0));
+
+        assert_eq!(
+            alerts.assets(),
+            HashSet::from([AssetId::from_chain(Chain::Ethereum), AssetId::from_chain(Chain::Solana), usdc])
+        );
+    }
 }
@@ -1,8 +1,10 @@
+use std::collections::HashSet;
+
 use serde::{Deserialize, Serialize};
 use strum::{AsRefStr, EnumIter, EnumString, IntoEnumIterator};
 use typeshare::typeshare;
 
-use crate::{Chain, TransactionType};
+use crate::{Chain, ChainAddress, TransactionType};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[typeshare(swift = "Equatable, Sendable")]
@@ -15,6 +17,75 @@ pub struct ScanTransactionPayload {
     pub website: Option<String>,
     #[serde(rename = "type")]
     pub transaction_type: TransactionType,
+    /// 原始调用数据（EVM calldata，十六进制）
+    #[serde(default)]
+    pub data: Option<String>,
+}
+
+/// ERC20 `approve(address,uint256)` 函数选择器
+const ERC20_APPROVE_SELECTOR: &str = "095ea7b3";
+/// 授权额度达到 2^255 及以上时视为无限授权（钱包/DApp 常用 `type(uint256).max`）
+const UNLIMITED_APPROVAL_MIN_HIGH_BYTE: u8 = 0x80;
+
+impl ScanTransactionPayload {
+    /// 是否为 ERC20 无限额度授权
+    pub fn is_unlimited_approval(&self) -> bool {
+        let Some(data) = &self.data else {
+            return false;
+        };
+        let data = data.strip_prefix("0x").unwrap_or(data).to_lowercase();
+        // selector(4 字节) + spender(32 字节) + amount(32 字节)
+        if data.len() != 8 + 64 * 2 || !data.starts_with(ERC20_APPROVE_SELECTOR) {
+            return false;
+        }
+        u8::from_str_radix(&data[72..74], 16).is_ok_and(|byte| byte >= UNLIMITED_APPROVAL_MIN_HIGH_BYTE)
+    }
+
+    /// 发起方或目标地址是否在黑名单中
+    pub fn involves_denylisted(&self, list: &Denylist) -> bool {
+        [&self.origin, &self.target].iter().any(|x| list.contains(x.chain, &x.address))
+    }
+}
+
+/// 恶意地址黑名单，地址统一规范化后存储（EVM 为 EIP-55 格式，base58 地址保持原样）
+#[derive(Clone, Debug, Default)]
+pub struct Denylist {
+    addresses: HashSet<String>,
+}
+
+impl Denylist {
+    pub fn new(addresses: HashSet<String>) -> Self {
+        let addresses = addresses
+            .iter()
+            .map(|address| {
+                let evm_address = ChainAddress::new(Chain::Ethereum, address.trim().to_lowercase());
+                if evm_address.is_valid() {
+                    evm_address.normalized()
+                } else {
+                    address.trim().to_string()
+                }
+            })
+            .collect();
+        Self { addresses }
+    }
+
+    pub fn contains(&self, chain: Chain, address: &str) -> bool {
+        let address = address.trim();
+        let normalized = if chain.is_evm() {
+            ChainAddress::new(chain, address.to_lowercase()).normalized()
+        } else {
+            ChainAddress::new(chain, address.to_string()).normalized()
+        };
+        self.addresses.contains(&normalized)
+    }
+
+    pub fn len(&self) -> usize {
+        self.addresses.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.addresses.is_empty()
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -25,6 +96,32 @@ pub struct ScanTransaction {
     pub is_memo_required: bool,
 }
 
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, AsRefStr, EnumString, PartialEq, Eq, PartialOrd, Ord)]
+#[typeshare(swift = "Equatable, Sendable")]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum RiskLevel {
+    None,
+    Low,
+    Medium,
+    High,
+}
+
+impl ScanTransaction {
+    /// 结合扫描结果与交易内容评估风险等级
+    pub fn risk_level(&self, payload: &ScanTransactionPayload) -> RiskLevel {
+        if self.is_malicious || payload.is_unlimited_approval() {
+            return RiskLevel::High;
+        }
+        match payload.transaction_type {
+            TransactionType::TokenApproval => RiskLevel::Medium,
+            TransactionType::SmartContractCall => RiskLevel::Low,
+            _ if self.is_memo_required => RiskLevel::Low,
+            _ => RiskLevel::None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[typeshare(swift = "Equatable, Sendable")]
 #[serde(rename_all = "camelCase")]
@@ -62,3 +159,80 @@ pub struct ScanAddress {
     pub is_memo_required: Option<bool>,
     pub is_verified: Option<bool>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPENDER: &str = "000000000000000000000000000000000022d473030f116ddee9f6b43ac78ba3";
+
+    fn payload(transaction_type: TransactionType, data: Option<String>) -> ScanTransactionPayload {
+        let target = |address: &str| ScanAddressTarget {
+            chain: Chain::Ethereum,
+            address: address.to_string(),
+        };
+        ScanTransactionPayload {
+            device_id: "device".to_string(),
+            wallet_index: 0,
+            origin: target("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+            target: target("0xdAC17F958D2ee523a2206206994597C13D831ec7"),
+            website: None,
+            transaction_type,
+            data,
+        }
+    }
+
+    fn scan(is_malicious: bool) -> ScanTransaction {
+        ScanTransaction {
+            is_malicious,
+            is_memo_required: false,
+        }
+    }
+
+    #[test]
+    fn test_risk_level_unlimited_approval() {
+        let data = format!("0x{ERC20_APPROVE_SELECTOR}{SPENDER}{}", "f".repeat(64));
+        let payload = payload(TransactionType::TokenApproval, Some(data));
+
+        assert!(payload.is_unlimited_approval());
+        assert_eq!(scan(false).risk_level(&payload), RiskLevel::High);
+    }
+
+    #[test]
+    fn test_risk_level_limited_approval() {
+        let data = format!("0x{ERC20_APPROVE_SELECTOR}{SPENDER}{:064x}", 1_000_000u64);
+        let payload = payload(TransactionType::TokenApproval, Some(data));
+
+        assert!(!payload.is_unlimited_approval());
+        assert_eq!(scan(false).risk_level(&payload), RiskLevel::Medium);
+    }
+
+    #[test]
+    fn test_denylist_evm_case_insensitive() {
+        let list = Denylist::new(HashSet::from(["0xdac17f958d2ee523a2206206994597c13d831ec7".to_string()]));
+
+        assert!(list.contains(Chain::Ethereum, "0xdAC17F958D2ee523a2206206994597C13D831ec7"));
+        assert!(list.contains(Chain::SmartChain, "0xDAC17F958D2EE523A2206206994597C13D831EC7"));
+        assert!(list.contains(Chain::Polygon, " 0xdac17f958d2ee523a2206206994597c13d831ec7 "));
+        assert!(!list.contains(Chain::Ethereum, "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"));
+
+        assert!(payload(TransactionType::Transfer, None).involves_denylisted(&list));
+    }
+
+    #[test]
+    fn test_denylist_solana() {
+        let list = Denylist::new(HashSet::from(["EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string()]));
+
+        assert!(list.contains(Chain::Solana, "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"));
+        assert!(!list.contains(Chain::Solana, "epjfwdd5aufqssqem2qn1xzybapc8g4wegGkzwytdt1v"));
+        assert!(!payload(TransactionType::Transfer, None).involves_denylisted(&list));
+    }
+
+    #[test]
+    fn test_risk_level_transfer() {
+        let payload = payload(TransactionType::Transfer, None);
+
+        assert_eq!(scan(false).risk_level(&payload), RiskLevel::None);
+        assert_eq!(scan(true).risk_level(&payload), RiskLevel::High);
+    }
+}
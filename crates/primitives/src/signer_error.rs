@@ -1,7 +1,14 @@
+use crate::Chain;
+
 #[derive(Debug, Clone)]
 pub enum SignerError {
     InvalidInput(String),
     UnsupportedOperation(String),
+    /// 私钥格式或长度无效，不携带私钥内容以免泄露
+    InvalidPrivateKey,
+    InvalidDerivationPath(String),
+    UnsupportedChain(Chain),
+    Serialization(String),
 }
 
 impl std::fmt::Display for SignerError {
@@ -9,6 +16,10 @@ impl std::fmt::Display for SignerError {
         match self {
             SignerError::InvalidInput(msg) => write!(f, "Invalid input: {}", msg),
             SignerError::UnsupportedOperation(msg) => write!(f, "Unsupported operation: {}", msg),
+            SignerError::InvalidPrivateKey => write!(f, "Invalid private key"),
+            SignerError::InvalidDerivationPath(path) => write!(f, "Invalid derivation path: {}", path),
+            SignerError::UnsupportedChain(chain) => write!(f, "Unsupported chain: {}", chain.as_ref()),
+            SignerError::Serialization(msg) => write!(f, "Serialization error: {}", msg),
         }
     }
 }
@@ -17,6 +28,37 @@ impl std::error::Error for SignerError {}
 
 impl From<serde_json::Error> for SignerError {
     fn from(error: serde_json::Error) -> Self {
-        SignerError::InvalidInput(error.to_string())
+        SignerError::Serialization(error.to_string())
+    }
+}
+
+impl From<hex::FromHexError> for SignerError {
+    fn from(error: hex::FromHexError) -> Self {
+        SignerError::InvalidInput(format!("Invalid hex: {}", error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(SignerError::InvalidPrivateKey.to_string(), "Invalid private key");
+        assert_eq!(
+            SignerError::InvalidDerivationPath("m/44'/60'/x".to_string()).to_string(),
+            "Invalid derivation path: m/44'/60'/x"
+        );
+        assert_eq!(SignerError::UnsupportedChain(Chain::Solana).to_string(), "Unsupported chain: solana");
+        assert_eq!(SignerError::Serialization("eof".to_string()).to_string(), "Serialization error: eof");
+    }
+
+    #[test]
+    fn test_from_hex_error() {
+        let error: SignerError = hex::decode("0xzz").unwrap_err().into();
+        assert!(matches!(error, SignerError::InvalidInput(ref msg) if msg.starts_with("Invalid hex: ")));
+
+        let error: SignerError = serde_json::from_str::<u64>("{").unwrap_err().into();
+        assert!(matches!(error, SignerError::Serialization(_)));
     }
 }
@@ -0,0 +1,96 @@
+//! 稳定币注册表
+//!
+//! 记录各链上主流稳定币（USDC / USDT，Solana 另含 USDS / PYUSD）的合约地址或 mint。
+
+use crate::{AssetId, Chain, ChainAddress};
+
+/// 获取链上已知的稳定币
+///
+/// # 返回值
+/// 该链的稳定币 `AssetId` 列表
+///
+/// # 示例
+/// ```
+/// use primitives::{Chain, stablecoins};
+///
+/// assert_eq!(stablecoins::stablecoins(Chain::Ethereum).len(), 2);
+/// ```
+pub fn stablecoins(chain: Chain) -> Vec<AssetId> {
+    let token_ids: &[&str] = match chain {
+        Chain::Ethereum => &[
+            "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48", // USDC
+            "0xdAC17F958D2ee523a2206206994597C13D831ec7", // USDT
+        ],
+        Chain::SmartChain => &[
+            "0x8AC76a51cc950d9822D68b83fE1Ad97B32Cd580d", // USDC
+            "0x55d398326f99059fF775485246999027B3197955", // USDT
+        ],
+        Chain::Polygon => &[
+            "0x3c499c542cEF5E3811e1192ce70d8cC03d5c3359", // USDC
+            "0xc2132D05D31c914a87C6611C10748AEb04B58e8F", // USDT
+        ],
+        Chain::Arbitrum => &[
+            "0xaf88d065e77c8cC2239327C5EDb3A432268e5831", // USDC
+            "0xFd086bC7CD5C481DCC9C85ebE478A1C0b69FCbb9", // USDT
+        ],
+        Chain::Solana => &[
+            "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v", // USDC
+            "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB", // USDT
+            "USDSwr9ApdHk5bvJKMjzff41FfuX8bSxdKcR81vTwcA", // USDS
+            "2b1kV6DkPAnxd5ixfnxCpjxmKwqjjaYmCZfHsFu24GXo", // PYUSD
+        ],
+    };
+    token_ids.iter().map(|token_id| AssetId::from_token(chain, token_id)).collect()
+}
+
+/// 是否为已知稳定币
+///
+/// EVM 链的合约地址不区分大小写，Solana mint 区分大小写。
+///
+/// # 示例
+/// ```
+/// use primitives::{AssetId, Chain, stablecoins};
+///
+/// assert!(stablecoins::is_stablecoin(&AssetId::from_token(Chain::Ethereum, "0xdac17f958d2ee523a2206206994597c13d831ec7")));
+/// assert!(!stablecoins::is_stablecoin(&AssetId::from_chain(Chain::Ethereum)));
+/// ```
+pub fn is_stablecoin(asset_id: &AssetId) -> bool {
+    let Some(token_id) = asset_id.token_id.as_deref() else {
+        return false;
+    };
+    let token = ChainAddress::new(asset_id.chain, token_id.to_string());
+    stablecoins(asset_id.chain)
+        .iter()
+        .filter_map(|stablecoin| stablecoin.token_id.as_deref())
+        .any(|stablecoin| token.equals(stablecoin))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stablecoins_per_chain() {
+        for chain in Chain::all() {
+            let assets = stablecoins(chain);
+            assert_eq!(assets.len(), if chain == Chain::Solana { 4 } else { 2 });
+            assert!(assets.iter().all(|asset| asset.chain == chain && is_stablecoin(asset)));
+        }
+    }
+
+    #[test]
+    fn test_is_stablecoin_evm() {
+        assert!(is_stablecoin(&AssetId::from_token(Chain::SmartChain, "0x55d398326f99059ff775485246999027b3197955")));
+        assert!(is_stablecoin(&AssetId::from_token(Chain::Arbitrum, "0xaf88d065e77c8cC2239327C5EDb3A432268e5831")));
+        // 同一地址在其他链上不是稳定币
+        assert!(!is_stablecoin(&AssetId::from_token(Chain::Polygon, "0xaf88d065e77c8cC2239327C5EDb3A432268e5831")));
+    }
+
+    #[test]
+    fn test_is_stablecoin_non_stable() {
+        // WETH
+        assert!(!is_stablecoin(&AssetId::from_token(Chain::Ethereum, "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2")));
+        assert!(!is_stablecoin(&AssetId::from_chain(Chain::Solana)));
+        assert!(!is_stablecoin(&AssetId::from_token(Chain::Solana, "epjfwdd5aufqssqem2qn1xzybapc8g4wegGkZwyTDt1v")));
+    }
+}
@@ -0,0 +1,55 @@
+use std::fmt;
+
+use config::ConfigError;
+
+/// 配置加载或校验错误
+#[derive(Debug)]
+pub enum SettingsError {
+    /// 配置文件读取或反序列化失败
+    Config(ConfigError),
+    /// 服务器端口无效
+    InvalidPort(u16),
+    /// 链的 RPC 地址无法解析
+    InvalidChainUrl { chain: String, url: String, reason: String },
+    /// 未知的日志级别
+    InvalidLogLevel(String),
+    /// 日志采样率不在 0.0 ~ 1.0 之间
+    InvalidSampleRate(f64),
+    /// 配置文件监听失败
+    Watch(notify::Error),
+}
+
+impl fmt::Display for SettingsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Config(err) => write!(f, "Config error: {}", err),
+            Self::InvalidPort(port) => write!(f, "Invalid server port: {}", port),
+            Self::InvalidChainUrl { chain, url, reason } => write!(f, "Invalid url for chain {}: '{}' ({})", chain, url, reason),
+            Self::InvalidLogLevel(level) => write!(f, "Invalid log level: '{}'", level),
+            Self::InvalidSampleRate(rate) => write!(f, "Invalid log sample rate: {} (expected 0.0 ~ 1.0)", rate),
+            Self::Watch(err) => write!(f, "Watch error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for SettingsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Config(err) => Some(err),
+            Self::Watch(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ConfigError> for SettingsError {
+    fn from(err: ConfigError) -> Self {
+        Self::Config(err)
+    }
+}
+
+impl From<notify::Error> for SettingsError {
+    fn from(err: notify::Error) -> Self {
+        Self::Watch(err)
+    }
+}
@@ -1,9 +1,15 @@
-use config::{Config, ConfigError, Environment, File};
+use std::path::{Path, PathBuf};
+
+use config::{Config, Environment, File};
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
 use serde::Deserialize;
 use tracing::Level;
 
+mod error;
+pub use error::SettingsError;
 
-
+/// 支持的日志级别
+pub const LOG_LEVELS: [&str; 5] = ["trace", "debug", "info", "warn", "error"];
 
 #[derive(Debug, Deserialize, Clone,Default)]
 #[allow(unused)]
@@ -15,12 +21,41 @@ pub struct Chains {
     pub arbitrum: Chain,
 }
 
+impl Chains {
+    /// 按名称列出所有链的配置
+    pub fn all(&self) -> Vec<(&'static str, &Chain)> {
+        vec![
+            ("solana", &self.solana),
+            ("ethereum", &self.ethereum),
+            ("smartchain", &self.smartchain),
+            ("polygon", &self.polygon),
+            ("arbitrum", &self.arbitrum),
+        ]
+    }
+}
+
 #[derive(Debug, Deserialize, Clone,Default)]
 #[allow(unused)]
 pub struct Chain {
     pub url: String,
     #[serde(default)]
     pub node: ChainURLType,
+    /// 请求超时（秒），为空时使用客户端默认值
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    /// 备用节点地址，主节点出现可重试错误时按顺序尝试
+    #[serde(default)]
+    pub fallback_urls: Vec<String>,
+    /// 各节点的权重，顺序与 `urls()` 一致，未配置的节点权重为 1；非空时按权重分配请求，不再主备切换
+    #[serde(default)]
+    pub weights: Vec<u32>,
+}
+
+impl Chain {
+    /// 主节点地址在前、备用节点地址在后的完整列表
+    pub fn urls(&self) -> Vec<String> {
+        std::iter::once(self.url.clone()).chain(self.fallback_urls.iter().cloned()).collect()
+    }
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -147,6 +182,14 @@ pub struct TracingConfig {
     /// 自定义过滤器 (例如: "my_crate=debug,other_crate=info")
     #[serde(default)]
     pub filter: Option<String>,
+
+    /// OTLP (HTTP) 导出地址 (例如: "http://localhost:4318/v1/traces")，设置后将 span 导出到 collector
+    #[serde(default)]
+    pub otlp_endpoint: Option<String>,
+
+    /// WARN 以下日志的采样率 (0.0 ~ 1.0)，表示保留的比例；WARN/ERROR 始终输出，未设置时不采样
+    #[serde(default)]
+    pub sample_rate: Option<f64>,
 }
 
 impl Default for TracingConfig {
@@ -162,6 +205,8 @@ impl Default for TracingConfig {
             json: false,
             with_ansi: true,
             filter: None,
+            otlp_endpoint: None,
+            sample_rate: None,
         }
     }
 }
@@ -196,7 +241,7 @@ impl Settings {
     /// 2. config/default.toml
     /// 3. config/{环境}.toml (如 config/development.toml)
     /// 4. 环境变量 (APP_ 前缀)
-    pub fn new() -> Result<Self, ConfigError> {
+    pub fn new() -> Result<Self, SettingsError> {
         let run_mode = std::env::var("RUN_MODE").unwrap_or_else(|_| "development".into());
 
         let config = Config::builder()
@@ -208,16 +253,87 @@ impl Settings {
             .add_source(Environment::with_prefix("APP").separator("__"))
             .build()?;
 
-        config.try_deserialize()
+        let settings: Self = config.try_deserialize()?;
+        settings.validate()?;
+        Ok(settings)
     }
 
     /// 从指定的配置文件创建
-    pub fn from_file(path: &str) -> Result<Self, ConfigError> {
+    pub fn from_file(path: &str) -> Result<Self, SettingsError> {
         let config = Config::builder()
             .add_source(File::with_name(path))
             .build()?;
 
-        config.try_deserialize()
+        let settings: Self = config.try_deserialize()?;
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    /// 监听配置文件变化
+    ///
+    /// 文件被修改或替换时重新解析，成功后把新配置传给 `on_change`；解析失败只记录日志。
+    /// 返回的监听器被 drop 后停止监听，调用方需要持有它。
+    pub fn watch<F>(path: &str, on_change: F) -> Result<RecommendedWatcher, SettingsError>
+    where
+        F: Fn(Settings) + Send + 'static,
+    {
+        let file_path = PathBuf::from(path);
+        let file_name = file_path.file_name().map(|name| name.to_os_string());
+        // 监听所在目录，编辑器以重命名方式保存时文件本身的监听会失效
+        let directory = match file_path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => Path::new(".").to_path_buf(),
+        };
+
+        let config_path = path.to_string();
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| match event {
+            Ok(event) => {
+                let is_change = event.kind.is_modify() || event.kind.is_create();
+                let is_target = event.paths.iter().any(|path| path.file_name().map(|name| name.to_os_string()) == file_name);
+                if !is_change || !is_target {
+                    return;
+                }
+                match Settings::from_file(&config_path) {
+                    Ok(settings) => on_change(settings),
+                    Err(err) => tracing::warn!("Failed to reload settings from {}: {}", config_path, err),
+                }
+            }
+            Err(err) => tracing::warn!("Settings watcher error for {}: {}", config_path, err),
+        })?;
+        watcher.watch(&directory, RecursiveMode::NonRecursive)?;
+
+        Ok(watcher)
+    }
+
+    /// 校验配置
+    ///
+    /// 检查服务器端口非 0、每条链的 RPC 地址是合法 URL、日志级别为已知值。
+    pub fn validate(&self) -> Result<(), SettingsError> {
+        if self.server.port == 0 {
+            return Err(SettingsError::InvalidPort(self.server.port));
+        }
+
+        for (name, chain) in self.chains.all() {
+            for url in chain.urls() {
+                url::Url::parse(&url).map_err(|err| SettingsError::InvalidChainUrl {
+                    chain: name.to_string(),
+                    url: url.clone(),
+                    reason: err.to_string(),
+                })?;
+            }
+        }
+
+        if !LOG_LEVELS.contains(&self.tracing.level.to_lowercase().as_str()) {
+            return Err(SettingsError::InvalidLogLevel(self.tracing.level.clone()));
+        }
+
+        if let Some(rate) = self.tracing.sample_rate
+            && !(0.0..=1.0).contains(&rate)
+        {
+            return Err(SettingsError::InvalidSampleRate(rate));
+        }
+
+        Ok(())
     }
 
     /// 创建开发环境配置
@@ -258,5 +374,103 @@ impl Settings {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_settings() -> Settings {
+        let mut settings = Settings::dev();
+        for chain in [
+            &mut settings.chains.solana,
+            &mut settings.chains.ethereum,
+            &mut settings.chains.smartchain,
+            &mut settings.chains.polygon,
+            &mut settings.chains.arbitrum,
+        ] {
+            chain.url = "https://rpc.example.com".to_string();
+        }
+        settings
+    }
+
+    #[test]
+    fn test_validate_valid() {
+        assert!(valid_settings().validate().is_ok());
+    }
 
+    #[test]
+    fn test_validate_missing_url() {
+        let mut settings = valid_settings();
+        settings.chains.ethereum.url = String::new();
+
+        match settings.validate() {
+            Err(SettingsError::InvalidChainUrl { chain, .. }) => assert_eq!(chain, "ethereum"),
+            other => panic!("Expected InvalidChainUrl, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_invalid_level() {
+        let mut settings = valid_settings();
+        settings.tracing.level = "verbose".to_string();
+
+        assert!(matches!(settings.validate(), Err(SettingsError::InvalidLogLevel(level)) if level == "verbose"));
+    }
 
+    #[test]
+    fn test_validate_invalid_sample_rate() {
+        let mut settings = valid_settings();
+        settings.tracing.sample_rate = Some(1.5);
+        assert!(matches!(settings.validate(), Err(SettingsError::InvalidSampleRate(rate)) if rate == 1.5));
+
+        settings.tracing.sample_rate = Some(0.0);
+        assert!(settings.validate().is_ok());
+    }
+
+    fn settings_toml(port: u16) -> String {
+        let chains: String = ["solana", "ethereum", "smartchain", "polygon", "arbitrum"]
+            .iter()
+            .map(|chain| format!("[chains.{chain}]\nurl = \"https://rpc.example.com\"\n"))
+            .collect();
+        format!("[server]\nport = {port}\n\n{chains}\n[ankr.key]\nsecret = \"\"\n\n[trongrid.key]\nsecret = \"\"\n")
+    }
+
+    #[test]
+    fn test_watch_reloads_on_change() {
+        let directory = std::env::temp_dir().join(format!("settings_watch_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("settings.toml");
+        std::fs::write(&path, settings_toml(3000)).unwrap();
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let _watcher = Settings::watch(path.to_str().unwrap(), move |settings| {
+            let _ = sender.send(settings.server.port);
+        })
+        .unwrap();
+
+        std::fs::write(&path, settings_toml(4000)).unwrap();
+
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+        let mut port = None;
+        while let Some(remaining) = deadline.checked_duration_since(std::time::Instant::now()) {
+            match receiver.recv_timeout(remaining) {
+                Ok(4000) => {
+                    port = Some(4000);
+                    break;
+                }
+                Ok(_) => continue,
+                Err(_) => break,
+            }
+        }
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(port, Some(4000));
+    }
+
+    #[test]
+    fn test_validate_invalid_port() {
+        let mut settings = valid_settings();
+        settings.server.port = 0;
+
+        assert!(matches!(settings.validate(), Err(SettingsError::InvalidPort(0))));
+    }
+}
@@ -1,4 +1,5 @@
 use std::error::Error;
+use std::time::{Duration, Instant};
 
 use core_chain_traits::ChainTraits;
 use primitives::{AssetBalance, Chain};
@@ -6,6 +7,17 @@ use settings::Settings;
 
 use crate::ProviderFactory;
 
+pub const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 单条链的健康检查结果，`latest_block` 与 `latency_ms` 仅在可用时有值
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChainHealth {
+    pub chain: Chain,
+    pub reachable: bool,
+    pub latest_block: Option<u64>,
+    pub latency_ms: Option<u64>,
+}
+
 pub struct ChainProviders {
     providers: Vec<Box<dyn ChainTraits>>,
 }
@@ -46,6 +58,43 @@ impl ChainProviders {
         Ok(provider)
     }
 
+    /// 并发请求每个 provider 的最新区块号，在超时时间内成功即视为可用
+    pub async fn health_check(&self) -> Vec<(Chain, bool)> {
+        self.health_status().await.into_iter().map(|health| (health.chain, health.reachable)).collect()
+    }
+
+    /// 与 `health_check` 相同，额外返回最新区块号和请求耗时
+    pub async fn health_status(&self) -> Vec<ChainHealth> {
+        let checks = self.providers.iter().map(|provider| async move {
+            let start = Instant::now();
+            let result = tokio::time::timeout(HEALTH_CHECK_TIMEOUT, provider.get_block_latest_number()).await;
+            let chain = provider.get_chain();
+            let unreachable = ChainHealth {
+                chain,
+                reachable: false,
+                latest_block: None,
+                latency_ms: None,
+            };
+            match result {
+                Ok(Ok(latest_block)) => ChainHealth {
+                    chain,
+                    reachable: true,
+                    latest_block: Some(latest_block),
+                    latency_ms: Some(start.elapsed().as_millis() as u64),
+                },
+                Ok(Err(err)) => {
+                    tracing::warn!("Health check failed for chain {:?}: {}", chain, err);
+                    unreachable
+                }
+                Err(_) => {
+                    tracing::warn!("Health check timed out for chain {:?}", chain);
+                    unreachable
+                }
+            }
+        });
+        futures::future::join_all(checks).await
+    }
+
     pub async fn get_balance_coin(
         &self,
         chain: Chain,
@@ -81,3 +130,35 @@ impl ChainProviders {
         self.get_provider(chain)?.get_balance_staking(address).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testkit::MockProvider;
+
+    #[tokio::test]
+    async fn test_health_check() {
+        let providers = ChainProviders::new(vec![
+            Box::new(MockProvider::new(Chain::Ethereum).with_error("HTTP error: status 503, body len: 0")),
+            Box::new(MockProvider::new(Chain::Solana).with_latest_block(100)),
+        ]);
+
+        let health = providers.health_check().await;
+        assert_eq!(health, vec![(Chain::Ethereum, false), (Chain::Solana, true)]);
+    }
+
+    #[tokio::test]
+    async fn test_health_status() {
+        let providers = ChainProviders::new(vec![
+            Box::new(MockProvider::new(Chain::Ethereum).with_error("HTTP error: status 503, body len: 0")),
+            Box::new(MockProvider::new(Chain::Solana).with_latest_block(100)),
+        ]);
+
+        let health = providers.health_status().await;
+        assert_eq!(health[0], ChainHealth { chain: Chain::Ethereum, reachable: false, latest_block: None, latency_ms: None });
+        assert_eq!(health[1].chain, Chain::Solana);
+        assert!(health[1].reachable);
+        assert_eq!(health[1].latest_block, Some(100));
+        assert!(health[1].latency_ms.is_some());
+    }
+}
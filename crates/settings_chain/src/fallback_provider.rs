@@ -0,0 +1,175 @@
+use std::error::Error;
+use std::future::Future;
+use std::pin::Pin;
+
+use async_trait::async_trait;
+use core_chain_traits::{ChainBalances, ChainProvider, ChainState, ChainTraits};
+use core_client::default_should_retry;
+use core_jsonrpc::types::JsonRpcError;
+use primitives::{AssetBalance, Chain, NodeSyncStatus};
+
+type ProviderResult<T> = Result<T, Box<dyn Error + Send + Sync>>;
+type ProviderFuture<'a, T> = Pin<Box<dyn Future<Output = ProviderResult<T>> + Send + 'a>>;
+
+/// 按顺序尝试多个节点的 provider
+///
+/// 遇到可重试错误（限流、5xx、网络错误、超时）时切换到下一个节点，其他错误直接返回。
+pub struct FallbackProvider {
+    chain: Chain,
+    providers: Vec<Box<dyn ChainTraits>>,
+}
+
+impl FallbackProvider {
+    pub fn new(chain: Chain, providers: Vec<Box<dyn ChainTraits>>) -> Self {
+        Self { chain, providers }
+    }
+
+    async fn try_each<'a, T, F>(&'a self, operation: F) -> ProviderResult<T>
+    where
+        F: Fn(&'a dyn ChainTraits) -> ProviderFuture<'a, T>,
+    {
+        let mut last_error: Box<dyn Error + Send + Sync> = format!("No providers configured for chain {}", self.chain.as_ref()).into();
+        for (index, provider) in self.providers.iter().enumerate() {
+            match operation(provider.as_ref()).await {
+                Ok(value) => return Ok(value),
+                Err(error) if is_retryable(error.as_ref()) => {
+                    tracing::warn!("Provider {} for chain {:?} failed, trying next: {}", index, self.chain, error);
+                    last_error = error;
+                }
+                Err(error) => return Err(error),
+            }
+        }
+        Err(last_error)
+    }
+}
+
+pub(crate) fn is_retryable(error: &(dyn Error + Send + Sync + 'static)) -> bool {
+    // 链客户端的错误（如 `EvmError`）通过 `source` 暴露内部的 `JsonRpcError`，需要沿错误链查找
+    let is_retryable_rpc_error = std::iter::successors(Some(error as &(dyn Error + 'static)), |&error| error.source())
+        .any(|error| error.downcast_ref::<JsonRpcError>().is_some_and(JsonRpcError::is_retryable));
+    if is_retryable_rpc_error {
+        return true;
+    }
+    let message = error.to_string().to_lowercase();
+    default_should_retry(&message) || ["status 500", "network error", "timeout"].iter().any(|pattern| message.contains(pattern))
+}
+
+impl ChainProvider for FallbackProvider {
+    fn get_chain(&self) -> Chain {
+        self.chain
+    }
+}
+
+#[async_trait]
+impl ChainBalances for FallbackProvider {
+    async fn get_balance_coin(&self, address: String) -> ProviderResult<AssetBalance> {
+        self.try_each(|provider| provider.get_balance_coin(address.clone())).await
+    }
+
+    async fn get_balance_tokens(&self, address: String, token_ids: Vec<String>) -> ProviderResult<Vec<AssetBalance>> {
+        self.try_each(|provider| provider.get_balance_tokens(address.clone(), token_ids.clone())).await
+    }
+
+    async fn get_balance_staking(&self, address: String) -> ProviderResult<Option<AssetBalance>> {
+        self.try_each(|provider| provider.get_balance_staking(address.clone())).await
+    }
+
+    async fn get_balance_assets(&self, address: String) -> ProviderResult<Vec<AssetBalance>> {
+        self.try_each(|provider| provider.get_balance_assets(address.clone())).await
+    }
+}
+
+#[async_trait]
+impl ChainState for FallbackProvider {
+    async fn get_chain_id(&self) -> ProviderResult<String> {
+        self.try_each(|provider| provider.get_chain_id()).await
+    }
+
+    async fn get_node_status(&self) -> ProviderResult<NodeSyncStatus> {
+        self.try_each(|provider| provider.get_node_status()).await
+    }
+
+    async fn get_block_latest_number(&self) -> ProviderResult<u64> {
+        self.try_each(|provider| provider.get_block_latest_number()).await
+    }
+}
+
+impl ChainTraits for FallbackProvider {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testkit::MockProvider;
+    use core_evm::rpc::EthereumClient;
+    use core_solana::rpc::client::SolanaClient;
+    use core_jsonrpc::{
+        JsonRpcClient,
+        testkit::mock_client,
+        types::{ERROR_LIMIT_EXCEEDED, ERROR_SERVER_ERROR},
+    };
+    use primitives::EVMChain;
+
+    #[tokio::test]
+    async fn test_fallback_on_retryable_error() {
+        let primary = MockProvider::new(Chain::Ethereum).with_error("HTTP error: status 503, body len: 0");
+        let fallback = MockProvider::new(Chain::Ethereum).with_latest_block(42);
+        let provider = FallbackProvider::new(Chain::Ethereum, vec![Box::new(primary.clone()), Box::new(fallback.clone())]);
+
+        assert_eq!(provider.get_block_latest_number().await.unwrap(), 42);
+        assert_eq!(primary.calls(), 1);
+        assert_eq!(fallback.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_fallback_stops_on_non_retryable_error() {
+        let primary = MockProvider::new(Chain::Ethereum).with_error("execution reverted");
+        let fallback = MockProvider::new(Chain::Ethereum);
+        let provider = FallbackProvider::new(Chain::Ethereum, vec![Box::new(primary), Box::new(fallback.clone())]);
+
+        let error = provider.get_balance_coin("0x".to_string()).await.unwrap_err();
+        assert_eq!(error.to_string(), "execution reverted");
+        assert_eq!(fallback.calls(), 0);
+    }
+
+    fn ethereum_client_with_error(code: i32, message: &'static str) -> EthereumClient<core_jsonrpc::testkit::MockClient> {
+        let mock = mock_client(move |_, _| Err(JsonRpcError { code, message: message.to_string() }));
+        EthereumClient::new(JsonRpcClient::new(mock), EVMChain::Ethereum)
+    }
+
+    #[tokio::test]
+    async fn test_fallback_on_evm_rate_limit() {
+        let fallback = MockProvider::new(Chain::Ethereum).with_latest_block(42);
+        let providers: Vec<Box<dyn ChainTraits>> = vec![
+            Box::new(ethereum_client_with_error(ERROR_LIMIT_EXCEEDED, "request limit exceeded")),
+            Box::new(ethereum_client_with_error(ERROR_SERVER_ERROR, "rate limited")),
+            Box::new(fallback.clone()),
+        ];
+        let provider = FallbackProvider::new(Chain::Ethereum, providers);
+
+        assert_eq!(provider.get_block_latest_number().await.unwrap(), 42);
+        assert_eq!(fallback.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_fallback_on_solana_rate_limit() {
+        let mock = mock_client(|_, _| Err(JsonRpcError { code: ERROR_LIMIT_EXCEEDED, message: "request limit exceeded".to_string() }));
+        let fallback = MockProvider::new(Chain::Solana).with_latest_block(42);
+        let providers: Vec<Box<dyn ChainTraits>> = vec![Box::new(SolanaClient::new(JsonRpcClient::new(mock))), Box::new(fallback.clone())];
+        let provider = FallbackProvider::new(Chain::Solana, providers);
+
+        assert_eq!(provider.get_block_latest_number().await.unwrap(), 42);
+        assert_eq!(fallback.calls(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_fallback_returns_last_error() {
+        let providers: Vec<Box<dyn ChainTraits>> = vec![
+            Box::new(MockProvider::new(Chain::Solana).with_error("HTTP error: status 503, body len: 0")),
+            Box::new(MockProvider::new(Chain::Solana).with_error("Network error: connection refused")),
+        ];
+        let provider = FallbackProvider::new(Chain::Solana, providers);
+
+        let error = provider.get_chain_id().await.unwrap_err();
+        assert_eq!(error.to_string(), "Network error: connection refused");
+    }
+}
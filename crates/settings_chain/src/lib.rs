@@ -1,18 +1,30 @@
 mod chain_providers;
+mod fallback_provider;
+mod load_balanced_provider;
+mod pooled_rpc_provider;
 mod provider_config;
-pub use chain_providers::ChainProviders;
-use core_client::{ReqwestClient, retry_policy};
+#[cfg(any(test, feature = "testkit"))]
+pub mod testkit;
+pub use chain_providers::{ChainHealth, ChainProviders, HEALTH_CHECK_TIMEOUT};
+pub use fallback_provider::FallbackProvider;
+pub use load_balanced_provider::LoadBalancedProvider;
+pub use pooled_rpc_provider::PooledRpcProvider;
+use core_client::{CircuitBreaker, Client, ReqwestClient, retry_policy};
 pub use provider_config::ProviderConfig;
 pub use settings::ChainURLType;
 
 use core_chain_traits::ChainTraits;
 use core_evm::rpc::ankr::AnkrClient;
 use core_evm::rpc::EthereumClient;
-use core_jsonrpc::JsonRpcClient;
+use core_jsonrpc::{JsonRpcClient, RpcClient};
 use core_solana::rpc::client::SolanaClient;
 
 use primitives::{Chain, EVMChain, NodeType};
 use settings::Settings;
+use std::sync::Arc;
+
+/// JSON-RPC 层遇到限流等可重试错误（见 `JsonRpcError::is_retryable`）时的最大重试次数
+pub const JSONRPC_MAX_RETRIES: u32 = 2;
 
 pub struct ProviderFactory {}
 
@@ -26,19 +38,22 @@ impl ProviderFactory {
         settings: &Settings,
         user_agent: &str,
     ) -> Box<dyn ChainTraits> {
+        Self::new_provider(Self::get_provider_config(chain, settings), user_agent)
+    }
+
+    pub fn get_provider_config(chain: Chain, settings: &Settings) -> ProviderConfig {
         let chain_config = Self::get_chain_config(chain, settings);
         let node_type = Self::get_node_type(chain_config.node.clone());
 
-        Self::new_provider(
-            ProviderConfig::new(
-                chain,
-                &chain_config.url,
-                node_type,
-                settings.ankr.key.secret.as_str(),
-                settings.trongrid.key.secret.as_str(),
-            ),
-            user_agent,
+        ProviderConfig::new(
+            chain,
+            chain_config.urls(),
+            node_type,
+            settings.ankr.key.secret.as_str(),
+            settings.trongrid.key.secret.as_str(),
         )
+        .with_timeout_secs(chain_config.timeout_secs)
+        .with_weights(chain_config.weights.clone())
     }
 
     pub fn new_providers(settings: &Settings) -> Vec<Box<dyn ChainTraits>> {
@@ -59,40 +74,80 @@ impl ProviderFactory {
     }
 
     pub fn new_provider(config: ProviderConfig, user_agent: &str) -> Box<dyn ChainTraits> {
+        if config.is_load_balanced() {
+            return Self::new_load_balanced_provider(config, user_agent);
+        }
+        if config.urls.len() > 1 {
+            let providers = config
+                .endpoint_configs()
+                .into_iter()
+                .map(|config| Self::new_endpoint_provider(config, user_agent))
+                .collect();
+            return Box::new(FallbackProvider::new(config.chain, providers));
+        }
+        Self::new_endpoint_provider(config, user_agent)
+    }
+
+    fn new_endpoint_provider(config: ProviderConfig, user_agent: &str) -> Box<dyn ChainTraits> {
         let host = config
-            .url
+            .url()
             .parse::<url::Url>()
             .ok()
             .and_then(|u| u.host_str().map(String::from))
             .unwrap_or_default();
 
-        let retry_policy_config = retry_policy(host, 3);
+        let retry_policy_config = retry_policy(host.clone(), 3);
         let reqwest_client = core_client::builder()
+            .timeout(config.timeout())
             .retry(retry_policy_config)
             .build()
             .expect("Failed to build reqwest client");
 
-        let chain = config.chain;
-        let url = config.url.clone();
-        let node_type = config.clone().node_type;
-        let gem_client = ReqwestClient::new_with_user_agent(
-            url.clone(),
+        let gem_client = CircuitBreaker::new(ReqwestClient::new_with_user_agent(
+            config.url().to_string(),
             reqwest_client.clone(),
             user_agent.to_string(),
-        );
+        ));
+        let ankr_client = CircuitBreaker::new(ReqwestClient::new(config.ankr_url(), reqwest_client));
+        Self::new_chain_client(&config, gem_client, &host, ankr_client)
+    }
+
+    // 每个请求按权重选择节点，可重试错误由 JSON-RPC 层重试到下一个选中的节点
+    fn new_load_balanced_provider(config: ProviderConfig, user_agent: &str) -> Box<dyn ChainTraits> {
+        let reqwest_client = core_client::builder()
+            .timeout(config.timeout())
+            .build()
+            .expect("Failed to build reqwest client");
+
+        let transport = ReqwestClient::new_with_user_agent(config.url().to_string(), reqwest_client.clone(), user_agent.to_string());
+        let provider = LoadBalancedProvider::new(config.weighted_urls(), Arc::new(transport));
+        let client = RpcClient::new(config.url().to_string(), Arc::new(provider));
+        let ankr_client = RpcClient::new(config.ankr_url(), Arc::new(ReqwestClient::new(config.ankr_url(), reqwest_client)));
+        // 请求可能落到任意节点，与备用节点一样按普通节点处理；多个 host 共用一个客户端，批量大小不按 host 共享
+        let config = ProviderConfig {
+            node_type: NodeType::Default,
+            ..config
+        };
+        Self::new_chain_client(&config, client, "", ankr_client)
+    }
+
+    fn new_chain_client<C>(config: &ProviderConfig, client: C, host: &str, ankr_client: C) -> Box<dyn ChainTraits>
+    where
+        C: Client + Clone + 'static,
+    {
+        let chain = config.chain;
+        let rpc_client = JsonRpcClient::new(client)
+            .with_chain(chain)
+            .with_host(host)
+            .with_max_retries(JSONRPC_MAX_RETRIES);
 
         match chain {
-            Chain::Solana => Box::new(SolanaClient::new(JsonRpcClient::new(gem_client.clone()))),
+            Chain::Solana => Box::new(SolanaClient::new(rpc_client)),
             Chain::Ethereum | Chain::SmartChain | Chain::Polygon | Chain::Arbitrum => {
                 let chain = EVMChain::from_chain(chain).unwrap();
-                let client = gem_client.clone();
-                let rpc_client = JsonRpcClient::new(client.clone());
-                let ethereum_client = EthereumClient::new(rpc_client.clone(), chain)
-                    .with_node_type(node_type)
-                    .with_ankr_client(AnkrClient::new(
-                        JsonRpcClient::new(ReqwestClient::new(config.clone().ankr_url(), reqwest_client.clone())),
-                        chain,
-                    ));
+                let ethereum_client = EthereumClient::new(rpc_client, chain)
+                    .with_node_type(config.node_type.clone())
+                    .with_ankr_client(AnkrClient::new(JsonRpcClient::new(ankr_client).with_max_retries(JSONRPC_MAX_RETRIES), chain));
                 Box::new(ethereum_client)
             }
         }
@@ -115,3 +170,60 @@ impl ProviderFactory {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_provider_config_timeout_override() {
+        let mut settings = Settings::dev();
+        settings.chains.ethereum.timeout_secs = Some(120);
+
+        let ethereum = ProviderFactory::get_provider_config(Chain::Ethereum, &settings);
+        let arbitrum = ProviderFactory::get_provider_config(Chain::Arbitrum, &settings);
+
+        assert_eq!(ethereum.timeout(), Duration::from_secs(120));
+        assert_eq!(arbitrum.timeout(), core_client::client_config::DEFAULT_TIMEOUT);
+    }
+
+    #[test]
+    fn test_provider_config_fallback_urls() {
+        let mut settings = Settings::dev();
+        settings.chains.ethereum.url = "https://archival.example.com".to_string();
+        settings.chains.ethereum.node = ChainURLType::Archival;
+        settings.chains.ethereum.fallback_urls = vec!["https://default.example.com".to_string()];
+
+        let config = ProviderFactory::get_provider_config(Chain::Ethereum, &settings);
+        assert_eq!(config.url(), "https://archival.example.com");
+
+        let endpoints = config.endpoint_configs();
+        assert_eq!(endpoints.len(), 2);
+        assert_eq!(endpoints[0].node_type, NodeType::Archival);
+        assert_eq!(endpoints[1].url(), "https://default.example.com");
+        assert_eq!(endpoints[1].node_type, NodeType::Default);
+    }
+
+    #[test]
+    fn test_provider_config_weights() {
+        let mut settings = Settings::dev();
+        settings.chains.solana.fallback_urls = vec!["https://b.example.com".to_string(), "https://c.example.com".to_string()];
+
+        let config = ProviderFactory::get_provider_config(Chain::Solana, &settings);
+        assert!(!config.is_load_balanced());
+
+        settings.chains.solana.weights = vec![3, 1];
+        let config = ProviderFactory::get_provider_config(Chain::Solana, &settings);
+        assert!(config.is_load_balanced());
+        assert_eq!(
+            config.weighted_urls(),
+            vec![
+                (settings.chains.solana.url.clone(), 3),
+                ("https://b.example.com".to_string(), 1),
+                ("https://c.example.com".to_string(), 1),
+            ]
+        );
+        assert!(!config.with_url("https://a.example.com").is_load_balanced());
+    }
+}
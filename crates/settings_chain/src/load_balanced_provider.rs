@@ -0,0 +1,158 @@
+use std::error::Error;
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use async_trait::async_trait;
+use core_jsonrpc::{RpcProvider, RpcResponse, Target};
+use primitives::Chain;
+
+/// 按权重轮询多个节点的 `RpcProvider`
+///
+/// 请求通过内部的 provider 发送，每次请求按权重选出一个节点并把 URL 切换过去。
+/// `get_endpoint` 返回第一个节点，`RpcClient` 以它作为 base url。
+#[derive(Debug)]
+pub struct LoadBalancedProvider<E> {
+    endpoints: Vec<(String, u32)>,
+    total_weight: usize,
+    provider: Arc<dyn RpcProvider<Error = E>>,
+    counter: AtomicUsize,
+}
+
+impl<E> LoadBalancedProvider<E> {
+    /// # 参数
+    /// - `endpoints` - 节点地址与权重，权重为 0 的节点会被忽略
+    /// - `provider` - 实际发送请求的 provider
+    pub fn new(endpoints: Vec<(String, u32)>, provider: Arc<dyn RpcProvider<Error = E>>) -> Self {
+        let endpoints: Vec<(String, u32)> = endpoints
+            .into_iter()
+            .filter(|(_, weight)| *weight > 0)
+            .map(|(url, weight)| (url.trim_end_matches('/').to_string(), weight))
+            .collect();
+        let total_weight = endpoints.iter().map(|(_, weight)| *weight as usize).sum();
+        Self {
+            endpoints,
+            total_weight,
+            provider,
+            counter: AtomicUsize::new(0),
+        }
+    }
+
+    fn next_endpoint(&self) -> Option<&str> {
+        if self.total_weight == 0 {
+            return None;
+        }
+        let mut slot = self.counter.fetch_add(1, Ordering::Relaxed) % self.total_weight;
+        for (url, weight) in &self.endpoints {
+            if slot < *weight as usize {
+                return Some(url);
+            }
+            slot -= *weight as usize;
+        }
+        None
+    }
+
+    fn route(&self, target: Target) -> Target {
+        let Some(selected) = self.next_endpoint() else {
+            return target;
+        };
+        match self.endpoints.iter().find(|(url, _)| target.url.starts_with(url.as_str())) {
+            Some((base, _)) => target.rebase(base, selected),
+            None => target,
+        }
+    }
+}
+
+#[async_trait]
+impl<E> RpcProvider for LoadBalancedProvider<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    type Error = E;
+
+    async fn request(&self, target: Target) -> Result<RpcResponse, Self::Error> {
+        self.provider.request(self.route(target)).await
+    }
+
+    fn get_endpoint(&self, chain: Chain) -> Result<String, Self::Error> {
+        match self.endpoints.first() {
+            Some((url, _)) => Ok(url.clone()),
+            None => self.provider.get_endpoint(chain),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[derive(Debug)]
+    struct MockError;
+
+    impl std::fmt::Display for MockError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "mock error")
+        }
+    }
+
+    impl Error for MockError {}
+
+    #[derive(Debug, Default)]
+    struct RecordingProvider {
+        urls: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl RpcProvider for RecordingProvider {
+        type Error = MockError;
+
+        async fn request(&self, target: Target) -> Result<RpcResponse, Self::Error> {
+            self.urls.lock().unwrap().push(target.url);
+            Ok(RpcResponse { status: Some(200), data: vec![] })
+        }
+
+        fn get_endpoint(&self, _chain: Chain) -> Result<String, Self::Error> {
+            Err(MockError)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_load_balanced_distribution_matches_weights() {
+        let recorder = Arc::new(RecordingProvider::default());
+        let endpoints = vec![
+            ("https://a.example.com".to_string(), 3),
+            ("https://b.example.com/".to_string(), 1),
+            ("https://c.example.com".to_string(), 0),
+        ];
+        let provider = LoadBalancedProvider::new(endpoints, recorder.clone());
+        let base = provider.get_endpoint(Chain::Ethereum).unwrap();
+        assert_eq!(base, "https://a.example.com");
+
+        for _ in 0..400 {
+            provider.request(Target::post_json(&base, serde_json::json!({}))).await.unwrap();
+        }
+
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for url in recorder.urls.lock().unwrap().iter() {
+            *counts.entry(url.clone()).or_default() += 1;
+        }
+        assert_eq!(counts.get("https://a.example.com"), Some(&300));
+        assert_eq!(counts.get("https://b.example.com"), Some(&100));
+        assert_eq!(counts.get("https://c.example.com"), None);
+    }
+
+    #[tokio::test]
+    async fn test_load_balanced_keeps_path() {
+        let recorder = Arc::new(RecordingProvider::default());
+        let endpoints = vec![("https://a.example.com".to_string(), 1), ("https://b.example.com".to_string(), 1)];
+        let provider = LoadBalancedProvider::new(endpoints, recorder.clone());
+
+        provider.request(Target::get("https://a.example.com/v1/status")).await.unwrap();
+        provider.request(Target::get("https://a.example.com/v1/status")).await.unwrap();
+
+        assert_eq!(*recorder.urls.lock().unwrap(), vec!["https://a.example.com/v1/status", "https://b.example.com/v1/status"]);
+    }
+}
@@ -0,0 +1,183 @@
+use std::error::Error;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use core_jsonrpc::{RpcProvider, RpcResponse, Target};
+use primitives::Chain;
+
+use crate::fallback_provider::is_retryable;
+
+/// 在一组节点地址之间轮换的 `RpcProvider`
+///
+/// 每个请求从下一个节点开始，遇到可重试错误（或 429/5xx 响应）时依次尝试后续节点。
+/// `get_endpoint` 返回当前选中的节点，`RpcClient` 以它作为 base url。
+#[derive(Debug)]
+pub struct PooledRpcProvider<E> {
+    urls: Vec<String>,
+    provider: Arc<dyn RpcProvider<Error = E>>,
+    counter: AtomicUsize,
+    selected: AtomicUsize,
+}
+
+impl<E> PooledRpcProvider<E> {
+    /// # 参数
+    /// - `urls` - 节点地址
+    /// - `provider` - 实际发送请求的 provider
+    pub fn new(urls: Vec<String>, provider: Arc<dyn RpcProvider<Error = E>>) -> Self {
+        Self {
+            urls: urls.into_iter().map(|url| url.trim_end_matches('/').to_string()).collect(),
+            provider,
+            counter: AtomicUsize::new(0),
+            selected: AtomicUsize::new(0),
+        }
+    }
+
+    fn route(&self, target: &Target, index: usize) -> Target {
+        let selected = &self.urls[index];
+        match self.urls.iter().find(|url| target.url.starts_with(url.as_str())) {
+            Some(base) => target.clone().rebase(base, selected),
+            None => target.clone(),
+        }
+    }
+}
+
+fn is_retryable_status(response: &RpcResponse) -> bool {
+    response.status.is_some_and(|status| status == 429 || status >= 500)
+}
+
+#[async_trait]
+impl<E> RpcProvider for PooledRpcProvider<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    type Error = E;
+
+    async fn request(&self, target: Target) -> Result<RpcResponse, Self::Error> {
+        if self.urls.is_empty() {
+            return self.provider.request(target).await;
+        }
+
+        let start = self.counter.fetch_add(1, Ordering::Relaxed);
+        let mut last_result = None;
+        for attempt in 0..self.urls.len() {
+            let index = (start + attempt) % self.urls.len();
+            self.selected.store(index, Ordering::Relaxed);
+
+            let result = self.provider.request(self.route(&target, index)).await;
+            match &result {
+                Ok(response) if is_retryable_status(response) => {
+                    tracing::warn!("Node {} responded with status {:?}, trying next", self.urls[index], response.status);
+                }
+                Err(error) if is_retryable(error) => {
+                    tracing::warn!("Node {} failed, trying next: {}", self.urls[index], error);
+                }
+                _ => return result,
+            }
+            last_result = Some(result);
+        }
+        last_result.expect("pool is not empty")
+    }
+
+    fn get_endpoint(&self, chain: Chain) -> Result<String, Self::Error> {
+        match self.urls.get(self.selected.load(Ordering::Relaxed)) {
+            Some(url) => Ok(url.clone()),
+            None => self.provider.get_endpoint(chain),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Debug)]
+    struct MockError(&'static str);
+
+    impl std::fmt::Display for MockError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl Error for MockError {}
+
+    // 对以 `failing` 开头的 URL 返回 `error`，其余返回 200
+    #[derive(Debug)]
+    struct FailingProvider {
+        failing: &'static str,
+        error: &'static str,
+        urls: Mutex<Vec<String>>,
+    }
+
+    impl FailingProvider {
+        fn new(failing: &'static str, error: &'static str) -> Self {
+            Self {
+                failing,
+                error,
+                urls: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl RpcProvider for FailingProvider {
+        type Error = MockError;
+
+        async fn request(&self, target: Target) -> Result<RpcResponse, Self::Error> {
+            self.urls.lock().unwrap().push(target.url.clone());
+            if target.url.starts_with(self.failing) {
+                return Err(MockError(self.error));
+            }
+            Ok(RpcResponse { status: Some(200), data: target.url.into_bytes() })
+        }
+
+        fn get_endpoint(&self, _chain: Chain) -> Result<String, Self::Error> {
+            Err(MockError("no endpoint"))
+        }
+    }
+
+    fn urls() -> Vec<String> {
+        vec!["https://a.example.com/".to_string(), "https://b.example.com".to_string()]
+    }
+
+    #[tokio::test]
+    async fn test_pooled_retries_next_url() {
+        let inner = Arc::new(FailingProvider::new("https://a.example.com", "HTTP error: status 503, body len: 0"));
+        let provider = PooledRpcProvider::new(urls(), inner.clone());
+        assert_eq!(provider.get_endpoint(Chain::Ethereum).unwrap(), "https://a.example.com");
+
+        let response = provider.request(Target::get("https://a.example.com/v1/status")).await.unwrap();
+
+        assert_eq!(response.data, b"https://b.example.com/v1/status");
+        assert_eq!(*inner.urls.lock().unwrap(), vec!["https://a.example.com/v1/status", "https://b.example.com/v1/status"]);
+        assert_eq!(provider.get_endpoint(Chain::Ethereum).unwrap(), "https://b.example.com");
+    }
+
+    #[tokio::test]
+    async fn test_pooled_rotates_per_request() {
+        let inner = Arc::new(FailingProvider::new("https://none.example.com", ""));
+        let provider = PooledRpcProvider::new(urls(), inner.clone());
+
+        for _ in 0..3 {
+            provider.request(Target::get("https://a.example.com/v1/status")).await.unwrap();
+        }
+
+        assert_eq!(
+            *inner.urls.lock().unwrap(),
+            vec!["https://a.example.com/v1/status", "https://b.example.com/v1/status", "https://a.example.com/v1/status"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pooled_returns_non_retryable_error() {
+        let inner = Arc::new(FailingProvider::new("https://a.example.com", "invalid params"));
+        let provider = PooledRpcProvider::new(urls(), inner.clone());
+
+        let error = provider.request(Target::get("https://a.example.com/v1/status")).await.unwrap_err();
+
+        assert_eq!(error.to_string(), "invalid params");
+        assert_eq!(inner.urls.lock().unwrap().len(), 1);
+    }
+}
@@ -1,21 +1,28 @@
+use std::time::Duration;
+
+use core_client::client_config::DEFAULT_TIMEOUT;
 use primitives::{Chain, NodeType};
 #[derive(Clone)]
 pub struct ProviderConfig {
     pub chain: Chain,
-    pub url: String,
+    pub urls: Vec<String>,
     pub node_type: NodeType,
     pub ankr_key: String,
     pub trongrid_key: String,
+    pub timeout_secs: Option<u64>,
+    pub weights: Vec<u32>,
 }
 
 impl ProviderConfig {
-    pub fn new(chain: Chain, url: &str, node_type: NodeType, ankr_key: &str, trongrid_key: &str) -> Self {
+    pub fn new(chain: Chain, urls: Vec<String>, node_type: NodeType, ankr_key: &str, trongrid_key: &str) -> Self {
         Self {
             chain,
-            url: url.to_string(),
+            urls,
             node_type,
             ankr_key: ankr_key.to_string(),
             trongrid_key: trongrid_key.to_string(),
+            timeout_secs: None,
+            weights: vec![],
         }
     }
 
@@ -23,10 +30,57 @@ impl ProviderConfig {
         format!("https://rpc.ankr.com/multichain/{}", self.ankr_key)
     }
 
+    pub fn url(&self) -> &str {
+        self.urls.first().map(String::as_str).unwrap_or_default()
+    }
+
     pub fn with_url(&self, url: &str) -> Self {
         Self {
-            url: url.to_string(),
+            urls: vec![url.to_string()],
+            ..self.clone()
+        }
+    }
+
+    // 每个地址拆成单独的配置，备用节点按普通节点处理
+    pub fn endpoint_configs(&self) -> Vec<ProviderConfig> {
+        self.urls
+            .iter()
+            .enumerate()
+            .map(|(index, url)| {
+                let config = self.with_url(url);
+                match index {
+                    0 => config,
+                    _ => ProviderConfig {
+                        node_type: NodeType::Default,
+                        ..config
+                    },
+                }
+            })
+            .collect()
+    }
+
+    pub fn with_timeout_secs(&self, timeout_secs: Option<u64>) -> Self {
+        Self {
+            timeout_secs,
             ..self.clone()
         }
     }
+
+    pub fn with_weights(&self, weights: Vec<u32>) -> Self {
+        Self { weights, ..self.clone() }
+    }
+
+    /// 配置了权重时按权重在多个节点之间分配请求
+    pub fn is_load_balanced(&self) -> bool {
+        self.urls.len() > 1 && !self.weights.is_empty()
+    }
+
+    /// 节点地址与权重，未配置权重的节点为 1
+    pub fn weighted_urls(&self) -> Vec<(String, u32)> {
+        self.urls.iter().cloned().zip(self.weights.iter().copied().chain(std::iter::repeat(1))).collect()
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.timeout_secs.map(Duration::from_secs).unwrap_or(DEFAULT_TIMEOUT)
+    }
 }
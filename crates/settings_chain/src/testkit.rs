@@ -0,0 +1,97 @@
+//! 测试用的链 provider
+
+use std::error::Error;
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use async_trait::async_trait;
+use core_chain_traits::{ChainBalances, ChainProvider, ChainState, ChainTraits};
+use primitives::{AssetBalance, AssetId, Chain};
+
+/// 返回固定结果的模拟 provider，记录被调用的次数
+#[derive(Clone)]
+pub struct MockProvider {
+    chain: Chain,
+    error: Option<String>,
+    latest_block: u64,
+    calls: Arc<AtomicUsize>,
+}
+
+impl MockProvider {
+    pub fn new(chain: Chain) -> Self {
+        Self {
+            chain,
+            error: None,
+            latest_block: 1,
+            calls: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// 所有调用都返回该错误
+    pub fn with_error(mut self, error: &str) -> Self {
+        self.error = Some(error.to_string());
+        self
+    }
+
+    pub fn with_latest_block(mut self, latest_block: u64) -> Self {
+        self.latest_block = latest_block;
+        self
+    }
+
+    pub fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+
+    fn respond<T>(&self, value: T) -> Result<T, Box<dyn Error + Send + Sync>> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        match &self.error {
+            Some(error) => Err(error.clone().into()),
+            None => Ok(value),
+        }
+    }
+}
+
+impl ChainProvider for MockProvider {
+    fn get_chain(&self) -> Chain {
+        self.chain
+    }
+}
+
+#[async_trait]
+impl ChainBalances for MockProvider {
+    async fn get_balance_coin(&self, _address: String) -> Result<AssetBalance, Box<dyn Error + Sync + Send>> {
+        self.respond(AssetBalance::new_zero_balance(AssetId::from_chain(self.chain)))
+    }
+
+    async fn get_balance_tokens(&self, _address: String, token_ids: Vec<String>) -> Result<Vec<AssetBalance>, Box<dyn Error + Sync + Send>> {
+        self.respond(
+            token_ids
+                .iter()
+                .map(|token_id| AssetBalance::new_zero_balance(AssetId::from_token(self.chain, token_id)))
+                .collect(),
+        )
+    }
+
+    async fn get_balance_staking(&self, _address: String) -> Result<Option<AssetBalance>, Box<dyn Error + Sync + Send>> {
+        self.respond(None)
+    }
+
+    async fn get_balance_assets(&self, _address: String) -> Result<Vec<AssetBalance>, Box<dyn Error + Send + Sync>> {
+        self.respond(vec![])
+    }
+}
+
+#[async_trait]
+impl ChainState for MockProvider {
+    async fn get_chain_id(&self) -> Result<String, Box<dyn Error + Sync + Send>> {
+        self.respond(self.chain.network_id().to_string())
+    }
+
+    async fn get_block_latest_number(&self) -> Result<u64, Box<dyn Error + Sync + Send>> {
+        self.respond(self.latest_block)
+    }
+}
+
+impl ChainTraits for MockProvider {}